@@ -0,0 +1,57 @@
+extern crate sdl3;
+
+use sdl3::app::{App, AppResult};
+use sdl3::event::Event;
+use sdl3::keyboard::Keycode;
+use sdl3::pixels::Color;
+use sdl3::render::WindowCanvas;
+use std::error::Error;
+
+struct ColorCycle {
+    canvas: WindowCanvas,
+    frame: u32,
+    // dropped last so SDL is shut down after the window
+    _sdl_context: sdl3::Sdl,
+}
+
+impl App for ColorCycle {
+    fn init(_args: Vec<String>) -> Result<Self, Box<dyn Error>> {
+        let sdl_context = sdl3::init()?;
+        let video_subsystem = sdl_context.video()?;
+        let window = video_subsystem
+            .window("rust-sdl3 demo: App callbacks", 800, 600)
+            .position_centered()
+            .resizable()
+            .build()?;
+
+        Ok(ColorCycle {
+            canvas: window.into_canvas(),
+            frame: 0,
+            _sdl_context: sdl_context,
+        })
+    }
+
+    fn iterate(&mut self) -> AppResult {
+        self.frame = self.frame.wrapping_add(1);
+        let i = (self.frame % 255) as u8;
+        self.canvas.set_draw_color(Color::RGB(i, 64, 255 - i));
+        self.canvas.clear();
+        self.canvas.present();
+        AppResult::Continue
+    }
+
+    fn event(&mut self, event: Event) -> AppResult {
+        match event {
+            Event::Quit { .. }
+            | Event::KeyDown {
+                keycode: Some(Keycode::Escape),
+                ..
+            } => AppResult::Success,
+            _ => AppResult::Continue,
+        }
+    }
+}
+
+pub fn main() {
+    std::process::exit(sdl3::app::run::<ColorCycle>());
+}
@@ -1,6 +1,6 @@
 extern crate sdl3;
 
-use sdl3::audio::{AudioCallback, AudioSpec};
+use sdl3::audio::{AudioCallback, AudioFormat, AudioRecordingCallback, AudioSpec};
 use sdl3::AudioSubsystem;
 use std::i16;
 use std::sync::mpsc;
@@ -17,8 +17,8 @@ struct Recording {
 
 // Append the input of the callback to the record_buffer.
 // When the record_buffer is full, send it to the main thread via done_sender.
-impl AudioCallback<i16> for Recording {
-    fn callback(&mut self, input: &mut [i16]) {
+impl AudioRecordingCallback<i16> for Recording {
+    fn callback(&mut self, input: &[i16]) {
         if self.done {
             return;
         }
@@ -136,10 +136,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sdl_context = sdl3::init()?;
     let audio_subsystem = sdl_context.audio()?;
 
+    // The callbacks receive i16 samples, so the format has to match.
     let desired_spec = AudioSpec {
-        freq: None,
-        channels: None,
-        format: None,
+        freq: Some(44100),
+        channels: Some(1),
+        format: Some(AudioFormat::s16_sys()),
     };
 
     let recorded_vec = record(&audio_subsystem, &desired_spec)?;
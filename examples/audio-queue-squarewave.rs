@@ -21,9 +21,6 @@ fn gen_wave(bytes_to_write: i32) -> Vec<i16> {
     result
 }
 
-// FIXME
-fn main() -> () {}
-#[cfg(feature = "")]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sdl_context = sdl3::init()?;
     let audio_subsystem = sdl_context.audio()?;
@@ -40,7 +37,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let wave = gen_wave(target_bytes);
     device.queue_audio(&wave)?;
     // Start playback
-    device.resume();
+    device.resume()?;
 
     // Play for 2 seconds
     std::thread::sleep(Duration::from_millis(2_000));
@@ -323,7 +323,7 @@ fn draw(canvas: &mut Canvas<Window>, edges: &[[f32; 6]], players: &[Player], pla
                 size_hor as u32,
                 size_ver as u32,
             );
-            canvas.set_clip_rect(rect); // Anything drawn outside this rectangle won't be visible
+            canvas.set_clip_rect(rect).unwrap(); // Anything drawn outside this rectangle won't be visible
 
             let x0 = player.pos[0]; // Player's x position
             let y0 = player.pos[1]; // Player's y position
@@ -437,7 +437,7 @@ fn draw(canvas: &mut Canvas<Window>, edges: &[[f32; 6]], players: &[Player], pla
                 .unwrap();
         }
     }
-    canvas.set_clip_rect(None);
+    canvas.set_clip_rect(None).unwrap();
     canvas.set_draw_color(Color::RGB(255, 255, 255));
     canvas.present();
 }
@@ -0,0 +1,66 @@
+extern crate sdl3;
+
+use sdl3::gpu::{
+    BufferRegion, BufferUsageFlags, Device, ShaderFormat, TransferBufferLocation,
+    TransferBufferUsage,
+};
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _sdl_context = sdl3::init()?;
+
+    // No window is needed to move data to the GPU and back
+    let gpu = Device::new(ShaderFormat::SpirV, true)?;
+
+    let values: Vec<u32> = (0..16).map(|i| i * i).collect();
+    let size = (values.len() * std::mem::size_of::<u32>()) as u32;
+
+    let buffer = gpu
+        .create_buffer()
+        .with_usage(BufferUsageFlags::Vertex)
+        .with_size(size)
+        .build()?;
+
+    // Upload buffers are written by the CPU and read by a copy pass...
+    let upload = gpu
+        .create_transfer_buffer()
+        .with_usage(TransferBufferUsage::Upload)
+        .with_size(size)
+        .build()?;
+    let mut map = upload.map::<u32>(&gpu, false);
+    map.mem_mut().copy_from_slice(&values);
+    map.unmap();
+
+    // ...while download buffers are written by a copy pass and read by the CPU
+    let download = gpu
+        .create_transfer_buffer()
+        .with_usage(TransferBufferUsage::Download)
+        .with_size(size)
+        .build()?;
+
+    let command_buffer = gpu.acquire_command_buffer()?;
+    let copy_pass = gpu.begin_copy_pass(&command_buffer)?;
+    copy_pass.upload_to_gpu_buffer(
+        TransferBufferLocation::new().with_transfer_buffer(&upload),
+        BufferRegion::new().with_buffer(&buffer).with_size(size),
+        false,
+    );
+    copy_pass.download_from_gpu_buffer(
+        BufferRegion::new().with_buffer(&buffer).with_size(size),
+        TransferBufferLocation::new().with_transfer_buffer(&download),
+    );
+    gpu.end_copy_pass(copy_pass);
+
+    // The fence is signaled once the GPU has executed the copies; map_read waits for it
+    let fence = command_buffer.submit_and_acquire_fence()?;
+    let readback = download.map_read(&gpu, &fence)?;
+    let result: Vec<u32> = readback
+        .bytes()
+        .chunks_exact(4)
+        .map(|bytes| u32::from_ne_bytes(bytes.try_into().unwrap()))
+        .collect();
+
+    println!("uploaded:   {:?}", values);
+    println!("downloaded: {:?}", result);
+    assert_eq!(values, result);
+    Ok(())
+}
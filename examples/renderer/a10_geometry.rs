@@ -0,0 +1,64 @@
+// https://github.com/libsdl-org/SDL/tree/main/examples/renderer
+extern crate sdl3;
+
+use sdl3::event::Event;
+use sdl3::pixels::Color;
+use sdl3::render::{FPoint, Vertex};
+use sdl3::Error;
+
+const WINDOW_WIDTH: u32 = 640;
+const WINDOW_HEIGHT: u32 = 480;
+
+fn main() -> Result<(), Error> {
+    let sdl_context = sdl3::init()?;
+    let video_subsystem = sdl_context.video()?;
+    let window = video_subsystem
+        .window(
+            "SDL3 Renderer Geometry Example",
+            WINDOW_WIDTH,
+            WINDOW_HEIGHT,
+        )
+        .position_centered()
+        .build()
+        .unwrap();
+
+    let mut canvas = window.into_canvas();
+    let mut event_pump = sdl_context.event_pump()?;
+
+    // Texture coordinates are ignored when no texture is given
+    let uv = FPoint::new(0.0, 0.0);
+
+    loop {
+        for event in event_pump.poll_iter() {
+            if let Event::Quit { .. } = event {
+                return Ok(());
+            }
+        }
+
+        canvas.set_draw_color(Color::BLACK);
+        canvas.clear();
+
+        // A triangle that grows and shrinks, colors are interpolated between the corners
+        let now = sdl3::timer::ticks() as f32 / 1000.0;
+        let scale = ((now.sin() + 1.0) / 2.0) * 100.0 + 100.0;
+        let (cx, cy) = (WINDOW_WIDTH as f32 / 4.0, WINDOW_HEIGHT as f32 / 2.0);
+        let triangle = [
+            Vertex::new(FPoint::new(cx, cy - scale), Color::RED, uv),
+            Vertex::new(FPoint::new(cx + scale, cy + scale), Color::GREEN, uv),
+            Vertex::new(FPoint::new(cx - scale, cy + scale), Color::BLUE, uv),
+        ];
+        canvas.render_geometry(&triangle, None, None)?;
+
+        // A quad made of two triangles sharing vertices through an index list
+        let (x, y, size) = (WINDOW_WIDTH as f32 / 2.0 + 60.0, 140.0, 200.0);
+        let quad = [
+            Vertex::new(FPoint::new(x, y), Color::YELLOW, uv),
+            Vertex::new(FPoint::new(x + size, y), Color::MAGENTA, uv),
+            Vertex::new(FPoint::new(x + size, y + size), Color::CYAN, uv),
+            Vertex::new(FPoint::new(x, y + size), Color::WHITE, uv),
+        ];
+        canvas.render_geometry(&quad, None, Some(&[0, 1, 2, 0, 2, 3]))?;
+
+        canvas.present();
+    }
+}
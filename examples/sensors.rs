@@ -40,18 +40,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     unsafe {
         if !controller.has_sensor(SensorType::Accelerometer) {
-            return Err(format!(
-                "{} doesn't support the accelerometer",
-                controller.name()
-            ));
+            return Err(format!("{} doesn't support the accelerometer", controller.name()).into());
         }
     }
     unsafe {
         if !controller.has_sensor(SensorType::Gyroscope) {
-            return Err(format!(
-                "{} doesn't support the gyroscope",
-                controller.name()
-            ));
+            return Err(format!("{} doesn't support the gyroscope", controller.name()).into());
         }
     }
 
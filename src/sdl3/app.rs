@@ -0,0 +1,198 @@
+//! Applications driven by SDL's main callbacks
+//!
+//! Instead of running its own loop, an [`App`] is called by SDL once per frame and once per
+//! event. This is how SDL runs smoothly on platforms that own the main loop, such as iOS and
+//! Emscripten, and keeps rendering while a window is resized on others.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::app::{App, AppResult};
+//! use sdl3::event::Event;
+//!
+//! struct Hello {
+//!     canvas: sdl3::render::WindowCanvas,
+//!     // dropped last so SDL is shut down after the window
+//!     _sdl: sdl3::Sdl,
+//! }
+//!
+//! impl App for Hello {
+//!     fn init(_args: Vec<String>) -> Result<Self, Box<dyn std::error::Error>> {
+//!         let sdl = sdl3::init()?;
+//!         let window = sdl.video()?.window("hello", 800, 600).build()?;
+//!         Ok(Hello {
+//!             canvas: window.into_canvas(),
+//!             _sdl: sdl,
+//!         })
+//!     }
+//!
+//!     fn iterate(&mut self) -> AppResult {
+//!         self.canvas.clear();
+//!         self.canvas.present();
+//!         AppResult::Continue
+//!     }
+//! }
+//!
+//! fn main() {
+//!     std::process::exit(sdl3::app::run::<Hello>());
+//! }
+//! ```
+
+use crate::event::Event;
+use crate::log::{log_error, Category};
+use crate::sys;
+use libc::{c_char, c_int, c_void};
+use std::error::Error;
+use std::ffi::{CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use sys::init::SDL_AppResult;
+
+/// Whether an [`App`] keeps running after a callback.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AppResult {
+    /// Keep calling the app.
+    Continue,
+    /// Quit, reporting success to the operating system.
+    Success,
+    /// Quit, reporting failure to the operating system.
+    Failure,
+}
+
+impl AppResult {
+    pub fn from_ll(result: SDL_AppResult) -> AppResult {
+        match result {
+            SDL_AppResult::CONTINUE => AppResult::Continue,
+            SDL_AppResult::SUCCESS => AppResult::Success,
+            _ => AppResult::Failure,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_AppResult {
+        match self {
+            AppResult::Continue => SDL_AppResult::CONTINUE,
+            AppResult::Success => SDL_AppResult::SUCCESS,
+            AppResult::Failure => SDL_AppResult::FAILURE,
+        }
+    }
+}
+
+/// An application run by [`run`].
+///
+/// All callbacks are called on the main thread, but never at the same time. A panic in a
+/// callback is logged and quits the app with a failure. SDL pumps events
+/// itself, so the app should not poll an [`EventPump`](crate::EventPump).
+pub trait App: Sized {
+    /// Called once at startup with the command line arguments, e.g. to initialize SDL and
+    /// create windows. Returning an error logs it and quits with a failure.
+    #[doc(alias = "SDL_AppInit")]
+    fn init(args: Vec<String>) -> Result<Self, Box<dyn Error>>;
+
+    /// Called once per frame to update and render.
+    #[doc(alias = "SDL_AppIterate")]
+    fn iterate(&mut self) -> AppResult;
+
+    /// Called for every event, possibly between frames. By default, quits successfully on
+    /// [`Event::Quit`] and ignores every other event.
+    #[doc(alias = "SDL_AppEvent")]
+    fn event(&mut self, event: Event) -> AppResult {
+        match event {
+            Event::Quit { .. } => AppResult::Success,
+            _ => AppResult::Continue,
+        }
+    }
+
+    /// Called once before SDL shuts down, with the result that ended the app.
+    #[doc(alias = "SDL_AppQuit")]
+    fn quit(self, result: AppResult) {
+        let _ = result;
+    }
+}
+
+/// Runs `A` until one of its callbacks stops it, returning the exit code for the process.
+///
+/// On platforms that own the main loop this may return before the app has quit, so `main`
+/// should return right after it.
+#[doc(alias = "SDL_EnterAppMainCallbacks")]
+pub fn run<A: App>() -> i32 {
+    let args: Vec<CString> = std::env::args()
+        .filter_map(|arg| CString::new(arg).ok())
+        .collect();
+    let mut argv: Vec<*mut c_char> = args
+        .iter()
+        .map(|arg| arg.as_ptr() as *mut c_char)
+        .chain(std::iter::once(std::ptr::null_mut()))
+        .collect();
+
+    unsafe {
+        sys::main::SDL_EnterAppMainCallbacks(
+            args.len() as c_int,
+            argv.as_mut_ptr(),
+            Some(app_init::<A>),
+            Some(app_iterate::<A>),
+            Some(app_event::<A>),
+            Some(app_quit::<A>),
+        )
+    }
+}
+
+unsafe extern "C" fn app_init<A: App>(
+    appstate: *mut *mut c_void,
+    argc: c_int,
+    argv: *mut *mut c_char,
+) -> SDL_AppResult {
+    let args = (0..argc.max(0) as usize)
+        .map(|i| *argv.add(i))
+        .filter(|arg| !arg.is_null())
+        .map(|arg| CStr::from_ptr(arg).to_string_lossy().into_owned())
+        .collect();
+
+    match catch_unwind(|| A::init(args)) {
+        Ok(Ok(app)) => {
+            *appstate = Box::into_raw(Box::new(app)) as *mut c_void;
+            SDL_AppResult::CONTINUE
+        }
+        Ok(Err(err)) => {
+            log_error(Category::Application, &err.to_string());
+            SDL_AppResult::FAILURE
+        }
+        Err(_) => panicked("init"),
+    }
+}
+
+unsafe extern "C" fn app_iterate<A: App>(appstate: *mut c_void) -> SDL_AppResult {
+    let app = &mut *(appstate as *mut A);
+    match catch_unwind(AssertUnwindSafe(|| app.iterate())) {
+        Ok(result) => result.to_ll(),
+        Err(_) => panicked("iterate"),
+    }
+}
+
+unsafe extern "C" fn app_event<A: App>(
+    appstate: *mut c_void,
+    event: *mut sys::events::SDL_Event,
+) -> SDL_AppResult {
+    let app = &mut *(appstate as *mut A);
+    match catch_unwind(AssertUnwindSafe(|| app.event(Event::from_ll(*event)))) {
+        Ok(result) => result.to_ll(),
+        Err(_) => panicked("event"),
+    }
+}
+
+unsafe extern "C" fn app_quit<A: App>(appstate: *mut c_void, result: SDL_AppResult) {
+    // null if `init` failed
+    if !appstate.is_null() {
+        let app = Box::from_raw(appstate as *mut A);
+        if catch_unwind(AssertUnwindSafe(|| app.quit(AppResult::from_ll(result)))).is_err() {
+            panicked("quit");
+        }
+    }
+}
+
+// Panics must not unwind into SDL, so they quit the app with a failure instead. The panic
+// message itself has already been printed by the panic hook.
+fn panicked(callback: &str) -> SDL_AppResult {
+    log_error(
+        Category::Application,
+        &format!("App::{} panicked, quitting", callback),
+    );
+    SDL_AppResult::FAILURE
+}
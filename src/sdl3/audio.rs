@@ -54,6 +54,7 @@
 
 use crate::get_error;
 use crate::iostream::IOStream;
+use crate::log::{log_error, Category};
 use crate::sys;
 use crate::AudioSubsystem;
 use crate::Error;
@@ -62,9 +63,12 @@ use std::convert::TryInto;
 use std::ffi::{c_int, CStr};
 use std::fmt;
 use std::fmt::{Debug, Display};
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use std::marker::PhantomData;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
 use sys::audio::{SDL_AUDIO_DEVICE_DEFAULT_PLAYBACK, SDL_AUDIO_DEVICE_DEFAULT_RECORDING};
 use sys::stdinc::SDL_free;
 
@@ -107,6 +111,9 @@ impl AudioSubsystem {
         Ok(ret)
     }
     /// Open a default playback device with the specified audio spec.
+    ///
+    /// The device follows the system's default: when the user switches to other headphones or
+    /// speakers, SDL moves it and the streams bound to it over, so audio keeps playing.
     pub fn open_playback_device(&self, spec: &AudioSpec) -> Result<AudioDevice, Error> {
         self.open_device(SDL_AUDIO_DEVICE_DEFAULT_PLAYBACK, spec)
     }
@@ -123,6 +130,30 @@ impl AudioSubsystem {
         )
     }
 
+    /// The default recording device, to open a stream on without opening the device first.
+    ///
+    /// To pull captured samples instead of receiving them in a callback, open a stream with
+    /// [`AudioDevice::open_device_stream`] and read from it:
+    ///
+    /// ```no_run
+    /// use sdl3::audio::{AudioFormat, AudioSpec};
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let audio_subsystem = sdl_context.audio().unwrap();
+    /// let spec = AudioSpec::new(Some(48000), Some(1), Some(AudioFormat::f32_sys()));
+    /// let mut stream = audio_subsystem
+    ///     .default_recording_device()
+    ///     .open_device_stream(Some(&spec))
+    ///     .unwrap();
+    /// stream.resume().unwrap();
+    ///
+    /// let mut samples = [0.0f32; 1024];
+    /// loop {
+    ///     let count = stream.get_samples(&mut samples).unwrap();
+    ///     // analyze samples[..count]
+    /// #   break;
+    /// }
+    /// ```
     pub fn default_recording_device(&self) -> AudioDevice {
         AudioDevice::new(
             AudioDeviceID::Device(SDL_AUDIO_DEVICE_DEFAULT_RECORDING),
@@ -174,19 +205,68 @@ impl AudioSubsystem {
         device.open_playback_stream_with_callback(spec, callback)
     }
 
+    /// Opens the default recording device with a callback that receives the captured samples.
+    /// The stream begins paused, so you must call `stream.resume()` to start recording.
     pub fn open_recording_stream<CB, Channel>(
         &self,
         spec: &AudioSpec,
         callback: CB,
     ) -> Result<AudioStreamWithCallback<CB>, Error>
     where
-        CB: AudioCallback<Channel>,
+        CB: AudioRecordingCallback<Channel>,
         Channel: AudioFormatNum + 'static,
     {
         let device = AudioDevice::open_recording(self, None, spec)?;
-        device.open_playback_stream_with_callback(spec, callback)
+        device.open_recording_stream_with_callback(spec, callback)
     }
 
+    /// Opens a playback device with a queue to push samples into, like `SDL_QueueAudio` in
+    /// SDL2. Opens the default device if `device` is `None`.
+    ///
+    /// Fields of `spec` that are `None` are taken from the device, except the format, which is
+    /// always that of `Channel`. The queue begins paused, so you must call
+    /// [`resume`](AudioQueue::resume) to start playback.
+    ///
+    /// New code should use an [`AudioStream`], which this is built on.
+    #[doc(alias = "SDL_QueueAudio")]
+    pub fn open_queue<'a, Channel, D>(
+        &self,
+        device: D,
+        spec: &AudioSpec,
+    ) -> Result<AudioQueue<Channel>, Error>
+    where
+        Channel: AudioFormatNum,
+        D: Into<Option<&'a AudioDeviceID>>,
+    {
+        if spec
+            .format
+            .is_some_and(|format| format != Channel::audio_format())
+        {
+            return Err(Error(
+                "AudioSpec format does not match AudioQueue Channel type".to_owned(),
+            ));
+        }
+        let device_id = device
+            .into()
+            .copied()
+            .unwrap_or(AudioDeviceID::Device(SDL_AUDIO_DEVICE_DEFAULT_PLAYBACK));
+        let (device_spec, _) = device_id.format()?;
+        let spec = AudioSpec {
+            freq: spec.freq.or(device_spec.freq),
+            channels: spec.channels.or(device_spec.channels),
+            format: Some(Channel::audio_format()),
+        };
+
+        // not closed by `AudioDevice::drop`, the stream owns the device it opens
+        let stream = AudioDevice::new(device_id, self.clone()).open_device_stream(Some(&spec))?;
+        Ok(AudioQueue {
+            stream,
+            spec,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The name of the audio driver in use, e.g. to include in bug reports.
     #[doc(alias = "SDL_GetCurrentAudioDriver")]
     pub fn current_audio_driver(&self) -> &'static str {
         unsafe {
@@ -261,6 +341,8 @@ impl AudioSubsystem {
             Ok(AudioStream {
                 stream,
                 audio_subsystem: self.clone(),
+                get_callback: None,
+                put_callback: None,
             })
         }
     }
@@ -429,7 +511,11 @@ impl Iterator for DriverIterator {
 
 impl ExactSizeIterator for DriverIterator {}
 
-/// Gets an iterator of all audio drivers compiled into the SDL2 library.
+/// Gets an iterator of all audio drivers compiled into the SDL library.
+///
+/// The driver is picked when the audio subsystem is initialized; use
+/// [`hint::set_audio_driver`](crate::hint::set_audio_driver) beforehand to force one.
+#[doc(alias = "SDL_GetNumAudioDrivers")]
 #[doc(alias = "SDL_GetAudioDriver")]
 #[inline]
 pub fn drivers() -> DriverIterator {
@@ -443,6 +529,68 @@ pub fn drivers() -> DriverIterator {
     }
 }
 
+/// Converts interleaved samples from `src_spec` to `dst_spec` in one go, e.g. to bring assets
+/// to a common format. Both specs need all their fields set.
+///
+/// To convert data that arrives piece by piece, create an unbound stream with
+/// [`AudioSubsystem::new_stream`] instead:
+///
+/// ```no_run
+/// use sdl3::audio::{AudioFormat, AudioSpec};
+///
+/// let sdl_context = sdl3::init().unwrap();
+/// let audio_subsystem = sdl_context.audio().unwrap();
+/// let src_spec = AudioSpec::new(Some(22050), Some(1), Some(AudioFormat::s16_sys()));
+/// let dst_spec = AudioSpec::new(Some(48000), Some(2), Some(AudioFormat::f32_sys()));
+/// let mut stream = audio_subsystem
+///     .new_stream(Some(&src_spec), Some(&dst_spec))
+///     .unwrap();
+///
+/// stream.put_samples(&[0i16; 2205]).unwrap();
+/// stream.flush().unwrap();
+/// let mut converted = vec![0.0f32; 48000 * 2 / 10];
+/// let count = stream.get_samples(&mut converted).unwrap();
+/// converted.truncate(count);
+/// ```
+#[doc(alias = "SDL_ConvertAudioSamples")]
+pub fn convert_audio(
+    src_spec: &AudioSpec,
+    src: &[u8],
+    dst_spec: &AudioSpec,
+) -> Result<Vec<u8>, Error> {
+    let src_len = c_int::try_from(src.len())
+        .map_err(|_| Error("Audio data is too large to convert".to_owned()))?;
+    let sdl_src_spec = sys::audio::SDL_AudioSpec::from(src_spec);
+    let sdl_dst_spec = sys::audio::SDL_AudioSpec::from(dst_spec);
+    let mut dst_data: *mut u8 = std::ptr::null_mut();
+    let mut dst_len: c_int = 0;
+
+    let result = unsafe {
+        sys::audio::SDL_ConvertAudioSamples(
+            &sdl_src_spec,
+            src.as_ptr(),
+            src_len,
+            &sdl_dst_spec,
+            &mut dst_data,
+            &mut dst_len,
+        )
+    };
+    if !result {
+        return Err(get_error());
+    }
+    if dst_data.is_null() {
+        return Ok(Vec::new());
+    }
+
+    let converted = unsafe { std::slice::from_raw_parts(dst_data, dst_len as usize) }.to_vec();
+    unsafe { SDL_free(dst_data as *mut c_void) };
+    Ok(converted)
+}
+
+/// The samples and format of a WAVE file, loaded into memory.
+///
+/// The samples can be played by putting [`buffer`](Self::buffer) into an [`AudioStream`] whose
+/// input spec is [`spec`](Self::spec).
 pub struct AudioSpecWAV {
     pub freq: i32,
     pub format: AudioFormat,
@@ -453,13 +601,14 @@ pub struct AudioSpecWAV {
 
 impl AudioSpecWAV {
     /// Loads a WAVE from the file path.
+    #[doc(alias = "SDL_LoadWAV")]
     pub fn load_wav<P: AsRef<Path>>(path: P) -> Result<AudioSpecWAV, Error> {
         let mut file = IOStream::from_file(path, "rb")?;
         AudioSpecWAV::load_wav_rw(&mut file)
     }
 
     /// Loads a WAVE from the data source.
-    #[doc(alias = "SDL_LoadWAV_RW")]
+    #[doc(alias = "SDL_LoadWAV_IO")]
     pub fn load_wav_rw(src: &mut IOStream) -> Result<AudioSpecWAV, Error> {
         use std::mem::MaybeUninit;
         use std::ptr::null_mut;
@@ -478,26 +627,71 @@ impl AudioSpecWAV {
             if !ret {
                 Err(get_error())
             } else {
-                let desired = desired.assume_init();
-                Ok(AudioSpecWAV {
-                    freq: desired.freq,
-                    format: AudioFormat::from_ll(desired.format).unwrap(),
-                    channels: desired.channels.try_into().unwrap(),
-                    audio_buf,
-                    audio_len,
-                })
+                let desired: sys::audio::SDL_AudioSpec = desired.assume_init();
+                let format = AudioFormat::from_ll(desired.format)
+                    .ok_or_else(|| Error("Unsupported WAVE sample format".to_owned()));
+                let channels = u8::try_from(desired.channels)
+                    .map_err(|_| Error("Unsupported WAVE channel count".to_owned()));
+                match (format, channels) {
+                    (Ok(format), Ok(channels)) => Ok(AudioSpecWAV {
+                        freq: desired.freq,
+                        format,
+                        channels,
+                        audio_buf,
+                        audio_len,
+                    }),
+                    (Err(err), _) | (_, Err(err)) => {
+                        SDL_free(audio_buf as *mut c_void);
+                        Err(err)
+                    }
+                }
             }
         }
     }
 
+    /// The format of the samples, to open or create a stream for them with.
+    pub fn spec(&self) -> AudioSpec {
+        AudioSpec::new(
+            Some(self.freq),
+            Some(self.channels.into()),
+            Some(self.format),
+        )
+    }
+
+    /// The interleaved samples as bytes.
     pub fn buffer(&self) -> &[u8] {
         use std::slice::from_raw_parts;
+        if self.audio_buf.is_null() {
+            return &[];
+        }
         unsafe {
             let ptr = self.audio_buf as *const u8;
             let len = self.audio_len as usize;
             from_raw_parts(ptr, len)
         }
     }
+
+    /// The interleaved samples, or `None` if they are not in the format of `T`.
+    ///
+    /// ```no_run
+    /// use sdl3::audio::AudioSpecWAV;
+    ///
+    /// let wav = AudioSpecWAV::load_wav("sound.wav").unwrap();
+    /// if let Some(samples) = wav.samples::<i16>() {
+    ///     println!("{} frames", samples.len() / wav.channels as usize);
+    /// }
+    /// ```
+    pub fn samples<T: AudioFormatNum>(&self) -> Option<&[T]> {
+        if self.format != T::audio_format() {
+            return None;
+        }
+        // SAFETY: every bit pattern is a valid sample, and the prefix/suffix check rejects
+        // misaligned buffers
+        match unsafe { self.buffer().align_to::<T>() } {
+            ([], samples, []) => Some(samples),
+            _ => None,
+        }
+    }
 }
 
 impl Drop for AudioSpecWAV {
@@ -701,6 +895,53 @@ impl AudioDeviceID {
             Ok(CStr::from_ptr(name_ptr).to_str().unwrap().to_owned())
         }
     }
+
+    /// Get the current format of the device, or its preferred format if it is not open, along
+    /// with its buffer size in sample frames.
+    #[doc(alias = "SDL_GetAudioDeviceFormat")]
+    pub fn format(&self) -> Result<(AudioSpec, i32), Error> {
+        let mut spec = sys::audio::SDL_AudioSpec::default();
+        let mut sample_frames: c_int = 0;
+        let result = unsafe {
+            sys::audio::SDL_GetAudioDeviceFormat(self.id(), &mut spec, &mut sample_frames)
+        };
+        if result {
+            Ok((AudioSpec::from(&spec), sample_frames))
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Get the order of the device's channels, if it is not the default order for its channel
+    /// count. Each entry is the index of the standard channel in that position.
+    #[doc(alias = "SDL_GetAudioDeviceChannelMap")]
+    pub fn channel_map(&self) -> Option<Vec<i32>> {
+        let mut count: c_int = 0;
+        let map = unsafe { sys::audio::SDL_GetAudioDeviceChannelMap(self.id(), &mut count) };
+        unsafe { channel_map_from_ll(map, count) }
+    }
+
+    /// Returns `true` for playback devices and `false` for recording devices.
+    #[doc(alias = "SDL_IsAudioDevicePlayback")]
+    pub fn is_playback(&self) -> bool {
+        unsafe { sys::audio::SDL_IsAudioDevicePlayback(self.id()) }
+    }
+
+    /// Returns `true` for hardware devices, and `false` for logical devices opened by an app.
+    #[doc(alias = "SDL_IsAudioDevicePhysical")]
+    pub fn is_physical(&self) -> bool {
+        unsafe { sys::audio::SDL_IsAudioDevicePhysical(self.id()) }
+    }
+}
+
+/// Copies a channel map returned by SDL and frees it.
+unsafe fn channel_map_from_ll(map: *mut c_int, count: c_int) -> Option<Vec<i32>> {
+    if map.is_null() {
+        return None;
+    }
+    let channel_map = std::slice::from_raw_parts(map, count.max(0) as usize).to_vec();
+    SDL_free(map as *mut c_void);
+    Some(channel_map)
 }
 
 impl PartialEq for AudioDeviceID {
@@ -758,6 +999,19 @@ impl AudioDevice {
         }
     }
 
+    /// Get the format of the device along with its buffer size in sample frames, see
+    /// [`AudioDeviceID::format`].
+    #[doc(alias = "SDL_GetAudioDeviceFormat")]
+    pub fn format(&self) -> Result<(AudioSpec, i32), Error> {
+        self.device_id.format()
+    }
+
+    /// Get the order of the device's channels, see [`AudioDeviceID::channel_map`].
+    #[doc(alias = "SDL_GetAudioDeviceChannelMap")]
+    pub fn channel_map(&self) -> Option<Vec<i32>> {
+        self.device_id.channel_map()
+    }
+
     /// Create an `AudioStream` for this device with the specified spec.
     /// This device will be closed when the stream is dropped.
     /// The device begins paused, so you must call `stream.resume()` to start playback.
@@ -785,10 +1039,53 @@ impl AudioDevice {
             Ok(AudioStream {
                 stream,
                 audio_subsystem,
+                get_callback: None,
+                put_callback: None,
             })
         }
     }
 
+    /// Sets a callback that sees the final mix of this device, after all bound streams are
+    /// mixed and just before it is played or after it is recorded, e.g. for a limiter or a
+    /// loudness meter.
+    ///
+    /// The callback gets the device's spec and its samples as interleaved `f32`s, which it may
+    /// change in place. It runs on the audio thread and should return as quickly as possible.
+    /// The callback stays set until the returned [`AudioPostmixCallback`] is dropped, or until
+    /// another postmix callback is set on the same device.
+    #[doc(alias = "SDL_SetAudioPostmixCallback")]
+    pub fn set_postmix_callback<F>(&self, callback: F) -> Result<AudioPostmixCallback, Error>
+    where
+        F: FnMut(&AudioSpec, &mut [f32]) + Send + 'static,
+    {
+        let mut callback = Box::new(callback);
+        let userdata = &mut *callback as *mut F as *mut c_void;
+        let device_id = self.device_id.id();
+
+        let mut active = ACTIVE_POSTMIX_CALLBACKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let result = unsafe {
+            sys::audio::SDL_SetAudioPostmixCallback(
+                device_id,
+                Some(audio_postmix_callback::<F>),
+                userdata,
+            )
+        };
+        if result {
+            let generation = NEXT_POSTMIX_GENERATION.fetch_add(1, Ordering::Relaxed);
+            active.retain(|&(id, _)| id != device_id);
+            active.push((device_id, generation));
+            Ok(AudioPostmixCallback {
+                device_id: self.device_id,
+                generation,
+                _callback: callback,
+            })
+        } else {
+            Err(get_error())
+        }
+    }
+
     /// Binds an audio stream to this device.
     #[doc(alias = "SDL_BindAudioStream")]
     pub fn bind_stream(&self, stream: &AudioStream) -> Result<(), Error> {
@@ -892,6 +1189,38 @@ impl AudioDevice {
         unsafe { sys::audio::SDL_ResumeAudioDevice(self.device_id.id()) }
     }
 
+    /// Returns `true` if the audio device is paused.
+    #[doc(alias = "SDL_AudioDevicePaused")]
+    pub fn is_paused(&self) -> bool {
+        unsafe { sys::audio::SDL_AudioDevicePaused(self.device_id.id()) }
+    }
+
+    /// Retrieves the gain of the audio device, applied to everything mixed into it.
+    #[doc(alias = "SDL_GetAudioDeviceGain")]
+    pub fn get_gain(&self) -> Result<f32, Error> {
+        let gain = unsafe { sys::audio::SDL_GetAudioDeviceGain(self.device_id.id()) };
+        if gain >= 0.0 {
+            Ok(gain)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets the gain of the audio device, e.g. for a master volume slider. `1.0` leaves the
+    /// volume unchanged, `0.0` mutes and larger values amplify.
+    ///
+    /// Only logical devices, opened by the app, have a gain; it can't be set on the physical
+    /// device ids returned by enumeration.
+    #[doc(alias = "SDL_SetAudioDeviceGain")]
+    pub fn set_gain(&self, gain: f32) -> Result<(), Error> {
+        let result = unsafe { sys::audio::SDL_SetAudioDeviceGain(self.device_id.id(), gain) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
     /// Opens a new audio stream for this device with the specified spec.
     /// The device begins paused, so you must call `stream.resume()` to start playback.
     #[doc(alias = "SDL_OpenAudioDeviceStream")]
@@ -952,6 +1281,8 @@ impl AudioDevice {
                     base_stream: AudioStream {
                         stream,
                         audio_subsystem: self.audio_subsystem.clone(),
+                        get_callback: None,
+                        put_callback: None,
                     },
                     _marker: PhantomData,
                     c_userdata,
@@ -1001,6 +1332,8 @@ impl AudioDevice {
                     base_stream: AudioStream {
                         stream,
                         audio_subsystem: self.audio_subsystem.clone(),
+                        get_callback: None,
+                        put_callback: None,
                     },
                     _marker: PhantomData,
                     c_userdata,
@@ -1010,10 +1343,69 @@ impl AudioDevice {
     }
 }
 
+/// Keeps a callback set with [`AudioDevice::set_postmix_callback`] active, and removes it
+/// when dropped.
+pub struct AudioPostmixCallback {
+    device_id: AudioDeviceID,
+    generation: u64,
+    _callback: Box<dyn Send>,
+}
+
+/// The device and generation of the postmix callback currently set on each device, so that
+/// dropping a replaced [`AudioPostmixCallback`] leaves its replacement alone.
+static ACTIVE_POSTMIX_CALLBACKS: Mutex<Vec<(sys::audio::SDL_AudioDeviceID, u64)>> =
+    Mutex::new(Vec::new());
+static NEXT_POSTMIX_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+impl Drop for AudioPostmixCallback {
+    #[doc(alias = "SDL_SetAudioPostmixCallback")]
+    fn drop(&mut self) {
+        let device_id = self.device_id.id();
+        let mut active = ACTIVE_POSTMIX_CALLBACKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        // A replaced callback is no longer called by SDL and can simply be freed.
+        let Some(index) = active
+            .iter()
+            .position(|&entry| entry == (device_id, self.generation))
+        else {
+            return;
+        };
+        active.swap_remove(index);
+
+        // Waits for a running callback to return, so it can be freed afterwards. Fails
+        // harmlessly if the device has been closed already.
+        unsafe { sys::audio::SDL_SetAudioPostmixCallback(device_id, None, std::ptr::null_mut()) };
+    }
+}
+
+unsafe extern "C" fn audio_postmix_callback<F>(
+    userdata: *mut c_void,
+    spec: *const sys::audio::SDL_AudioSpec,
+    buffer: *mut f32,
+    buflen: c_int,
+) where
+    F: FnMut(&AudioSpec, &mut [f32]) + Send + 'static,
+{
+    if buffer.is_null() || spec.is_null() {
+        return;
+    }
+    let callback = &mut *(userdata as *mut F);
+    let spec = AudioSpec::from(&*spec);
+    let samples = std::slice::from_raw_parts_mut(buffer, buflen.max(0) as usize / size_of::<f32>());
+    // panics must not unwind into SDL, the mixed buffer is kept as is instead
+    if catch_unwind(AssertUnwindSafe(|| callback(&spec, samples))).is_err() {
+        log_error(Category::Audio, "AudioDevice postmix callback panicked");
+    }
+}
+
 pub struct AudioStream {
     stream: *mut sys::audio::SDL_AudioStream,
     #[expect(dead_code, reason = "keep the audio subsystem alive")]
     audio_subsystem: AudioSubsystem,
+    // the closures passed to `set_get_callback` and `set_put_callback`, freed after the stream
+    get_callback: Option<TypedStreamCallback>,
+    put_callback: Option<TypedStreamCallback>,
 }
 
 impl Drop for AudioStream {
@@ -1195,10 +1587,54 @@ impl AudioStream {
             Ok(Self {
                 stream,
                 audio_subsystem: audio_subsystem.clone(),
+                get_callback: None,
+                put_callback: None,
             })
         }
     }
 
+    /// Unbinds the stream from its device, if it is bound to one.
+    #[doc(alias = "SDL_UnbindAudioStream")]
+    pub fn unbind(&self) {
+        unsafe { sys::audio::SDL_UnbindAudioStream(self.stream) };
+    }
+
+    /// Moves the stream to `device`, e.g. after `Event::AudioDeviceRemoved` for the specific
+    /// device it was bound to.
+    ///
+    /// Streams bound to a default device opened with
+    /// [`AudioSubsystem::open_playback_device`] never need this, since SDL migrates them to
+    /// the new default itself.
+    ///
+    /// ```no_run
+    /// use sdl3::audio::{AudioFormat, AudioSpec};
+    /// use sdl3::event::Event;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let audio_subsystem = sdl_context.audio().unwrap();
+    /// let spec = AudioSpec::new(Some(48000), Some(2), Some(AudioFormat::f32_sys()));
+    /// let device_id = audio_subsystem.audio_playback_device_ids().unwrap()[0];
+    /// let mut device = sdl3::audio::AudioDevice::open_playback(&audio_subsystem, &device_id, &spec)
+    ///     .unwrap();
+    /// let stream = audio_subsystem.new_stream(Some(&spec), None).unwrap();
+    /// device.bind_stream(&stream).unwrap();
+    ///
+    /// for event in sdl_context.event_pump().unwrap().poll_iter() {
+    ///     if let Event::AudioDeviceRemoved { which, iscapture: false, .. } = event {
+    ///         if which == device_id.id() {
+    ///             // fall back to whatever the default is now
+    ///             device = audio_subsystem.open_playback_device(&spec).unwrap();
+    ///             stream.rebind(&device).unwrap();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "SDL_BindAudioStream")]
+    pub fn rebind(&self, device: &AudioDevice) -> Result<(), Error> {
+        self.unbind();
+        device.bind_stream(self)
+    }
+
     /// Retrieves the source and destination formats of the audio stream.
     ///
     /// Returns a tuple `(src_spec, dst_spec)` where each is an `Option<AudioSpec>`.
@@ -1226,6 +1662,45 @@ impl AudioStream {
         }
     }
 
+    /// Changes the source and destination formats of the audio stream, leaving a format
+    /// unchanged where `None` is given. The side of a stream bound to a device is managed by
+    /// SDL and can't be changed.
+    ///
+    /// Errors if the new format doesn't match the sample type of a callback set with
+    /// [`set_get_callback`](Self::set_get_callback) or
+    /// [`set_put_callback`](Self::set_put_callback).
+    #[doc(alias = "SDL_SetAudioStreamFormat")]
+    pub fn set_format(
+        &self,
+        src_spec: Option<&AudioSpec>,
+        dst_spec: Option<&AudioSpec>,
+    ) -> Result<(), Error> {
+        let conflicts = |callback: &Option<TypedStreamCallback>, spec: Option<&AudioSpec>| {
+            let format = spec.and_then(|spec| spec.format);
+            matches!((callback, format), (Some(callback), Some(format)) if callback.format != format)
+        };
+        if conflicts(&self.get_callback, src_spec) || conflicts(&self.put_callback, dst_spec) {
+            return Err(Error(
+                "AudioStream format does not match the callback sample type".to_owned(),
+            ));
+        }
+
+        let sdl_src_spec = src_spec.map(sys::audio::SDL_AudioSpec::from);
+        let sdl_dst_spec = dst_spec.map(sys::audio::SDL_AudioSpec::from);
+        let result = unsafe {
+            sys::audio::SDL_SetAudioStreamFormat(
+                self.stream,
+                crate::util::option_to_ptr(sdl_src_spec.as_ref()),
+                crate::util::option_to_ptr(sdl_dst_spec.as_ref()),
+            )
+        };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
     /// Retrieves the gain of the audio stream.
     ///
     /// Returns the gain as a `f32` on success, or an error message on failure.
@@ -1239,8 +1714,101 @@ impl AudioStream {
         }
     }
 
-    /// Pauses playback of the audio stream.
-    #[doc(alias = "SDL_PauseAudioStream")]
+    /// Sets the gain of the audio stream, applied as its data is read. `1.0` leaves the volume
+    /// unchanged, `0.0` mutes and larger values amplify.
+    #[doc(alias = "SDL_SetAudioStreamGain")]
+    pub fn set_gain(&self, gain: f32) -> Result<(), Error> {
+        let result = unsafe { sys::audio::SDL_SetAudioStreamGain(self.stream, gain) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Retrieves the channel map of the data put into the stream, or `None` if it uses the
+    /// default order for its channel count.
+    #[doc(alias = "SDL_GetAudioStreamInputChannelMap")]
+    pub fn get_input_channel_map(&self) -> Option<Vec<i32>> {
+        let mut count: c_int = 0;
+        unsafe {
+            let map = sys::audio::SDL_GetAudioStreamInputChannelMap(self.stream, &mut count);
+            channel_map_from_ll(map, count)
+        }
+    }
+
+    /// Retrieves the channel map of the data read from the stream, or `None` if it uses the
+    /// default order for its channel count.
+    #[doc(alias = "SDL_GetAudioStreamOutputChannelMap")]
+    pub fn get_output_channel_map(&self) -> Option<Vec<i32>> {
+        let mut count: c_int = 0;
+        unsafe {
+            let map = sys::audio::SDL_GetAudioStreamOutputChannelMap(self.stream, &mut count);
+            channel_map_from_ll(map, count)
+        }
+    }
+
+    /// Sets the order of the channels in the data put into the stream, or resets it to the
+    /// default with `None`.
+    ///
+    /// Entry `i` of `map` is the channel that channel `i` is moved to, or `-1` to mute it:
+    /// `[1, 0]` swaps the channels of a stereo signal and `[1, 1]` plays its right channel on
+    /// both. The map must have one entry per channel of the stream's format.
+    #[doc(alias = "SDL_SetAudioStreamInputChannelMap")]
+    pub fn set_input_channel_map(&self, map: Option<&[i32]>) -> Result<(), Error> {
+        let (ptr, count) = channel_map_to_ll(map)?;
+        let result =
+            unsafe { sys::audio::SDL_SetAudioStreamInputChannelMap(self.stream, ptr, count) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets the order of the channels in the data read from the stream, or resets it to the
+    /// default with `None`, e.g. to route output to specific outputs of a multi-channel
+    /// interface. See [`set_input_channel_map`](Self::set_input_channel_map) for the format of
+    /// `map`.
+    #[doc(alias = "SDL_SetAudioStreamOutputChannelMap")]
+    pub fn set_output_channel_map(&self, map: Option<&[i32]>) -> Result<(), Error> {
+        let (ptr, count) = channel_map_to_ll(map)?;
+        let result =
+            unsafe { sys::audio::SDL_SetAudioStreamOutputChannelMap(self.stream, ptr, count) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Retrieves the frequency ratio of the audio stream.
+    #[doc(alias = "SDL_GetAudioStreamFrequencyRatio")]
+    pub fn get_frequency_ratio(&self) -> Result<f32, Error> {
+        let ratio = unsafe { sys::audio::SDL_GetAudioStreamFrequencyRatio(self.stream) };
+        if ratio > 0.0 {
+            Ok(ratio)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets the frequency ratio of the audio stream, speeding up or slowing down playback and
+    /// shifting its pitch along, e.g. for engine sounds or fast-forward. `1.0` is normal speed,
+    /// `2.0` plays twice as fast; SDL accepts values from `0.01` to `100.0`.
+    #[doc(alias = "SDL_SetAudioStreamFrequencyRatio")]
+    pub fn set_frequency_ratio(&self, ratio: f32) -> Result<(), Error> {
+        let result = unsafe { sys::audio::SDL_SetAudioStreamFrequencyRatio(self.stream, ratio) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Pauses the device this stream is bound to, which pauses every stream bound to it, e.g.
+    /// while the game is unfocused or loading.
+    #[doc(alias = "SDL_PauseAudioStreamDevice")]
     pub fn pause(&self) -> Result<(), Error> {
         let result = unsafe { sys::audio::SDL_PauseAudioStreamDevice(self.stream) };
         if result {
@@ -1250,8 +1818,8 @@ impl AudioStream {
         }
     }
 
-    /// Resumes playback of the audio stream.
-    #[doc(alias = "SDL_ResumeAudioStream")]
+    /// Resumes the device this stream is bound to.
+    #[doc(alias = "SDL_ResumeAudioStreamDevice")]
     pub fn resume(&self) -> Result<(), Error> {
         let result = unsafe { sys::audio::SDL_ResumeAudioStreamDevice(self.stream) };
         if result {
@@ -1261,6 +1829,12 @@ impl AudioStream {
         }
     }
 
+    /// Returns `true` if the device this stream is bound to is paused.
+    #[doc(alias = "SDL_AudioStreamDevicePaused")]
+    pub fn is_paused(&self) -> bool {
+        unsafe { sys::audio::SDL_AudioStreamDevicePaused(self.stream) }
+    }
+
     /// Gets the number of converted/resampled bytes available.
     #[doc(alias = "SDL_GetAudioStreamAvailable")]
     pub fn available_bytes(&self) -> Result<i32, Error> {
@@ -1272,6 +1846,139 @@ impl AudioStream {
         }
     }
 
+    /// Sets a callback that generates audio on demand, called whenever the stream is read from
+    /// and needs more data, e.g. by the device it is bound to.
+    ///
+    /// The callback fills the samples SDL asks for, which are then put into the stream. It
+    /// runs on the audio thread, replacing any previous get callback. The input format of the
+    /// stream must be `T::audio_format()`.
+    ///
+    /// ```no_run
+    /// use sdl3::audio::{AudioFormat, AudioSpec};
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let audio_subsystem = sdl_context.audio().unwrap();
+    /// let spec = AudioSpec::new(Some(48000), Some(1), Some(AudioFormat::f32_sys()));
+    /// let mut stream = audio_subsystem
+    ///     .default_playback_device()
+    ///     .open_device_stream(Some(&spec))
+    ///     .unwrap();
+    ///
+    /// let mut phase = 0.0f32;
+    /// stream
+    ///     .set_get_callback(move |out: &mut [f32]| {
+    ///         for sample in out {
+    ///             *sample = (phase * std::f32::consts::TAU).sin() * 0.25;
+    ///             phase = (phase + 440.0 / 48000.0) % 1.0;
+    ///         }
+    ///     })
+    ///     .unwrap();
+    /// stream.resume().unwrap();
+    /// ```
+    #[doc(alias = "SDL_SetAudioStreamGetCallback")]
+    pub fn set_get_callback<T, F>(&mut self, callback: F) -> Result<(), Error>
+    where
+        T: AudioFormatNum + Send,
+        F: FnMut(&mut [T]) + Send + 'static,
+    {
+        check_callback_format::<T>(self.get_format()?.0)?;
+        let mut callback = Box::new(StreamCallback {
+            callback,
+            buffer: Vec::<T>::new(),
+        });
+        let userdata = &mut *callback as *mut StreamCallback<T, F> as *mut c_void;
+        let result = unsafe {
+            sys::audio::SDL_SetAudioStreamGetCallback(
+                self.stream,
+                Some(audio_stream_get_callback::<T, F>),
+                userdata,
+            )
+        };
+        if result {
+            // SDL no longer calls the previous callback, so it can be freed
+            self.get_callback = Some(TypedStreamCallback {
+                format: T::audio_format(),
+                _callback: callback,
+            });
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets a callback that receives the converted samples whenever data is put into the
+    /// stream, e.g. by the recording device it is bound to.
+    ///
+    /// The samples are taken out of the stream before the callback is called, so they can't
+    /// be read from it afterwards. The callback runs on the thread putting the data, replacing
+    /// any previous put callback. The output format of the stream must be
+    /// `T::audio_format()`.
+    #[doc(alias = "SDL_SetAudioStreamPutCallback")]
+    pub fn set_put_callback<T, F>(&mut self, callback: F) -> Result<(), Error>
+    where
+        T: AudioFormatNum + Send,
+        F: FnMut(&[T]) + Send + 'static,
+    {
+        check_callback_format::<T>(self.get_format()?.1)?;
+        let mut callback = Box::new(StreamCallback {
+            callback,
+            buffer: Vec::<T>::new(),
+        });
+        let userdata = &mut *callback as *mut StreamCallback<T, F> as *mut c_void;
+        let result = unsafe {
+            sys::audio::SDL_SetAudioStreamPutCallback(
+                self.stream,
+                Some(audio_stream_put_callback::<T, F>),
+                userdata,
+            )
+        };
+        if result {
+            // SDL no longer calls the previous callback, so it can be freed
+            self.put_callback = Some(TypedStreamCallback {
+                format: T::audio_format(),
+                _callback: callback,
+            });
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Gets the number of bytes put into the stream that have not been converted yet,
+    /// e.g. to keep a playback stream from running dry without queueing too far ahead.
+    #[doc(alias = "SDL_GetAudioStreamQueued")]
+    pub fn queued_bytes(&self) -> Result<i32, Error> {
+        let queued = unsafe { sys::audio::SDL_GetAudioStreamQueued(self.stream) };
+        if queued == -1 {
+            Err(get_error())
+        } else {
+            Ok(queued)
+        }
+    }
+
+    /// Converts all data put into the stream so far, including a trailing partial frame, so
+    /// it can all be read. Use this when no more data will be put, e.g. at the end of a sound.
+    #[doc(alias = "SDL_FlushAudioStream")]
+    pub fn flush(&self) -> Result<(), Error> {
+        let result = unsafe { sys::audio::SDL_FlushAudioStream(self.stream) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Drops all data in the stream, both queued and already converted.
+    #[doc(alias = "SDL_ClearAudioStream")]
+    pub fn clear(&self) -> Result<(), Error> {
+        let result = unsafe { sys::audio::SDL_ClearAudioStream(self.stream) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
     /// Converts a slice of bytes to a f32 sample based on AudioFormat.
     /// Returns a Result containing the converted f32 or an error message.
     fn read_bytes_to_f32(&self, chunk: &[u8]) -> Result<f32, Error> {
@@ -1371,6 +2078,7 @@ impl AudioStream {
     }
 
     /// Adds data to the stream.
+    #[doc(alias = "SDL_PutAudioStreamData")]
     pub fn put_data(&self, buf: &[u8]) -> Result<(), Error> {
         let result = unsafe {
             sys::audio::SDL_PutAudioStreamData(self.stream, buf.as_ptr().cast(), buf.len() as i32)
@@ -1381,6 +2089,148 @@ impl AudioStream {
             Err(get_error())
         }
     }
+
+    /// Adds samples to the stream.
+    ///
+    /// The samples are put as-is, so the input format of the stream should be
+    /// `T::audio_format()`, e.g. by creating it from an [`AudioSpec`] with that format.
+    #[doc(alias = "SDL_PutAudioStreamData")]
+    pub fn put_samples<T: AudioFormatNum>(&self, samples: &[T]) -> Result<(), Error> {
+        let result = unsafe {
+            sys::audio::SDL_PutAudioStreamData(
+                self.stream,
+                samples.as_ptr().cast(),
+                std::mem::size_of_val(samples) as c_int,
+            )
+        };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Reads converted samples into `buf`, returning the number of samples read.
+    ///
+    /// The samples are read as-is, so the output format of the stream should be
+    /// `T::audio_format()`. Partial samples are left in the stream.
+    #[doc(alias = "SDL_GetAudioStreamData")]
+    pub fn get_samples<T: AudioFormatNum>(&mut self, buf: &mut [T]) -> Result<usize, Error> {
+        let sample_size = std::mem::size_of::<T>();
+        // only ask for whole samples, so none are split between reads
+        let available = self.available_bytes()? as usize / sample_size * sample_size;
+        let len = std::mem::size_of_val(buf).min(available);
+        let read = unsafe {
+            sys::audio::SDL_GetAudioStreamData(self.stream, buf.as_mut_ptr().cast(), len as c_int)
+        };
+        if read == -1 {
+            Err(get_error())
+        } else {
+            Ok(read as usize / sample_size)
+        }
+    }
+}
+
+fn channel_map_to_ll(map: Option<&[i32]>) -> Result<(*const c_int, c_int), Error> {
+    match map {
+        Some(map) => {
+            let count = c_int::try_from(map.len())
+                .map_err(|_| Error("Channel map is too long".to_owned()))?;
+            Ok((map.as_ptr(), count))
+        }
+        None => Ok((std::ptr::null(), 0)),
+    }
+}
+
+/// A closure set with `set_get_callback` or `set_put_callback`, with the sample format it
+/// expects.
+struct TypedStreamCallback {
+    format: AudioFormat,
+    _callback: Box<dyn Send>,
+}
+
+/// The userdata of the stream callbacks: the closure and a buffer reused between calls, so the
+/// audio thread doesn't allocate every time.
+struct StreamCallback<T, F> {
+    callback: F,
+    buffer: Vec<T>,
+}
+
+fn check_callback_format<T: AudioFormatNum>(spec: Option<AudioSpec>) -> Result<(), Error> {
+    match spec.and_then(|spec| spec.format) {
+        Some(format) if format != T::audio_format() => Err(Error(
+            "AudioStream format does not match the callback sample type".to_owned(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+unsafe extern "C" fn audio_stream_get_callback<T, F>(
+    userdata: *mut c_void,
+    stream: *mut sys::audio::SDL_AudioStream,
+    additional_amount: c_int,
+    _total_amount: c_int,
+) where
+    T: AudioFormatNum,
+    F: FnMut(&mut [T]) + Send + 'static,
+{
+    let sample_count = additional_amount.max(0) as usize / size_of::<T>();
+    if sample_count == 0 {
+        return;
+    }
+
+    let state = &mut *(userdata as *mut StreamCallback<T, F>);
+    state.buffer.clear();
+    state.buffer.resize(sample_count, T::SILENCE);
+    // panics must not unwind into SDL, skip the samples instead
+    if catch_unwind(AssertUnwindSafe(|| (state.callback)(&mut state.buffer))).is_err() {
+        log_error(Category::Audio, "AudioStream get callback panicked");
+        return;
+    }
+
+    let len = std::mem::size_of_val(state.buffer.as_slice()) as c_int;
+    if !sys::audio::SDL_PutAudioStreamData(stream, state.buffer.as_ptr().cast(), len) {
+        log_error(
+            Category::Audio,
+            &format!("Error pushing audio data into stream: {}", get_error()),
+        );
+    }
+}
+
+unsafe extern "C" fn audio_stream_put_callback<T, F>(
+    userdata: *mut c_void,
+    stream: *mut sys::audio::SDL_AudioStream,
+    _additional_amount: c_int,
+    _total_amount: c_int,
+) where
+    T: AudioFormatNum,
+    F: FnMut(&[T]) + Send + 'static,
+{
+    // the amounts count input bytes, the converted output may differ
+    let available = sys::audio::SDL_GetAudioStreamAvailable(stream);
+    let sample_count = available.max(0) as usize / size_of::<T>();
+    if sample_count == 0 {
+        return;
+    }
+
+    let state = &mut *(userdata as *mut StreamCallback<T, F>);
+    state.buffer.clear();
+    state.buffer.resize(sample_count, T::SILENCE);
+    let len = std::mem::size_of_val(state.buffer.as_slice()) as c_int;
+    let read = sys::audio::SDL_GetAudioStreamData(stream, state.buffer.as_mut_ptr().cast(), len);
+    if read < 0 {
+        log_error(
+            Category::Audio,
+            &format!("Error getting audio data from stream: {}", get_error()),
+        );
+        return;
+    }
+
+    state.buffer.truncate(read as usize / size_of::<T>());
+    // panics must not unwind into SDL, drop the samples instead
+    if catch_unwind(AssertUnwindSafe(|| (state.callback)(&state.buffer))).is_err() {
+        log_error(Category::Audio, "AudioStream put callback panicked");
+    }
 }
 
 impl Read for AudioStream {
@@ -1404,6 +2254,23 @@ impl Read for AudioStream {
     }
 }
 
+impl Write for AudioStream {
+    /// Puts audio data into the stream, e.g. to feed a playback stream from code that writes
+    /// to an `io::Write`. Like [`put_data`](AudioStream::put_data), this takes bytes in the
+    /// input format of the stream.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(c_int::MAX as usize);
+        self.put_data(&buf[..len]).map_err(io::Error::other)?;
+        Ok(len)
+    }
+
+    /// Does nothing: data is available to the device as soon as it is written. Call
+    /// [`AudioStream::flush`] to also convert a trailing partial frame at the end of a sound.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 // Streams with callbacks
 pub struct AudioStreamWithCallback<CB> {
     base_stream: AudioStream,
@@ -1416,6 +2283,12 @@ impl<CB> Drop for AudioStreamWithCallback<CB> {
         // `base_stream` will be dropped automatically.
         if !self.c_userdata.is_null() {
             unsafe {
+                // The stream is only destroyed after this, so make sure SDL is done with the
+                // callback before freeing it. This waits for a running callback to return.
+                let stream = self.base_stream.stream;
+                sys::audio::SDL_SetAudioStreamGetCallback(stream, None, std::ptr::null_mut());
+                sys::audio::SDL_SetAudioStreamPutCallback(stream, None, std::ptr::null_mut());
+
                 // Drop the callback box
                 let _ = Box::from_raw(self.c_userdata as *mut CB);
             }
@@ -1434,6 +2307,68 @@ impl<CB> AudioStreamWithCallback<CB> {
     pub fn resume(&self) -> Result<(), Error> {
         self.base_stream.resume()
     }
+
+    /// Returns `true` if the audio stream is paused.
+    pub fn is_paused(&self) -> bool {
+        self.base_stream.is_paused()
+    }
+
+    /// Retrieves the gain of the audio stream.
+    pub fn get_gain(&self) -> Result<f32, Error> {
+        self.base_stream.get_gain()
+    }
+
+    /// Sets the gain of the audio stream, see [`AudioStream::set_gain`].
+    pub fn set_gain(&self, gain: f32) -> Result<(), Error> {
+        self.base_stream.set_gain(gain)
+    }
+
+    /// Sets the frequency ratio of the audio stream, see [`AudioStream::set_frequency_ratio`].
+    pub fn set_frequency_ratio(&self, ratio: f32) -> Result<(), Error> {
+        self.base_stream.set_frequency_ratio(ratio)
+    }
+}
+
+/// A playback device fed by queueing samples, opened with [`AudioSubsystem::open_queue`].
+pub struct AudioQueue<Channel: AudioFormatNum> {
+    stream: AudioStream,
+    spec: AudioSpec,
+    _marker: PhantomData<Channel>,
+}
+
+impl<Channel: AudioFormatNum> AudioQueue<Channel> {
+    /// The format the device was opened with.
+    pub fn spec(&self) -> &AudioSpec {
+        &self.spec
+    }
+
+    /// Queues interleaved samples to be played after those already queued.
+    #[doc(alias = "SDL_QueueAudio")]
+    pub fn queue_audio(&self, data: &[Channel]) -> Result<(), Error> {
+        self.stream.put_samples(data)
+    }
+
+    /// The number of bytes queued but not yet played.
+    #[doc(alias = "SDL_GetQueuedAudioSize")]
+    pub fn size(&self) -> u32 {
+        self.stream.queued_bytes().map_or(0, |queued| queued as u32)
+    }
+
+    /// Drops all queued samples.
+    #[doc(alias = "SDL_ClearQueuedAudio")]
+    pub fn clear(&self) -> Result<(), Error> {
+        self.stream.clear()
+    }
+
+    /// Pauses playback.
+    pub fn pause(&self) -> Result<(), Error> {
+        self.stream.pause()
+    }
+
+    /// Starts or resumes playback.
+    pub fn resume(&self) -> Result<(), Error> {
+        self.stream.resume()
+    }
 }
 
 pub trait AudioRecordingCallback<Channel>: Send + 'static
@@ -1462,12 +2397,13 @@ unsafe extern "C" fn audio_recording_stream_callback<CB, Channel>(
     let buffer_ptr = buffer.as_mut_ptr() as *mut c_void;
     let ret = sys::audio::SDL_GetAudioStreamData(sdl_stream, buffer_ptr, len);
 
-    if ret != len {
+    if ret < 0 {
         eprintln!("Error getting audio data from stream: {}", get_error());
         return;
     }
 
-    // Call the user's callback with the captured audio data
+    // Call the user's callback with the captured audio data, which may be less than requested
+    buffer.truncate(ret as usize / size_of::<Channel>());
     callback.callback(&buffer);
 }
 
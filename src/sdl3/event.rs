@@ -3,6 +3,7 @@ Event Handling
  */
 
 use std::borrow::ToOwned;
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::ffi::CStr;
@@ -10,20 +11,29 @@ use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::mem;
 use std::mem::transmute;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::ptr;
-use std::sync::Mutex;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+#[cfg(feature = "futures")]
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
 
 use crate::gamepad;
 use crate::gamepad::{Axis, Button};
 use crate::get_error;
 use crate::joystick;
-use crate::joystick::HatState;
+use crate::joystick::{HatState, PowerLevel};
 use crate::keyboard;
 use crate::keyboard::Keycode;
 use crate::keyboard::Mod;
 use crate::keyboard::Scancode;
 use crate::mouse;
 use crate::mouse::{MouseButton, MouseState, MouseWheelDirection};
+use crate::pen::{PenAxis, PenId, PenInputFlags};
 use crate::sys;
 use crate::sys::events::SDL_EventFilter;
 use crate::video::{Display, Orientation};
@@ -42,6 +52,15 @@ use sys::stdinc::Uint16;
 struct CustomEventTypeMaps {
     sdl_id_to_type_id: HashMap<u32, ::std::any::TypeId>,
     type_id_to_sdl_id: HashMap<::std::any::TypeId, u32>,
+    /// Payloads that have not been taken out of an event yet, keyed by the unique token pushed
+    /// in `data2`, with their address and type.
+    pending_payloads: HashMap<usize, PendingPayload>,
+    next_payload_token: usize,
+}
+
+struct PendingPayload {
+    ptr: usize,
+    type_id: ::std::any::TypeId,
 }
 
 impl CustomEventTypeMaps {
@@ -49,6 +68,9 @@ impl CustomEventTypeMaps {
         CustomEventTypeMaps {
             sdl_id_to_type_id: HashMap::new(),
             type_id_to_sdl_id: HashMap::new(),
+            pending_payloads: HashMap::new(),
+            // 0 is left out so that events with a null `data2` never match
+            next_payload_token: 1,
         }
     }
 }
@@ -58,6 +80,11 @@ lazy_static! {
         Mutex::new(CustomEventTypeMaps::new());
 }
 
+/// The closure type of [`EventSubsystem::set_event_filter`](crate::EventSubsystem::set_event_filter).
+pub type EventFilterCallback = Box<dyn FnMut(&Event) -> bool + Send + 'static>;
+
+static EVENT_FILTER: Mutex<Option<EventFilterCallback>> = Mutex::new(None);
+
 impl crate::EventSubsystem {
     /// Removes all events in the event queue that match the specified event type.
     #[doc(alias = "SDL_FlushEvent")]
@@ -71,6 +98,37 @@ impl crate::EventSubsystem {
         unsafe { sys::events::SDL_FlushEvents(min_type, max_type) };
     }
 
+    /// Enables or disables events of one type at the source. Disabled events are never added to
+    /// the queue, and any already queued are removed, e.g. to turn off sensor, pen motion or
+    /// touch events an application doesn't use.
+    ///
+    /// Takes an [`EventType`] or the raw type of events that have no [`EventType`] yet.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl3::event::EventType;
+    /// use sdl3::sys::events::SDL_EVENT_SENSOR_UPDATE;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let event_subsystem = sdl_context.event().unwrap();
+    ///
+    /// event_subsystem.set_event_enabled(EventType::FingerMotion, false);
+    /// event_subsystem.set_event_enabled(EventType::PenMotion, false);
+    /// event_subsystem.set_event_enabled(SDL_EVENT_SENSOR_UPDATE, false);
+    /// assert!(!event_subsystem.event_enabled(EventType::PenMotion));
+    /// ```
+    #[doc(alias = "SDL_SetEventEnabled")]
+    pub fn set_event_enabled<T: Into<u32>>(&self, event_type: T, enabled: bool) {
+        unsafe { sys::events::SDL_SetEventEnabled(event_type.into(), enabled) };
+    }
+
+    /// Returns whether events of this type are added to the queue, see
+    /// [`set_event_enabled`](Self::set_event_enabled).
+    #[doc(alias = "SDL_EventEnabled")]
+    pub fn event_enabled<T: Into<u32>>(&self, event_type: T) -> bool {
+        unsafe { sys::events::SDL_EventEnabled(event_type.into()) }
+    }
+
     /// Reads the events at the front of the event queue, until the maximum amount
     /// of events is read.
     ///
@@ -96,29 +154,139 @@ impl crate::EventSubsystem {
     where
         B: FromIterator<Event>,
     {
-        unsafe {
-            let mut events = Vec::with_capacity(max_amount as usize);
-
-            let result = {
-                let events_ptr = events.as_mut_ptr();
-
-                sys::events::SDL_PeepEvents(
-                    events_ptr,
-                    max_amount as c_int,
-                    sys::events::SDL_PEEKEVENT,
-                    sys::events::SDL_EVENT_FIRST.into(),
-                    sys::events::SDL_EVENT_LAST.into(),
-                )
-            };
+        match self.peek_events_in_range(
+            max_amount,
+            sys::events::SDL_EVENT_FIRST.into(),
+            sys::events::SDL_EVENT_LAST.into(),
+        ) {
+            Ok(events) => events,
+            // The only error possible is "Couldn't lock event queue"
+            Err(err) => panic!("{}", err),
+        }
+    }
 
-            if result < 0 {
-                // The only error possible is "Couldn't lock event queue"
-                panic!("{}", get_error());
-            } else {
-                events.set_len(result as usize);
+    /// Reads up to `max_amount` events at the front of the event queue whose type is within
+    /// `min_type..=max_type`, without removing them.
+    ///
+    /// Unlike the `poll_*` and `wait_*` functions, this doesn't pump events, so call
+    /// [`EventPump::pump_events`](crate::EventPump::pump_events) first to see new input.
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn peek_events_in_range<B>(
+        &self,
+        max_amount: u32,
+        min_type: u32,
+        max_type: u32,
+    ) -> Result<B, Error>
+    where
+        B: FromIterator<Event>,
+    {
+        peep_events(max_amount, sys::events::SDL_PEEKEVENT, min_type, max_type)
+    }
 
-                events.into_iter().map(Event::from_ll).collect()
-            }
+    /// Removes and returns up to `max_amount` events at the front of the event queue whose type
+    /// is within `min_type..=max_type`, e.g. to route one category of events separately or to
+    /// record them for replay.
+    ///
+    /// Like [`peek_events_in_range`](Self::peek_events_in_range), this doesn't pump events.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl3::event::{Event, EventType};
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let event_subsystem = sdl_context.event().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    ///
+    /// event_pump.pump_events();
+    /// let keyboard_events: Vec<Event> = event_subsystem
+    ///     .get_events_in_range(
+    ///         1024,
+    ///         EventType::KeyDown as u32,
+    ///         EventType::KeyUp as u32,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn get_events_in_range<B>(
+        &self,
+        max_amount: u32,
+        min_type: u32,
+        max_type: u32,
+    ) -> Result<B, Error>
+    where
+        B: FromIterator<Event>,
+    {
+        peep_events(max_amount, sys::events::SDL_GETEVENT, min_type, max_type)
+    }
+
+    /// Removes and returns the event at the front of the event queue, without pumping events.
+    ///
+    /// Only one [`EventPump`](crate::EventPump) can exist, as pumping invalidates keyboard
+    /// state and window surfaces borrowed from it. Reading events that were already queued
+    /// doesn't, so any number of `EventSubsystem` handles can do it, e.g. when an app framework
+    /// owns the `EventPump` and pumps events once per frame.
+    ///
+    /// # Example
+    /// ```no_run
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    /// let event_subsystem = sdl_context.event().unwrap();
+    ///
+    /// // owned by the framework's loop
+    /// event_pump.pump_events();
+    ///
+    /// // anywhere else on the main thread
+    /// for event in event_subsystem.poll_queued_iter() {
+    ///     println!("{:?}", event);
+    /// }
+    /// ```
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn poll_queued_event(&self) -> Option<Event> {
+        self.get_events_in_range::<Vec<Event>>(
+            1,
+            sys::events::SDL_EVENT_FIRST.into(),
+            sys::events::SDL_EVENT_LAST.into(),
+        )
+        .ok()?
+        .pop()
+    }
+
+    /// Returns an iterator over the events already in the event queue, removing them as it
+    /// goes, see [`poll_queued_event`](Self::poll_queued_event).
+    pub fn poll_queued_iter(&self) -> impl Iterator<Item = Event> + '_ {
+        std::iter::from_fn(move || self.poll_queued_event())
+    }
+
+    /// Adds events to the back of the event queue in one go, e.g. to replay recorded input.
+    ///
+    /// Unlike [`push_event`](Self::push_event), the events bypass the event filter and event
+    /// watchers. Returns the number of events added, which is less than `events.len()` if the
+    /// queue filled up.
+    #[doc(alias = "SDL_PeepEvents")]
+    pub fn add_events(&self, events: &[Event]) -> Result<usize, Error> {
+        let mut raw_events = events
+            .iter()
+            .map(|event| {
+                event.clone().to_ll().ok_or_else(|| {
+                    Error("Cannot push unsupported event type to the queue".to_owned())
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let result = unsafe {
+            sys::events::SDL_PeepEvents(
+                raw_events.as_mut_ptr(),
+                raw_events.len() as c_int,
+                sys::events::SDL_ADDEVENT,
+                sys::events::SDL_EVENT_FIRST.into(),
+                sys::events::SDL_EVENT_LAST.into(),
+            )
+        };
+
+        if result < 0 {
+            Err(get_error())
+        } else {
+            Ok(result as usize)
         }
     }
 
@@ -186,8 +354,9 @@ impl crate::EventSubsystem {
     #[inline(always)]
     pub fn register_custom_event<T: ::std::any::Any>(&self) -> Result<(), Error> {
         use std::any::TypeId;
-        let event_id = *(unsafe { self.register_events(1) })?.first().unwrap();
-        let mut cet = CUSTOM_EVENT_TYPES.lock().unwrap();
+        let mut cet = CUSTOM_EVENT_TYPES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
         let type_id = TypeId::of::<Box<T>>();
 
         if cet.type_id_to_sdl_id.contains_key(&type_id) {
@@ -195,6 +364,7 @@ impl crate::EventSubsystem {
                 "The same event type can not be registered twice!".to_owned(),
             ));
         }
+        let event_id = *(unsafe { self.register_events(1) })?.first().unwrap();
 
         cet.sdl_id_to_type_id.insert(event_id, type_id);
         cet.type_id_to_sdl_id.insert(type_id, event_id);
@@ -230,7 +400,7 @@ impl crate::EventSubsystem {
     ///     assert_eq!(e2.a, 42);
     /// }
     /// ```
-    pub fn push_custom_event<T: ::std::any::Any>(&self, event: T) -> Result<(), Error> {
+    pub fn push_custom_event<T: ::std::any::Any + Send>(&self, event: T) -> Result<(), Error> {
         self.event_sender().push_custom_event(event)
     }
 
@@ -259,12 +429,122 @@ impl crate::EventSubsystem {
     ///     dbg!(event);
     /// });
     /// ```
+    ///
+    /// Watchers run as soon as an event is added, so they are the way to redraw while the OS
+    /// runs a modal loop during window resizes or moves and the normal event loop is blocked.
+    /// They may be called from whichever thread adds the event.
+    ///
+    /// # Example: redraw during live resizes
+    /// ```no_run
+    /// use sdl3::event::{Event, WindowEvent};
+    ///
+    /// let sdl = sdl3::init().unwrap();
+    /// let ev = sdl.event().unwrap();
+    /// let mut redraw = |_width: i32, _height: i32| { /* render a frame */ };
+    ///
+    /// let _event_watch = ev.add_event_watch(move |event| match event {
+    ///     Event::Window {
+    ///         win_event: WindowEvent::Resized(w, h),
+    ///         ..
+    ///     } => redraw(w, h),
+    ///     Event::Window {
+    ///         win_event: WindowEvent::Exposed,
+    ///         ..
+    ///     } => redraw(0, 0),
+    ///     _ => {}
+    /// });
+    /// ```
+    #[doc(alias = "SDL_AddEventWatch")]
     pub fn add_event_watch<'a, CB: EventWatchCallback + 'a>(
         &self,
         callback: CB,
     ) -> EventWatch<'a, CB> {
         EventWatch::add(callback)
     }
+
+    /// Returns a stream of the events added to the event queue, for async code to await
+    /// events instead of polling for them.
+    ///
+    /// The stream receives a copy of each event through an event watch, so the events stay in
+    /// the queue for other readers, and copies pile up until the stream is polled. It does not
+    /// pump events: the main thread still has to call
+    /// [`EventPump::pump_events`](crate::EventPump::pump_events) regularly for OS events to
+    /// arrive, while events pushed from other threads arrive right away. Events added with
+    /// [`add_events`](Self::add_events) skip event watches and never reach the stream.
+    #[cfg(feature = "futures")]
+    #[doc(alias = "SDL_AddEventWatch")]
+    pub fn event_stream(&self) -> Result<EventStream, Error> {
+        EventStream::new(self.clone())
+    }
+
+    /// Sets a filter that decides whether each event is added to the event queue, replacing any
+    /// previous filter. Events for which `filter` returns `false` are dropped.
+    ///
+    /// The filter runs as events are added, possibly on other threads, so it should be quick. It
+    /// must not set or clear the event filter itself.
+    /// Events added from inside the filter itself are not filtered, nor are events added with
+    /// [`add_events`](Self::add_events). Event watchers only see the events the filter lets
+    /// through. A `Quit` event raised by an interrupt signal, such as Ctrl-C, bypasses the
+    /// filter, while a window manager's request to close the application is filtered; if it is
+    /// dropped, the window stays open if possible.
+    ///
+    /// # Example: drop relative mouse motion
+    /// ```no_run
+    /// use sdl3::event::Event;
+    ///
+    /// let sdl = sdl3::init().unwrap();
+    /// let ev = sdl.event().unwrap();
+    ///
+    /// ev.set_event_filter(|event| !matches!(event, Event::MouseMotion { .. }));
+    /// ```
+    #[doc(alias = "SDL_SetEventFilter")]
+    pub fn set_event_filter<F: FnMut(&Event) -> bool + Send + 'static>(&self, filter: F) {
+        *EVENT_FILTER.lock().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(filter));
+        unsafe { sys::events::SDL_SetEventFilter(Some(event_filter_marshall), ptr::null_mut()) };
+    }
+
+    /// Removes the filter set with [`set_event_filter`](Self::set_event_filter), so every event
+    /// is added to the queue again.
+    #[doc(alias = "SDL_SetEventFilter")]
+    pub fn clear_event_filter(&self) {
+        unsafe { sys::events::SDL_SetEventFilter(None, ptr::null_mut()) };
+        *EVENT_FILTER.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Runs `filter` once over the events currently in the queue, removing those for which it
+    /// returns `false`, e.g. to coalesce a burst of events before processing them.
+    ///
+    /// # Example: keep only the latest mouse motion
+    /// ```no_run
+    /// use sdl3::event::Event;
+    ///
+    /// let sdl = sdl3::init().unwrap();
+    /// let mut event_pump = sdl.event_pump().unwrap();
+    /// let ev = sdl.event().unwrap();
+    ///
+    /// event_pump.pump_events();
+    /// let mut motions = ev
+    ///     .peek_events::<Vec<Event>>(1024)
+    ///     .iter()
+    ///     .filter(|event| matches!(event, Event::MouseMotion { .. }))
+    ///     .count();
+    /// ev.filter_events(|event| match event {
+    ///     Event::MouseMotion { .. } => {
+    ///         motions -= 1;
+    ///         motions == 0
+    ///     }
+    ///     _ => true,
+    /// });
+    /// ```
+    #[doc(alias = "SDL_FilterEvents")]
+    pub fn filter_events<F: FnMut(&Event) -> bool>(&self, mut filter: F) {
+        unsafe {
+            sys::events::SDL_FilterEvents(
+                Some(filter_events_marshall::<F>),
+                &mut filter as *mut F as *mut c_void,
+            )
+        };
+    }
 }
 
 /// Types of events that can be delivered.
@@ -280,6 +560,7 @@ pub enum EventType {
     AppDidEnterBackground = sys::events::SDL_EVENT_DID_ENTER_BACKGROUND.0,
     AppWillEnterForeground = sys::events::SDL_EVENT_WILL_ENTER_FOREGROUND.0,
     AppDidEnterForeground = sys::events::SDL_EVENT_DID_ENTER_FOREGROUND.0,
+    SystemThemeChanged = sys::events::SDL_EVENT_SYSTEM_THEME_CHANGED.0,
 
     DisplayAdded = sys::events::SDL_EVENT_DISPLAY_ADDED.0,
     DisplayRemoved = sys::events::SDL_EVENT_DISPLAY_REMOVED.0,
@@ -306,11 +587,14 @@ pub enum EventType {
     WindowHitTest = sys::events::SDL_EVENT_WINDOW_HIT_TEST.0,
     WindowICCProfileChanged = sys::events::SDL_EVENT_WINDOW_ICCPROF_CHANGED.0,
     WindowDisplayChanged = sys::events::SDL_EVENT_WINDOW_DISPLAY_CHANGED.0,
+    WindowDisplayScaleChanged = sys::events::SDL_EVENT_WINDOW_DISPLAY_SCALE_CHANGED.0,
+    WindowHDRStateChanged = sys::events::SDL_EVENT_WINDOW_HDR_STATE_CHANGED.0,
 
     // TODO: SysWM = sys::events::SDL_EVENT_SYSWM .0,
     KeyDown = sys::events::SDL_EVENT_KEY_DOWN.0,
     KeyUp = sys::events::SDL_EVENT_KEY_UP.0,
     TextEditing = sys::events::SDL_EVENT_TEXT_EDITING.0,
+    TextEditingCandidates = sys::events::SDL_EVENT_TEXT_EDITING_CANDIDATES.0,
     TextInput = sys::events::SDL_EVENT_TEXT_INPUT.0,
 
     MouseMotion = sys::events::SDL_EVENT_MOUSE_MOTION.0,
@@ -324,6 +608,7 @@ pub enum EventType {
     JoyButtonUp = sys::events::SDL_EVENT_JOYSTICK_BUTTON_UP.0,
     JoyDeviceAdded = sys::events::SDL_EVENT_JOYSTICK_ADDED.0,
     JoyDeviceRemoved = sys::events::SDL_EVENT_JOYSTICK_REMOVED.0,
+    JoyBatteryUpdated = sys::events::SDL_EVENT_JOYSTICK_BATTERY_UPDATED.0,
 
     ControllerAxisMotion = sys::events::SDL_EVENT_GAMEPAD_AXIS_MOTION.0,
     ControllerButtonDown = sys::events::SDL_EVENT_GAMEPAD_BUTTON_DOWN.0,
@@ -340,18 +625,35 @@ pub enum EventType {
     FingerDown = sys::events::SDL_EVENT_FINGER_DOWN.0,
     FingerUp = sys::events::SDL_EVENT_FINGER_UP.0,
     FingerMotion = sys::events::SDL_EVENT_FINGER_MOTION.0,
+
+    PenProximityIn = sys::events::SDL_EVENT_PEN_PROXIMITY_IN.0,
+    PenProximityOut = sys::events::SDL_EVENT_PEN_PROXIMITY_OUT.0,
+    PenDown = sys::events::SDL_EVENT_PEN_DOWN.0,
+    PenUp = sys::events::SDL_EVENT_PEN_UP.0,
+    PenButtonDown = sys::events::SDL_EVENT_PEN_BUTTON_DOWN.0,
+    PenButtonUp = sys::events::SDL_EVENT_PEN_BUTTON_UP.0,
+    PenMotion = sys::events::SDL_EVENT_PEN_MOTION.0,
+    PenAxisMotion = sys::events::SDL_EVENT_PEN_AXIS.0,
     // gestures have been removed from SD3: https://github.com/libsdl-org/SDL_gesture
     ClipboardUpdate = sys::events::SDL_EVENT_CLIPBOARD_UPDATE.0,
     DropFile = sys::events::SDL_EVENT_DROP_FILE.0,
     DropText = sys::events::SDL_EVENT_DROP_TEXT.0,
     DropBegin = sys::events::SDL_EVENT_DROP_BEGIN.0,
     DropComplete = sys::events::SDL_EVENT_DROP_COMPLETE.0,
+    DropPosition = sys::events::SDL_EVENT_DROP_POSITION.0,
 
     AudioDeviceAdded = sys::events::SDL_EVENT_AUDIO_DEVICE_ADDED.0,
     AudioDeviceRemoved = sys::events::SDL_EVENT_AUDIO_DEVICE_REMOVED.0,
+    AudioDeviceFormatChanged = sys::events::SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED.0,
+
+    CameraDeviceAdded = sys::events::SDL_EVENT_CAMERA_DEVICE_ADDED.0,
+    CameraDeviceRemoved = sys::events::SDL_EVENT_CAMERA_DEVICE_REMOVED.0,
+    CameraDeviceApproved = sys::events::SDL_EVENT_CAMERA_DEVICE_APPROVED.0,
+    CameraDeviceDenied = sys::events::SDL_EVENT_CAMERA_DEVICE_DENIED.0,
 
     RenderTargetsReset = sys::events::SDL_EVENT_RENDER_TARGETS_RESET.0,
     RenderDeviceReset = sys::events::SDL_EVENT_RENDER_DEVICE_RESET.0,
+    RenderDeviceLost = sys::events::SDL_EVENT_RENDER_DEVICE_LOST.0,
 
     User = sys::events::SDL_EVENT_USER.0,
     Last = sys::events::SDL_EVENT_LAST.0,
@@ -385,6 +687,7 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_DID_ENTER_BACKGROUND => AppDidEnterBackground,
             SDL_EVENT_WILL_ENTER_FOREGROUND => AppWillEnterForeground,
             SDL_EVENT_DID_ENTER_FOREGROUND => AppDidEnterForeground,
+            SDL_EVENT_SYSTEM_THEME_CHANGED => SystemThemeChanged,
 
             SDL_EVENT_DISPLAY_ADDED => DisplayAdded,
             SDL_EVENT_DISPLAY_REMOVED => DisplayRemoved,
@@ -407,10 +710,13 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_WINDOW_FOCUS_GAINED => WindowFocusGained,
             SDL_EVENT_WINDOW_FOCUS_LOST => WindowFocusLost,
             SDL_EVENT_WINDOW_CLOSE_REQUESTED => WindowCloseRequested,
+            SDL_EVENT_WINDOW_DISPLAY_SCALE_CHANGED => WindowDisplayScaleChanged,
+            SDL_EVENT_WINDOW_HDR_STATE_CHANGED => WindowHDRStateChanged,
 
             SDL_EVENT_KEY_DOWN => KeyDown,
             SDL_EVENT_KEY_UP => KeyUp,
             SDL_EVENT_TEXT_EDITING => TextEditing,
+            SDL_EVENT_TEXT_EDITING_CANDIDATES => TextEditingCandidates,
             SDL_EVENT_TEXT_INPUT => TextInput,
 
             SDL_EVENT_MOUSE_MOTION => MouseMotion,
@@ -424,6 +730,7 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_JOYSTICK_BUTTON_UP => JoyButtonUp,
             SDL_EVENT_JOYSTICK_ADDED => JoyDeviceAdded,
             SDL_EVENT_JOYSTICK_REMOVED => JoyDeviceRemoved,
+            SDL_EVENT_JOYSTICK_BATTERY_UPDATED => JoyBatteryUpdated,
 
             SDL_EVENT_GAMEPAD_AXIS_MOTION => ControllerAxisMotion,
             SDL_EVENT_GAMEPAD_BUTTON_DOWN => ControllerButtonDown,
@@ -441,17 +748,34 @@ impl TryFrom<u32> for EventType {
             SDL_EVENT_FINGER_UP => FingerUp,
             SDL_EVENT_FINGER_MOTION => FingerMotion,
 
+            SDL_EVENT_PEN_PROXIMITY_IN => PenProximityIn,
+            SDL_EVENT_PEN_PROXIMITY_OUT => PenProximityOut,
+            SDL_EVENT_PEN_DOWN => PenDown,
+            SDL_EVENT_PEN_UP => PenUp,
+            SDL_EVENT_PEN_BUTTON_DOWN => PenButtonDown,
+            SDL_EVENT_PEN_BUTTON_UP => PenButtonUp,
+            SDL_EVENT_PEN_MOTION => PenMotion,
+            SDL_EVENT_PEN_AXIS => PenAxisMotion,
+
             SDL_EVENT_CLIPBOARD_UPDATE => ClipboardUpdate,
             SDL_EVENT_DROP_FILE => DropFile,
             SDL_EVENT_DROP_TEXT => DropText,
             SDL_EVENT_DROP_BEGIN => DropBegin,
             SDL_EVENT_DROP_COMPLETE => DropComplete,
+            SDL_EVENT_DROP_POSITION => DropPosition,
 
             SDL_EVENT_AUDIO_DEVICE_ADDED => AudioDeviceAdded,
             SDL_EVENT_AUDIO_DEVICE_REMOVED => AudioDeviceRemoved,
+            SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED => AudioDeviceFormatChanged,
+
+            SDL_EVENT_CAMERA_DEVICE_ADDED => CameraDeviceAdded,
+            SDL_EVENT_CAMERA_DEVICE_REMOVED => CameraDeviceRemoved,
+            SDL_EVENT_CAMERA_DEVICE_APPROVED => CameraDeviceApproved,
+            SDL_EVENT_CAMERA_DEVICE_DENIED => CameraDeviceDenied,
 
             SDL_EVENT_RENDER_TARGETS_RESET => RenderTargetsReset,
             SDL_EVENT_RENDER_DEVICE_RESET => RenderDeviceReset,
+            SDL_EVENT_RENDER_DEVICE_LOST => RenderDeviceLost,
 
             SDL_EVENT_USER => User,
             SDL_EVENT_LAST => Last,
@@ -466,11 +790,17 @@ impl TryFrom<u32> for EventType {
 pub enum DisplayEvent {
     None,
     Orientation(Orientation),
+    /// A display was connected.
     Added,
+    /// A display was disconnected; windows on it are moved to another display.
     Removed,
+    /// The display's bounds in the desktop layout changed.
     Moved,
+    /// The desktop mode changed, see [`Display::get_mode`].
     DesktopModeChanged,
+    /// The current mode changed, see [`Display::get_current_mode`].
     CurrentModeChanged,
+    /// The content scale changed, see [`Display::get_content_scale`].
     ContentScaleChanged,
 }
 
@@ -531,6 +861,10 @@ impl DisplayEvent {
                 | (Self::Orientation(_), Self::Orientation(_))
                 | (Self::Added, Self::Added)
                 | (Self::Removed, Self::Removed)
+                | (Self::Moved, Self::Moved)
+                | (Self::DesktopModeChanged, Self::DesktopModeChanged)
+                | (Self::CurrentModeChanged, Self::CurrentModeChanged)
+                | (Self::ContentScaleChanged, Self::ContentScaleChanged)
         )
     }
 }
@@ -556,6 +890,8 @@ pub enum WindowEvent {
     HitTest(i32, i32),
     ICCProfChanged,
     DisplayChanged(i32),
+    DisplayScaleChanged,
+    HDRStateChanged,
 }
 
 impl WindowEvent {
@@ -580,6 +916,8 @@ impl WindowEvent {
                 EventType::WindowHitTest => WindowEvent::HitTest(data1, data2),
                 EventType::WindowICCProfileChanged => WindowEvent::ICCProfChanged,
                 EventType::WindowDisplayChanged => WindowEvent::DisplayChanged(data1),
+                EventType::WindowDisplayScaleChanged => WindowEvent::DisplayScaleChanged,
+                EventType::WindowHDRStateChanged => WindowEvent::HDRStateChanged,
                 _ => WindowEvent::None,
             },
             Err(_) => WindowEvent::None,
@@ -606,6 +944,8 @@ impl WindowEvent {
             WindowEvent::HitTest(d1, d2) => (EventType::WindowHitTest, d1, d2),
             WindowEvent::ICCProfChanged => (EventType::WindowICCProfileChanged, 0, 0),
             WindowEvent::DisplayChanged(d1) => (EventType::WindowDisplayChanged, d1, 0),
+            WindowEvent::DisplayScaleChanged => (EventType::WindowDisplayScaleChanged, 0, 0),
+            WindowEvent::HDRStateChanged => (EventType::WindowHDRStateChanged, 0, 0),
         }
     }
 
@@ -630,10 +970,55 @@ impl WindowEvent {
                 | (Self::HitTest(_, _), Self::HitTest(_, _))
                 | (Self::ICCProfChanged, Self::ICCProfChanged)
                 | (Self::DisplayChanged(_), Self::DisplayChanged(_))
+                | (Self::DisplayScaleChanged, Self::DisplayScaleChanged)
+                | (Self::HDRStateChanged, Self::HDRStateChanged)
         )
     }
 }
 
+/// An `SDL_Event` as SDL reported it, carried by [`Event::Unknown`].
+#[derive(Clone, Copy, Default)]
+pub struct RawEvent(sys::events::SDL_Event);
+
+impl RawEvent {
+    /// The event's type, as in `SDL_Event::type`.
+    pub fn type_(&self) -> u32 {
+        unsafe { self.0.r#type }
+    }
+
+    /// The event's timestamp, as in `SDL_Event::common.timestamp`.
+    pub fn timestamp(&self) -> u64 {
+        unsafe { self.0.common.timestamp }
+    }
+
+    /// The raw event, to read the struct matching its type.
+    pub fn as_ll(&self) -> &sys::events::SDL_Event {
+        &self.0
+    }
+}
+
+impl From<sys::events::SDL_Event> for RawEvent {
+    fn from(raw: sys::events::SDL_Event) -> Self {
+        RawEvent(raw)
+    }
+}
+
+/// Raw events are equal if their type and timestamp are: the layout of the rest of the event
+/// isn't known, and may include padding that was never initialized.
+impl PartialEq for RawEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_() == other.type_() && self.timestamp() == other.timestamp()
+    }
+}
+
+impl std::fmt::Debug for RawEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RawEvent")
+            .field("type_", &self.type_())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 /// Different event types.
 pub enum Event {
@@ -658,6 +1043,10 @@ pub enum Event {
     AppDidEnterForeground {
         timestamp: u64,
     },
+    /// The light/dark theme of the system changed, see `VideoSubsystem::get_system_theme`.
+    SystemThemeChanged {
+        timestamp: u64,
+    },
 
     Window {
         timestamp: u64,
@@ -687,13 +1076,30 @@ pub enum Event {
         raw: Uint16,
     },
 
+    /// The IME composition changed; draw `text` at the text input area until it is committed
+    /// with `TextInput`.
     TextEditing {
         timestamp: u64,
         window_id: u32,
         text: String,
+        /// The cursor position, or the start of the selection, in UTF-8 characters, or -1 if
+        /// not set.
         start: i32,
+        /// The length of the selection in UTF-8 characters, or -1 if not set.
         length: i32,
     },
+    /// The IME candidate list changed, sent instead of showing the IME's own list if the
+    /// `SDL_HINT_IME_IMPLEMENTED_UI` hint includes `"candidates"`. An empty list means the
+    /// candidates should be hidden.
+    TextEditingCandidates {
+        timestamp: u64,
+        window_id: u32,
+        candidates: Vec<String>,
+        /// The index of the highlighted candidate, or -1 if none is.
+        selected_candidate: i32,
+        /// Whether the list should be laid out horizontally.
+        horizontal: bool,
+    },
 
     TextInput {
         timestamp: u64,
@@ -781,6 +1187,16 @@ pub enum Event {
         /// The joystick's `id`
         which: u32,
     },
+    /// The battery state of a joystick or gamepad changed, e.g. to warn the player when it
+    /// runs low.
+    JoyBatteryUpdated {
+        timestamp: u64,
+        /// The joystick's `id`
+        which: u32,
+        state: PowerLevel,
+        /// The remaining charge from 0 to 100, or -1 if unknown
+        percent: i32,
+    },
 
     ControllerAxisMotion {
         timestamp: u64,
@@ -813,6 +1229,8 @@ pub enum Event {
         /// The controller's joystick `id`
         which: u32,
     },
+    /// The mapping of an opened controller changed, e.g. after
+    /// `GamepadSubsystem::add_mapping`.
     ControllerDeviceRemapped {
         timestamp: u64,
         /// The controller's joystick `id`
@@ -875,6 +1293,9 @@ pub enum Event {
         ///
         /// See the `sensor` module for more information.
         data: [f32; 3],
+        /// When the sensor took the reading, in nanoseconds, or 0 if the sensor doesn't report
+        /// it. More precise than `timestamp` for integrating gyroscope data.
+        sensor_timestamp: u64,
     },
 
     FingerDown {
@@ -908,6 +1329,79 @@ pub enum Event {
         pressure: f32,
     },
 
+    /// A pen came into range of the device.
+    PenProximityIn {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+    },
+    /// A pen left the range of the device; its id may be reused afterwards.
+    PenProximityOut {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+    },
+    /// The pen tip, or the eraser if `eraser` is set, touched the surface.
+    PenDown {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+        pen_state: PenInputFlags,
+        x: f32,
+        y: f32,
+        eraser: bool,
+    },
+    /// The pen tip, or the eraser if `eraser` is set, was lifted from the surface.
+    PenUp {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+        pen_state: PenInputFlags,
+        x: f32,
+        y: f32,
+        eraser: bool,
+    },
+    /// A pen button was pressed; `button` starts at 1.
+    PenButtonDown {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+        pen_state: PenInputFlags,
+        x: f32,
+        y: f32,
+        button: u8,
+    },
+    /// A pen button was released; `button` starts at 1.
+    PenButtonUp {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+        pen_state: PenInputFlags,
+        x: f32,
+        y: f32,
+        button: u8,
+    },
+    /// The pen moved to `x`, `y` in window coordinates, whether or not it touches the surface.
+    PenMotion {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+        pen_state: PenInputFlags,
+        x: f32,
+        y: f32,
+    },
+    /// A pen axis such as pressure or tilt changed to `value`.
+    PenAxisMotion {
+        timestamp: u64,
+        window_id: u32,
+        which: PenId,
+        pen_state: PenInputFlags,
+        x: f32,
+        y: f32,
+        axis: PenAxis,
+        value: f32,
+    },
+
     DollarRecord {
         timestamp: u64,
         touch_id: i64,
@@ -928,45 +1422,107 @@ pub enum Event {
         num_fingers: u16,
     },
 
+    /// The clipboard or primary selection changed, e.g. to update whether "Paste" is
+    /// available.
     ClipboardUpdate {
         timestamp: u64,
+        /// Whether this application set the new clipboard contents.
+        owner: bool,
+        /// The formats the new contents are available in, such as `text/plain;charset=utf-8`.
+        mime_types: Vec<String>,
     },
 
+    /// A file was dropped at `x`, `y` in window coordinates.
     DropFile {
         timestamp: u64,
         window_id: u32,
         filename: String,
+        x: f32,
+        y: f32,
     },
+    /// Text was dropped at `x`, `y` in window coordinates; `filename` holds the text.
     DropText {
         timestamp: u64,
         window_id: u32,
         filename: String,
+        x: f32,
+        y: f32,
     },
+    /// A drag entered the window; one `DropFile` or `DropText` follows per dropped item.
     DropBegin {
         timestamp: u64,
         window_id: u32,
     },
+    /// The drag left the window or every item has been dropped.
     DropComplete {
         timestamp: u64,
         window_id: u32,
     },
+    /// The drag moved to `x`, `y` in window coordinates, e.g. to highlight the drop target.
+    DropPosition {
+        timestamp: u64,
+        window_id: u32,
+        x: f32,
+        y: f32,
+    },
 
     AudioDeviceAdded {
         timestamp: u64,
         which: u32,
         iscapture: bool,
     },
+    /// An audio device was unplugged or disconnected. Streams bound to it go silent, see
+    /// [`AudioStream::rebind`](crate::audio::AudioStream::rebind).
     AudioDeviceRemoved {
         timestamp: u64,
         which: u32,
         iscapture: bool,
     },
+    /// The system changed the format of an audio device, e.g. its sample rate; streams
+    /// bound to it convert to the new format on their own.
+    AudioDeviceFormatChanged {
+        timestamp: u64,
+        which: u32,
+        iscapture: bool,
+    },
 
+    /// A camera was plugged in; `which` is its camera id.
+    CameraDeviceAdded {
+        timestamp: u64,
+        which: u32,
+    },
+    /// A camera was unplugged.
+    CameraDeviceRemoved {
+        timestamp: u64,
+        which: u32,
+    },
+    /// The user granted access to an opened camera, so frames will start arriving.
+    CameraDeviceApproved {
+        timestamp: u64,
+        which: u32,
+    },
+    /// The user denied access to an opened camera, which should be closed.
+    CameraDeviceDenied {
+        timestamp: u64,
+        which: u32,
+    },
+
+    /// The contents of the render targets of the renderer in `window_id` were lost and must be
+    /// drawn again.
     RenderTargetsReset {
         timestamp: u64,
+        window_id: u32,
     },
+    /// The renderer's device was reset, losing all its textures, which must be created again.
     RenderDeviceReset {
         timestamp: u64,
+        window_id: u32,
+    },
+    /// The renderer's device was lost and can't be recovered; the renderer must be destroyed
+    /// and created again.
+    RenderDeviceLost {
+        timestamp: u64,
+        window_id: u32,
     },
 
     User {
@@ -978,9 +1534,13 @@ pub enum Event {
         data2: *mut c_void,
     },
 
+    /// An event this crate doesn't map yet, e.g. from a newer SDL version. `raw` holds the
+    /// event as SDL reported it, so it can still be handled or pushed again; when it is pushed,
+    /// `timestamp` and `type_` replace the ones in `raw`.
     Unknown {
         timestamp: u64,
         type_: u32,
+        raw: RawEvent,
     },
 
     Display {
@@ -1009,14 +1569,14 @@ impl Event {
         match *self {
             Event::User {
                 window_id,
-                type_: _,
+                type_,
                 code,
                 data1,
                 data2,
                 timestamp,
             } => {
                 let event = sys::events::SDL_UserEvent {
-                    r#type: sys::events::SDL_EVENT_USER.into(),
+                    r#type: type_,
                     timestamp,
                     windowID: window_id,
                     code,
@@ -1034,6 +1594,17 @@ impl Event {
                 }
             }
 
+            Event::Unknown {
+                timestamp,
+                type_,
+                raw,
+            } => {
+                let mut raw = raw.0;
+                raw.common.r#type = type_;
+                raw.common.timestamp = timestamp;
+                Some(raw)
+            }
+
             Event::Quit { timestamp } => {
                 let event = sys::events::SDL_QuitEvent {
                     r#type: sys::events::SDL_EVENT_QUIT,
@@ -1400,6 +1971,29 @@ impl Event {
                     Some(ret.assume_init())
                 }
             }
+            Event::JoyBatteryUpdated {
+                timestamp,
+                which,
+                state,
+                percent,
+            } => {
+                let event = sys::events::SDL_JoyBatteryEvent {
+                    r#type: sys::events::SDL_EVENT_JOYSTICK_BATTERY_UPDATED,
+                    reserved: 0,
+                    timestamp,
+                    which,
+                    state: state.to_ll(),
+                    percent,
+                };
+                unsafe {
+                    ptr::copy(
+                        &event,
+                        ret.as_mut_ptr() as *mut sys::events::SDL_JoyBatteryEvent,
+                        1,
+                    );
+                    Some(ret.assume_init())
+                }
+            }
             Event::ControllerAxisMotion {
                 timestamp,
                 which,
@@ -1563,7 +2157,6 @@ impl Event {
             | Event::DropFile { .. }
             | Event::TextEditing { .. }
             | Event::TextInput { .. }
-            | Event::Unknown { .. }
             | _ => {
                 // don't know how to convert!
                 None
@@ -1594,7 +2187,9 @@ impl Event {
                 | EventType::WindowCloseRequested
                 | EventType::WindowHitTest
                 | EventType::WindowICCProfileChanged
-                | EventType::WindowDisplayChanged => {
+                | EventType::WindowDisplayChanged
+                | EventType::WindowDisplayScaleChanged
+                | EventType::WindowHDRStateChanged => {
                     let event = raw.window;
                     Event::Window {
                         timestamp: event.timestamp,
@@ -1649,6 +2244,12 @@ impl Event {
                         timestamp: event.timestamp,
                     }
                 }
+                EventType::SystemThemeChanged => {
+                    let event = raw.common;
+                    Event::SystemThemeChanged {
+                        timestamp: event.timestamp,
+                    }
+                }
 
                 EventType::DisplayOrientation
                 | EventType::DisplayAdded
@@ -1713,6 +2314,29 @@ impl Event {
                         length: event.length,
                     }
                 }
+                EventType::TextEditingCandidates => {
+                    let event = raw.edit_candidates;
+
+                    let candidates = if event.candidates.is_null() {
+                        Vec::new()
+                    } else {
+                        std::slice::from_raw_parts(
+                            event.candidates,
+                            event.num_candidates.max(0) as usize,
+                        )
+                        .iter()
+                        .map(|&candidate| CStr::from_ptr(candidate).to_string_lossy().into_owned())
+                        .collect()
+                    };
+
+                    Event::TextEditingCandidates {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        candidates,
+                        selected_candidate: event.selected_candidate,
+                        horizontal: event.horizontal,
+                    }
+                }
                 EventType::TextInput => {
                     let event = raw.text;
 
@@ -1833,6 +2457,15 @@ impl Event {
                         which: event.which,
                     }
                 }
+                EventType::JoyBatteryUpdated => {
+                    let event = raw.jbattery;
+                    Event::JoyBatteryUpdated {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                        state: PowerLevel::from_ll(event.state),
+                        percent: event.percent,
+                    }
+                }
 
                 EventType::ControllerAxisMotion => {
                     let event = raw.gaxis;
@@ -1930,6 +2563,7 @@ impl Event {
                         which: event.which,
                         sensor: crate::sensor::SensorType::from_ll(event.sensor),
                         data: event.data,
+                        sensor_timestamp: event.sensor_timestamp,
                     }
                 }
 
@@ -1973,34 +2607,136 @@ impl Event {
                     }
                 }
 
+                EventType::PenProximityIn => {
+                    let event = raw.pproximity;
+                    Event::PenProximityIn {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                    }
+                }
+                EventType::PenProximityOut => {
+                    let event = raw.pproximity;
+                    Event::PenProximityOut {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                    }
+                }
+                EventType::PenDown => {
+                    let event = raw.ptouch;
+                    Event::PenDown {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        pen_state: PenInputFlags::from_bits_truncate(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        eraser: event.eraser,
+                    }
+                }
+                EventType::PenUp => {
+                    let event = raw.ptouch;
+                    Event::PenUp {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        pen_state: PenInputFlags::from_bits_truncate(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        eraser: event.eraser,
+                    }
+                }
+                EventType::PenButtonDown => {
+                    let event = raw.pbutton;
+                    Event::PenButtonDown {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        pen_state: PenInputFlags::from_bits_truncate(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        button: event.button,
+                    }
+                }
+                EventType::PenButtonUp => {
+                    let event = raw.pbutton;
+                    Event::PenButtonUp {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        pen_state: PenInputFlags::from_bits_truncate(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        button: event.button,
+                    }
+                }
+                EventType::PenMotion => {
+                    let event = raw.pmotion;
+                    Event::PenMotion {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        pen_state: PenInputFlags::from_bits_truncate(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                    }
+                }
+                EventType::PenAxisMotion => {
+                    let event = raw.paxis;
+                    Event::PenAxisMotion {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        which: event.which,
+                        pen_state: PenInputFlags::from_bits_truncate(event.pen_state),
+                        x: event.x,
+                        y: event.y,
+                        axis: PenAxis::from_ll(event.axis),
+                        value: event.value,
+                    }
+                }
+
                 EventType::ClipboardUpdate => {
-                    let event = raw.common;
+                    let event = raw.clipboard;
+
+                    let mime_types = if event.mime_types.is_null() {
+                        Vec::new()
+                    } else {
+                        std::slice::from_raw_parts(
+                            event.mime_types,
+                            event.num_mime_types.max(0) as usize,
+                        )
+                        .iter()
+                        .map(|&mime_type| CStr::from_ptr(mime_type).to_string_lossy().into_owned())
+                        .collect()
+                    };
+
                     Event::ClipboardUpdate {
                         timestamp: event.timestamp,
+                        owner: event.owner,
+                        mime_types,
                     }
                 }
                 EventType::DropFile => {
                     let event = raw.drop;
 
-                    let buf = CStr::from_ptr(event.data as *const _).to_bytes();
-                    let text = String::from_utf8_lossy(buf).to_string();
-
                     Event::DropFile {
                         timestamp: event.timestamp,
                         window_id: event.windowID,
-                        filename: text,
+                        filename: drop_data(&event),
+                        x: event.x,
+                        y: event.y,
                     }
                 }
                 EventType::DropText => {
                     let event = raw.drop;
 
-                    let buf = CStr::from_ptr(event.data as *const _).to_bytes();
-                    let text = String::from_utf8_lossy(buf).to_string();
-
                     Event::DropText {
                         timestamp: event.timestamp,
                         window_id: event.windowID,
-                        filename: text,
+                        filename: drop_data(&event),
+                        x: event.x,
+                        y: event.y,
                     }
                 }
                 EventType::DropBegin => {
@@ -2019,6 +2755,16 @@ impl Event {
                         window_id: event.windowID,
                     }
                 }
+                EventType::DropPosition => {
+                    let event = raw.drop;
+
+                    Event::DropPosition {
+                        timestamp: event.timestamp,
+                        window_id: event.windowID,
+                        x: event.x,
+                        y: event.y,
+                    }
+                }
                 EventType::AudioDeviceAdded => {
                     let event = raw.adevice;
                     Event::AudioDeviceAdded {
@@ -2037,12 +2783,55 @@ impl Event {
                         iscapture: event.recording,
                     }
                 }
+                EventType::AudioDeviceFormatChanged => {
+                    let event = raw.adevice;
+                    Event::AudioDeviceFormatChanged {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                        iscapture: event.recording,
+                    }
+                }
+
+                EventType::CameraDeviceAdded => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceAdded {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::CameraDeviceRemoved => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceRemoved {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::CameraDeviceApproved => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceApproved {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
+                EventType::CameraDeviceDenied => {
+                    let event = raw.cdevice;
+                    Event::CameraDeviceDenied {
+                        timestamp: event.timestamp,
+                        which: event.which,
+                    }
+                }
 
                 EventType::RenderTargetsReset => Event::RenderTargetsReset {
-                    timestamp: raw.common.timestamp,
+                    timestamp: raw.render.timestamp,
+                    window_id: raw.render.windowID,
                 },
                 EventType::RenderDeviceReset => Event::RenderDeviceReset {
-                    timestamp: raw.common.timestamp,
+                    timestamp: raw.render.timestamp,
+                    window_id: raw.render.windowID,
+                },
+                EventType::RenderDeviceLost => Event::RenderDeviceLost {
+                    timestamp: raw.render.timestamp,
+                    window_id: raw.render.windowID,
                 },
 
                 EventType::First => panic!("Unused event, EventType::First, was encountered"),
@@ -2059,6 +2848,7 @@ impl Event {
                         Event::Unknown {
                             timestamp: event.timestamp,
                             type_: event.r#type,
+                            raw: RawEvent(raw),
                         }
                     } else {
                         let event = raw.user;
@@ -2081,27 +2871,43 @@ impl Event {
         matches!(*self, Event::User { .. })
     }
 
+    /// Takes the payload out of an event pushed with
+    /// [`EventSubsystem::push_custom_event`](crate::EventSubsystem::push_custom_event).
+    ///
+    /// Returns `None` if this is not a custom event of type `T`, or if its payload has already
+    /// been taken, including through a clone of this event. Payloads of custom events that are
+    /// never taken are leaked.
+    ///
+    /// Custom events carry the payload's address in `data1` and a token identifying it in
+    /// `data2`; both must be left untouched for the payload to be found.
     pub fn as_user_event_type<T: ::std::any::Any>(&self) -> Option<T> {
         use std::any::TypeId;
         let type_id = TypeId::of::<Box<T>>();
 
-        let (event_id, event_box_ptr) = match *self {
-            Event::User { type_, data1, .. } => (type_, data1),
+        let (event_id, event_box_ptr, token) = match *self {
+            Event::User {
+                type_,
+                data1,
+                data2,
+                ..
+            } => (type_, data1, data2 as usize),
             _ => return None,
         };
 
-        let cet = CUSTOM_EVENT_TYPES.lock().unwrap();
-
-        let event_type_id = match cet.sdl_id_to_type_id.get(&event_id) {
-            Some(id) => id,
-            None => {
-                panic!("internal error; could not find typeid")
-            }
-        };
-
-        if &type_id != event_type_id {
+        let mut cet = CUSTOM_EVENT_TYPES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // Tokens are only reused once the counter wraps around, and never while their payload is
+        // pending, so stale clones and forged events can't realistically match a payload pushed
+        // later, even at the same address.
+        let is_pending = cet.pending_payloads.get(&token).is_some_and(|payload| {
+            payload.ptr == event_box_ptr as usize && payload.type_id == type_id
+        });
+        if cet.sdl_id_to_type_id.get(&event_id) != Some(&type_id) || !is_pending {
             return None;
         }
+        cet.pending_payloads.remove(&token);
 
         let event_box: Box<T> = unsafe { Box::from_raw(event_box_ptr as *mut T) };
 
@@ -2138,11 +2944,13 @@ impl Event {
             | (Self::AppDidEnterBackground { .. }, Self::AppDidEnterBackground { .. })
             | (Self::AppWillEnterForeground { .. }, Self::AppWillEnterForeground { .. })
             | (Self::AppDidEnterForeground { .. }, Self::AppDidEnterForeground { .. })
+            | (Self::SystemThemeChanged { .. }, Self::SystemThemeChanged { .. })
             | (Self::Display { .. }, Self::Display { .. })
             | (Self::Window { .. }, Self::Window { .. })
             | (Self::KeyDown { .. }, Self::KeyDown { .. })
             | (Self::KeyUp { .. }, Self::KeyUp { .. })
             | (Self::TextEditing { .. }, Self::TextEditing { .. })
+            | (Self::TextEditingCandidates { .. }, Self::TextEditingCandidates { .. })
             | (Self::TextInput { .. }, Self::TextInput { .. })
             | (Self::MouseMotion { .. }, Self::MouseMotion { .. })
             | (Self::MouseButtonDown { .. }, Self::MouseButtonDown { .. })
@@ -2154,15 +2962,27 @@ impl Event {
             | (Self::JoyButtonUp { .. }, Self::JoyButtonUp { .. })
             | (Self::JoyDeviceAdded { .. }, Self::JoyDeviceAdded { .. })
             | (Self::JoyDeviceRemoved { .. }, Self::JoyDeviceRemoved { .. })
+            | (Self::JoyBatteryUpdated { .. }, Self::JoyBatteryUpdated { .. })
             | (Self::ControllerAxisMotion { .. }, Self::ControllerAxisMotion { .. })
             | (Self::ControllerButtonDown { .. }, Self::ControllerButtonDown { .. })
             | (Self::ControllerButtonUp { .. }, Self::ControllerButtonUp { .. })
             | (Self::ControllerDeviceAdded { .. }, Self::ControllerDeviceAdded { .. })
             | (Self::ControllerDeviceRemoved { .. }, Self::ControllerDeviceRemoved { .. })
             | (Self::ControllerDeviceRemapped { .. }, Self::ControllerDeviceRemapped { .. })
+            | (Self::ControllerTouchpadDown { .. }, Self::ControllerTouchpadDown { .. })
+            | (Self::ControllerTouchpadMotion { .. }, Self::ControllerTouchpadMotion { .. })
+            | (Self::ControllerTouchpadUp { .. }, Self::ControllerTouchpadUp { .. })
             | (Self::FingerDown { .. }, Self::FingerDown { .. })
             | (Self::FingerUp { .. }, Self::FingerUp { .. })
             | (Self::FingerMotion { .. }, Self::FingerMotion { .. })
+            | (Self::PenProximityIn { .. }, Self::PenProximityIn { .. })
+            | (Self::PenProximityOut { .. }, Self::PenProximityOut { .. })
+            | (Self::PenDown { .. }, Self::PenDown { .. })
+            | (Self::PenUp { .. }, Self::PenUp { .. })
+            | (Self::PenButtonDown { .. }, Self::PenButtonDown { .. })
+            | (Self::PenButtonUp { .. }, Self::PenButtonUp { .. })
+            | (Self::PenMotion { .. }, Self::PenMotion { .. })
+            | (Self::PenAxisMotion { .. }, Self::PenAxisMotion { .. })
             | (Self::DollarRecord { .. }, Self::DollarRecord { .. })
             | (Self::MultiGesture { .. }, Self::MultiGesture { .. })
             | (Self::ClipboardUpdate { .. }, Self::ClipboardUpdate { .. })
@@ -2170,10 +2990,17 @@ impl Event {
             | (Self::DropText { .. }, Self::DropText { .. })
             | (Self::DropBegin { .. }, Self::DropBegin { .. })
             | (Self::DropComplete { .. }, Self::DropComplete { .. })
+            | (Self::DropPosition { .. }, Self::DropPosition { .. })
             | (Self::AudioDeviceAdded { .. }, Self::AudioDeviceAdded { .. })
             | (Self::AudioDeviceRemoved { .. }, Self::AudioDeviceRemoved { .. })
+            | (Self::AudioDeviceFormatChanged { .. }, Self::AudioDeviceFormatChanged { .. })
+            | (Self::CameraDeviceAdded { .. }, Self::CameraDeviceAdded { .. })
+            | (Self::CameraDeviceRemoved { .. }, Self::CameraDeviceRemoved { .. })
+            | (Self::CameraDeviceApproved { .. }, Self::CameraDeviceApproved { .. })
+            | (Self::CameraDeviceDenied { .. }, Self::CameraDeviceDenied { .. })
             | (Self::RenderTargetsReset { .. }, Self::RenderTargetsReset { .. })
             | (Self::RenderDeviceReset { .. }, Self::RenderDeviceReset { .. })
+            | (Self::RenderDeviceLost { .. }, Self::RenderDeviceLost { .. })
             | (Self::User { .. }, Self::User { .. })
             | (Self::Unknown { .. }, Self::Unknown { .. }) => true,
             #[cfg(feature = "hidapi")]
@@ -2206,10 +3033,12 @@ impl Event {
             Self::AppDidEnterBackground { timestamp, .. } => timestamp,
             Self::AppWillEnterForeground { timestamp, .. } => timestamp,
             Self::AppDidEnterForeground { timestamp, .. } => timestamp,
+            Self::SystemThemeChanged { timestamp, .. } => timestamp,
             Self::Display { timestamp, .. } => timestamp,
             Self::KeyDown { timestamp, .. } => timestamp,
             Self::KeyUp { timestamp, .. } => timestamp,
             Self::TextEditing { timestamp, .. } => timestamp,
+            Self::TextEditingCandidates { timestamp, .. } => timestamp,
             Self::TextInput { timestamp, .. } => timestamp,
             Self::MouseMotion { timestamp, .. } => timestamp,
             Self::MouseButtonDown { timestamp, .. } => timestamp,
@@ -2221,6 +3050,7 @@ impl Event {
             Self::JoyButtonUp { timestamp, .. } => timestamp,
             Self::JoyDeviceAdded { timestamp, .. } => timestamp,
             Self::JoyDeviceRemoved { timestamp, .. } => timestamp,
+            Self::JoyBatteryUpdated { timestamp, .. } => timestamp,
             Self::ControllerAxisMotion { timestamp, .. } => timestamp,
             Self::ControllerButtonDown { timestamp, .. } => timestamp,
             Self::ControllerButtonUp { timestamp, .. } => timestamp,
@@ -2235,6 +3065,14 @@ impl Event {
             Self::FingerDown { timestamp, .. } => timestamp,
             Self::FingerUp { timestamp, .. } => timestamp,
             Self::FingerMotion { timestamp, .. } => timestamp,
+            Self::PenProximityIn { timestamp, .. } => timestamp,
+            Self::PenProximityOut { timestamp, .. } => timestamp,
+            Self::PenDown { timestamp, .. } => timestamp,
+            Self::PenUp { timestamp, .. } => timestamp,
+            Self::PenButtonDown { timestamp, .. } => timestamp,
+            Self::PenButtonUp { timestamp, .. } => timestamp,
+            Self::PenMotion { timestamp, .. } => timestamp,
+            Self::PenAxisMotion { timestamp, .. } => timestamp,
             Self::DollarRecord { timestamp, .. } => timestamp,
             Self::MultiGesture { timestamp, .. } => timestamp,
             Self::ClipboardUpdate { timestamp, .. } => timestamp,
@@ -2242,15 +3080,68 @@ impl Event {
             Self::DropText { timestamp, .. } => timestamp,
             Self::DropBegin { timestamp, .. } => timestamp,
             Self::DropComplete { timestamp, .. } => timestamp,
+            Self::DropPosition { timestamp, .. } => timestamp,
             Self::AudioDeviceAdded { timestamp, .. } => timestamp,
             Self::AudioDeviceRemoved { timestamp, .. } => timestamp,
+            Self::AudioDeviceFormatChanged { timestamp, .. } => timestamp,
+            Self::CameraDeviceAdded { timestamp, .. } => timestamp,
+            Self::CameraDeviceRemoved { timestamp, .. } => timestamp,
+            Self::CameraDeviceApproved { timestamp, .. } => timestamp,
+            Self::CameraDeviceDenied { timestamp, .. } => timestamp,
             Self::RenderTargetsReset { timestamp, .. } => timestamp,
             Self::RenderDeviceReset { timestamp, .. } => timestamp,
+            Self::RenderDeviceLost { timestamp, .. } => timestamp,
             Self::User { timestamp, .. } => timestamp,
             Self::Unknown { timestamp, .. } => timestamp,
         }
     }
 
+    /// Returns the event's timestamp as the time elapsed between SDL's initialization and the
+    /// event, on the same clock as [`timer::ticks_ns`](crate::timer::ticks_ns).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    /// use std::time::Duration;
+    ///
+    /// let ev = Event::Quit {
+    ///     timestamp: 1_500_000_000,
+    /// };
+    /// assert_eq!(ev.timestamp_duration(), Duration::from_millis(1500));
+    /// ```
+    pub fn timestamp_duration(&self) -> Duration {
+        Duration::from_nanos(self.get_timestamp())
+    }
+
+    /// Returns how long ago the event happened, e.g. to measure input latency when a frame
+    /// reacting to it is presented.
+    ///
+    /// Events pushed with a timestamp in the future count as just happened.
+    #[doc(alias = "SDL_GetTicksNS")]
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(crate::timer::ticks_ns().saturating_sub(self.get_timestamp()))
+    }
+
+    /// Returns `true` if this event happened strictly before `other`.
+    ///
+    /// Events are queued in the order they are received, but events from different devices
+    /// may be timestamped by their drivers, so this can differ from the queue order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    ///
+    /// let first = Event::Quit { timestamp: 10 };
+    /// let second = Event::Quit { timestamp: 20 };
+    /// assert!(first.happened_before(&second));
+    /// assert!(!second.happened_before(&first));
+    /// ```
+    pub fn happened_before(&self, other: &Event) -> bool {
+        self.get_timestamp() < other.get_timestamp()
+    }
+
     /// Returns the `window_id` field of the event if it's present (not all events have it!).
     ///
     /// # Example
@@ -2277,15 +3168,28 @@ impl Event {
             Self::KeyDown { window_id, .. } => Some(*window_id),
             Self::KeyUp { window_id, .. } => Some(*window_id),
             Self::TextEditing { window_id, .. } => Some(*window_id),
+            Self::TextEditingCandidates { window_id, .. } => Some(*window_id),
             Self::TextInput { window_id, .. } => Some(*window_id),
             Self::MouseMotion { window_id, .. } => Some(*window_id),
             Self::MouseButtonDown { window_id, .. } => Some(*window_id),
             Self::MouseButtonUp { window_id, .. } => Some(*window_id),
             Self::MouseWheel { window_id, .. } => Some(*window_id),
+            Self::PenProximityIn { window_id, .. } => Some(*window_id),
+            Self::PenProximityOut { window_id, .. } => Some(*window_id),
+            Self::PenDown { window_id, .. } => Some(*window_id),
+            Self::PenUp { window_id, .. } => Some(*window_id),
+            Self::PenButtonDown { window_id, .. } => Some(*window_id),
+            Self::PenButtonUp { window_id, .. } => Some(*window_id),
+            Self::PenMotion { window_id, .. } => Some(*window_id),
+            Self::PenAxisMotion { window_id, .. } => Some(*window_id),
             Self::DropFile { window_id, .. } => Some(*window_id),
             Self::DropText { window_id, .. } => Some(*window_id),
             Self::DropBegin { window_id, .. } => Some(*window_id),
             Self::DropComplete { window_id, .. } => Some(*window_id),
+            Self::DropPosition { window_id, .. } => Some(*window_id),
+            Self::RenderTargetsReset { window_id, .. } => Some(*window_id),
+            Self::RenderDeviceReset { window_id, .. } => Some(*window_id),
+            Self::RenderDeviceLost { window_id, .. } => Some(*window_id),
             Self::User { window_id, .. } => Some(*window_id),
             _ => None,
         }
@@ -2378,7 +3282,10 @@ impl Event {
     /// assert!(another_ev.is_text() == false); // Not a text event!
     /// ```
     pub fn is_text(&self) -> bool {
-        matches!(self, Self::TextEditing { .. } | Self::TextInput { .. })
+        matches!(
+            self,
+            Self::TextEditing { .. } | Self::TextEditingCandidates { .. } | Self::TextInput { .. }
+        )
     }
 
     /// Returns `true` if this is a mouse event.
@@ -2443,6 +3350,9 @@ impl Event {
                 | Self::ControllerDeviceAdded { .. }
                 | Self::ControllerDeviceRemoved { .. }
                 | Self::ControllerDeviceRemapped { .. }
+                | Self::ControllerTouchpadDown { .. }
+                | Self::ControllerTouchpadMotion { .. }
+                | Self::ControllerTouchpadUp { .. }
         )
     }
 
@@ -2474,6 +3384,7 @@ impl Event {
                 | Self::JoyButtonUp { .. }
                 | Self::JoyDeviceAdded { .. }
                 | Self::JoyDeviceRemoved { .. }
+                | Self::JoyBatteryUpdated { .. }
         )
     }
 
@@ -2508,6 +3419,39 @@ impl Event {
         )
     }
 
+    /// Returns `true` if this is a pen event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    ///
+    /// let ev = Event::PenProximityIn {
+    ///     timestamp: 0,
+    ///     window_id: 0,
+    ///     which: 1,
+    /// };
+    /// assert!(ev.is_pen());
+    ///
+    /// let another_ev = Event::Quit {
+    ///     timestamp: 0,
+    /// };
+    /// assert!(another_ev.is_pen() == false); // Not a pen event!
+    /// ```
+    pub fn is_pen(&self) -> bool {
+        matches!(
+            self,
+            Self::PenProximityIn { .. }
+                | Self::PenProximityOut { .. }
+                | Self::PenDown { .. }
+                | Self::PenUp { .. }
+                | Self::PenButtonDown { .. }
+                | Self::PenButtonUp { .. }
+                | Self::PenMotion { .. }
+                | Self::PenAxisMotion { .. }
+        )
+    }
+
     /// Returns `true` if this is a drop event.
     ///
     /// # Example
@@ -2533,6 +3477,7 @@ impl Event {
                 | Self::DropText { .. }
                 | Self::DropBegin { .. }
                 | Self::DropComplete { .. }
+                | Self::DropPosition { .. }
         )
     }
 
@@ -2558,7 +3503,37 @@ impl Event {
     pub fn is_audio(&self) -> bool {
         matches!(
             self,
-            Self::AudioDeviceAdded { .. } | Self::AudioDeviceRemoved { .. }
+            Self::AudioDeviceAdded { .. }
+                | Self::AudioDeviceRemoved { .. }
+                | Self::AudioDeviceFormatChanged { .. }
+        )
+    }
+
+    /// Returns `true` if this is a camera event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sdl3::event::Event;
+    ///
+    /// let ev = Event::CameraDeviceApproved {
+    ///     timestamp: 0,
+    ///     which: 3,
+    /// };
+    /// assert!(ev.is_camera());
+    ///
+    /// let another_ev = Event::Quit {
+    ///     timestamp: 0,
+    /// };
+    /// assert!(another_ev.is_camera() == false); // Not a camera event!
+    /// ```
+    pub fn is_camera(&self) -> bool {
+        matches!(
+            self,
+            Self::CameraDeviceAdded { .. }
+                | Self::CameraDeviceRemoved { .. }
+                | Self::CameraDeviceApproved { .. }
+                | Self::CameraDeviceDenied { .. }
         )
     }
 
@@ -2571,6 +3546,7 @@ impl Event {
     ///
     /// let ev = Event::RenderTargetsReset {
     ///     timestamp: 0,
+    ///     window_id: 1,
     /// };
     /// assert!(ev.is_render());
     ///
@@ -2582,7 +3558,9 @@ impl Event {
     pub fn is_render(&self) -> bool {
         matches!(
             self,
-            Self::RenderTargetsReset { .. } | Self::RenderDeviceReset { .. }
+            Self::RenderTargetsReset { .. }
+                | Self::RenderDeviceReset { .. }
+                | Self::RenderDeviceLost { .. }
         )
     }
 
@@ -2622,6 +3600,7 @@ impl Event {
     /// let ev = Event::Unknown {
     ///     timestamp: 0,
     ///     type_: 0,
+    ///     raw: Default::default(),
     /// };
     /// assert!(ev.is_unknown());
     ///
@@ -2674,6 +3653,45 @@ unsafe fn poll_event() -> Option<Event> {
     }
 }
 
+fn peep_events<B>(
+    max_amount: u32,
+    action: sys::events::SDL_EventAction,
+    min_type: u32,
+    max_type: u32,
+) -> Result<B, Error>
+where
+    B: FromIterator<Event>,
+{
+    let mut events = Vec::with_capacity(max_amount as usize);
+
+    let result = unsafe {
+        sys::events::SDL_PeepEvents(
+            events.as_mut_ptr(),
+            max_amount.min(c_int::MAX as u32) as c_int,
+            action,
+            min_type,
+            max_type,
+        )
+    };
+
+    if result < 0 {
+        Err(get_error())
+    } else {
+        unsafe { events.set_len(result as usize) };
+
+        Ok(events.into_iter().map(Event::from_ll).collect())
+    }
+}
+
+/// The file name or text of a drop event, which SDL frees once the event is processed.
+fn drop_data(event: &sys::events::SDL_DropEvent) -> String {
+    if event.data.is_null() {
+        return String::new();
+    }
+    let buf = unsafe { CStr::from_ptr(event.data) }.to_bytes();
+    String::from_utf8_lossy(buf).into_owned()
+}
+
 unsafe fn wait_event() -> Event {
     let mut raw = mem::MaybeUninit::uninit();
     let success = sys::events::SDL_WaitEvent(raw.as_mut_ptr());
@@ -2687,7 +3705,9 @@ unsafe fn wait_event() -> Event {
 
 unsafe fn wait_event_timeout(timeout: u32) -> Option<Event> {
     let mut raw = mem::MaybeUninit::uninit();
-    let success = sys::events::SDL_WaitEventTimeout(raw.as_mut_ptr(), timeout as c_int);
+    // timeouts that don't fit would turn negative, which SDL treats as waiting forever
+    let timeout = timeout.min(c_int::MAX as u32) as c_int;
+    let success = sys::events::SDL_WaitEventTimeout(raw.as_mut_ptr(), timeout);
 
     if success {
         Some(Event::from_ll(raw.assume_init()))
@@ -2735,11 +3755,35 @@ impl crate::EventPump {
     }
 
     /// Waits indefinitely for the next available event.
+    ///
+    /// Events are pumped while waiting, and the thread sleeps until an event arrives, making
+    /// this suited to tools that only redraw on input. Other threads can wake it up by pushing
+    /// an event through an [`EventSender`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use sdl3::event::Event;
+    ///
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let mut event_pump = sdl_context.event_pump().unwrap();
+    ///
+    /// loop {
+    ///     match event_pump.wait_event() {
+    ///         Event::Quit { .. } => break,
+    ///         _ => { /* update and redraw */ }
+    ///     }
+    /// }
+    /// ```
+    #[doc(alias = "SDL_WaitEvent")]
     pub fn wait_event(&mut self) -> Event {
         unsafe { wait_event() }
     }
 
     /// Waits until the specified timeout (in milliseconds) for the next available event.
+    ///
+    /// Returns `None` if no event arrived in time, e.g. to run periodic work such as cursor
+    /// blinking between events. A timeout of `0` behaves like [`poll_event`](Self::poll_event).
+    #[doc(alias = "SDL_WaitEventTimeout")]
     pub fn wait_event_timeout(&mut self, timeout: u32) -> Option<Event> {
         unsafe { wait_event_timeout(timeout) }
     }
@@ -2832,6 +3876,7 @@ mod test {
     use super::super::video::Orientation;
     use super::DisplayEvent;
     use super::Event;
+    use super::RawEvent;
     use super::WindowEvent;
 
     // Tests a round-trip conversion from an Event type to
@@ -3104,6 +4149,41 @@ mod test {
             panic!()
         }
     }
+
+    #[test]
+    fn test_from_ll_unknown_keeps_raw_event() {
+        let mut raw_event = crate::sys::events::SDL_Event::default();
+        unsafe {
+            raw_event.common.r#type = 0x7000;
+            raw_event.common.timestamp = 42;
+        }
+
+        let event = Event::from_ll(raw_event);
+        if let Event::Unknown {
+            timestamp,
+            type_,
+            raw,
+        } = &event
+        {
+            assert_eq!((*timestamp, *type_), (42, 0x7000));
+            assert_eq!(raw.type_(), 0x7000);
+        } else {
+            panic!()
+        }
+        assert_eq!(Event::from_ll(event.to_ll().unwrap()), event);
+    }
+
+    #[test]
+    fn test_to_ll_unknown_uses_event_fields() {
+        let event = Event::Unknown {
+            timestamp: 7,
+            type_: 0x7001,
+            raw: RawEvent::default(),
+        };
+        let raw = event.to_ll().unwrap();
+        let raw = RawEvent::from(raw);
+        assert_eq!((raw.type_(), raw.timestamp()), (0x7001, 7));
+    }
 }
 
 /// A sendible type that can push events to the event queue.
@@ -3159,9 +4239,15 @@ impl EventSender {
     ///     assert_eq!(e2.a, 42);
     /// }
     /// ```
-    pub fn push_custom_event<T: ::std::any::Any>(&self, event: T) -> Result<(), Error> {
+    ///
+    /// The payload is moved into the event queue and owned by the first
+    /// [`Event::as_user_event_type`] call that takes it out, so it must be `Send` to be
+    /// received on the thread polling events.
+    pub fn push_custom_event<T: ::std::any::Any + Send>(&self, event: T) -> Result<(), Error> {
         use std::any::TypeId;
-        let cet = CUSTOM_EVENT_TYPES.lock().unwrap();
+        let mut cet = CUSTOM_EVENT_TYPES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
         let type_id = TypeId::of::<Box<T>>();
 
         let user_event_id = *match cet.type_id_to_sdl_id.get(&type_id) {
@@ -3173,20 +4259,39 @@ impl EventSender {
             }
         };
 
-        let event_box = Box::new(event);
+        let event_box_ptr = Box::into_raw(Box::new(event));
+        let mut token = cet.next_payload_token;
+        // after the counter wrapped around, skip the tokens of payloads that are still pending
+        while cet.pending_payloads.contains_key(&token) {
+            token = token.wrapping_add(1).max(1);
+        }
+        cet.next_payload_token = token.wrapping_add(1).max(1);
+        cet.pending_payloads.insert(
+            token,
+            PendingPayload {
+                ptr: event_box_ptr as usize,
+                type_id,
+            },
+        );
         let event = Event::User {
             timestamp: 0,
             window_id: 0,
             type_: user_event_id,
             code: 0,
-            data1: Box::into_raw(event_box) as *mut c_void,
-            data2: ::std::ptr::null_mut(),
+            data1: event_box_ptr as *mut c_void,
+            data2: token as *mut c_void,
         };
         drop(cet);
 
-        self.push_event(event)?;
-
-        Ok(())
+        self.push_event(event).inspect_err(|_| {
+            // the event never made it into the queue, so nothing else can take the payload
+            let mut cet = CUSTOM_EVENT_TYPES
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if cet.pending_payloads.remove(&token).is_some() {
+                drop(unsafe { Box::from_raw(event_box_ptr) });
+            }
+        })
     }
 }
 
@@ -3218,15 +4323,20 @@ impl<'a, CB: EventWatchCallback + 'a> EventWatch<'a, CB> {
 
     /// Activates the event watch.
     /// Does nothing if it is already activated.
+    ///
+    /// If SDL fails to add the watch, it stays deactivated, which can be checked with
+    /// [`activated`](Self::activated).
+    #[doc(alias = "SDL_AddEventWatch")]
     pub fn activate(&mut self) {
         if !self.activated {
-            self.activated = true;
-            unsafe { sys::events::SDL_AddEventWatch(self.filter(), self.callback()) };
+            self.activated =
+                unsafe { sys::events::SDL_AddEventWatch(self.filter(), self.callback()) };
         }
     }
 
     /// Deactivates the event watch.
-    /// Does nothing if it is already activated.
+    /// Does nothing if it is already deactivated.
+    #[doc(alias = "SDL_RemoveEventWatch")]
     pub fn deactivate(&mut self) {
         if self.activated {
             self.activated = false;
@@ -3273,6 +4383,132 @@ extern "C" fn event_callback_marshall<CB: EventWatchCallback>(
     false
 }
 
+extern "C" fn event_filter_marshall(
+    _user_data: *mut c_void,
+    event: *mut sdl3_sys::events::SDL_Event,
+) -> bool {
+    thread_local! {
+        static IN_EVENT_FILTER: Cell<bool> = const { Cell::new(false) };
+    }
+
+    // Events pushed from within the filter would deadlock on the lock, let them through instead.
+    if IN_EVENT_FILTER.get() {
+        return true;
+    }
+    // The filter holds no invariants a poisoned lock could protect.
+    let mut filter = EVENT_FILTER.lock().unwrap_or_else(PoisonError::into_inner);
+    let Some(filter) = filter.as_mut() else {
+        return true;
+    };
+
+    /// Clears the flag again when the filter returns.
+    struct InEventFilter;
+    impl Drop for InEventFilter {
+        fn drop(&mut self) {
+            IN_EVENT_FILTER.set(false);
+        }
+    }
+    IN_EVENT_FILTER.set(true);
+    let _in_event_filter = InEventFilter;
+
+    // panics must not unwind into SDL, the event is kept instead
+    catch_unwind(AssertUnwindSafe(|| {
+        filter(&Event::from_ll(unsafe { *event }))
+    }))
+    .unwrap_or(true)
+}
+
+extern "C" fn filter_events_marshall<F: FnMut(&Event) -> bool>(
+    user_data: *mut c_void,
+    event: *mut sdl3_sys::events::SDL_Event,
+) -> bool {
+    let f: &mut F = unsafe { &mut *(user_data as *mut _) };
+    // panics must not unwind into SDL, the event is kept instead
+    catch_unwind(AssertUnwindSafe(|| f(&Event::from_ll(unsafe { *event })))).unwrap_or(true)
+}
+
+/// A [`Stream`](futures_core::Stream) of events, created with
+/// [`EventSubsystem::event_stream`](crate::EventSubsystem::event_stream).
+#[cfg(feature = "futures")]
+pub struct EventStream {
+    #[expect(dead_code, reason = "keep the event subsystem alive")]
+    event_subsystem: crate::EventSubsystem,
+    // boxed so the event watch keeps a stable pointer to it
+    state: Box<Mutex<EventStreamState>>,
+}
+
+#[cfg(feature = "futures")]
+#[derive(Default)]
+struct EventStreamState {
+    waker: Option<Waker>,
+    // the events seen by the event watch that haven't been yielded yet
+    queue: VecDeque<Event>,
+}
+
+#[cfg(feature = "futures")]
+impl EventStream {
+    fn new(event_subsystem: crate::EventSubsystem) -> Result<EventStream, Error> {
+        let stream = EventStream {
+            event_subsystem,
+            state: Box::default(),
+        };
+        if unsafe {
+            sys::events::SDL_AddEventWatch(Some(event_stream_marshall), stream.user_data())
+        } {
+            Ok(stream)
+        } else {
+            Err(get_error())
+        }
+    }
+
+    fn user_data(&self) -> *mut c_void {
+        &*self.state as *const Mutex<EventStreamState> as *mut c_void
+    }
+}
+
+#[cfg(feature = "futures")]
+impl futures_core::Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        match state.queue.pop_front() {
+            Some(event) => Poll::Ready(Some(event)),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl Drop for EventStream {
+    #[doc(alias = "SDL_RemoveEventWatch")]
+    fn drop(&mut self) {
+        unsafe { sys::events::SDL_RemoveEventWatch(Some(event_stream_marshall), self.user_data()) };
+    }
+}
+
+#[cfg(feature = "futures")]
+extern "C" fn event_stream_marshall(
+    user_data: *mut c_void,
+    event: *mut sdl3_sys::events::SDL_Event,
+) -> bool {
+    let state: &Mutex<EventStreamState> = unsafe { &*(user_data as *const _) };
+    let event = Event::from_ll(unsafe { *event });
+    let waker = {
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.queue.push_back(event);
+        state.waker.take()
+    };
+    if let Some(waker) = waker {
+        // wakers are executor code, which must not unwind into SDL either
+        let _ = catch_unwind(AssertUnwindSafe(|| waker.wake()));
+    }
+    false
+}
+
 impl<F: FnMut(Event)> EventWatchCallback for F {
     fn callback(&mut self, event: Event) {
         self(event)
@@ -15,10 +15,12 @@ use std::convert::TryInto;
 use crate::common::IntegerOrSdlError;
 use crate::get_error;
 use crate::guid::Guid;
+use crate::joystick::{ConnectionState, PowerInfo, PowerLevel};
 use crate::sys;
 use crate::Error;
 use crate::GamepadSubsystem;
 use std::mem::transmute;
+use sys::gamepad::{SDL_GamepadButtonLabel, SDL_GamepadType};
 use sys::joystick::SDL_GetJoystickID;
 
 #[derive(Debug, Clone)]
@@ -115,6 +117,28 @@ impl GamepadSubsystem {
         }
     }
 
+    /// Get the instance ids of the attached gamepads, to pass to [`open`](Self::open).
+    #[doc(alias = "SDL_GetGamepads")]
+    pub fn gamepads(&self) -> Result<Vec<u32>, Error> {
+        let mut num_gamepads: i32 = 0;
+        unsafe {
+            let gamepad_ids = sys::gamepad::SDL_GetGamepads(&mut num_gamepads);
+            if gamepad_ids.is_null() {
+                Err(get_error())
+            } else {
+                let ids = std::slice::from_raw_parts(gamepad_ids, num_gamepads as usize).to_vec();
+                sys::stdinc::SDL_free(gamepad_ids as *mut c_void);
+                Ok(ids)
+            }
+        }
+    }
+
+    /// Return the type of the gamepad with instance id `joystick_index`, without opening it.
+    #[doc(alias = "SDL_GetGamepadTypeForID")]
+    pub fn type_for_id(&self, joystick_index: u32) -> GamepadType {
+        GamepadType::from_ll(unsafe { sys::gamepad::SDL_GetGamepadTypeForID(joystick_index) })
+    }
+
     // FIXME:
     // replaced with SDL_SetGamepadEventsEnabled() and SDL_GamepadEventsEnabled()
 
@@ -134,6 +158,9 @@ impl GamepadSubsystem {
     // }
 
     /// Add a new controller input mapping from a mapping string.
+    ///
+    /// Opened controllers using the mapping are remapped right away and
+    /// report an `Event::ControllerDeviceRemapped`.
     #[doc(alias = "SDL_AddGamepadMapping")]
     pub fn add_mapping(&self, mapping: &str) -> Result<MappingStatus, AddMappingError> {
         use self::AddMappingError::*;
@@ -152,7 +179,18 @@ impl GamepadSubsystem {
         }
     }
 
-    /// Load controller input mappings from a file.
+    /// Load controller input mappings from a file, such as the community
+    /// `gamecontrollerdb.txt`, returning the number of mappings added.
+    ///
+    /// ```no_run
+    /// let sdl_context = sdl3::init().unwrap();
+    /// let gamepad_subsystem = sdl_context.gamepad().unwrap();
+    /// let added = gamepad_subsystem
+    ///     .load_mappings("gamecontrollerdb.txt")
+    ///     .unwrap();
+    /// println!("added {} mappings", added);
+    /// ```
+    #[doc(alias = "SDL_AddGamepadMappingsFromFile")]
     pub fn load_mappings<P: AsRef<Path>>(&self, path: P) -> Result<i32, AddMappingError> {
         use self::AddMappingError::*;
 
@@ -184,11 +222,76 @@ impl GamepadSubsystem {
         }
     }
 
+    /// Return the mapping for the controllers with the given `guid`.
     #[doc(alias = "SDL_GetGamepadMappingForGUID")]
     pub fn mapping_for_guid(&self, guid: Guid) -> Result<String, Error> {
-        let c_str = unsafe { sys::gamepad::SDL_GetGamepadMappingForGUID(guid.raw()) };
+        unsafe {
+            sdl_string_to_string_or_err(sys::gamepad::SDL_GetGamepadMappingForGUID(guid.raw()))
+        }
+    }
+
+    /// Return the mapping for the controller with instance id
+    /// `joystick_index`, without opening it.
+    #[doc(alias = "SDL_GetGamepadMappingForID")]
+    pub fn mapping_for_id(&self, joystick_index: u32) -> Result<String, Error> {
+        unsafe {
+            sdl_string_to_string_or_err(sys::gamepad::SDL_GetGamepadMappingForID(joystick_index))
+        }
+    }
 
-        c_str_to_string_or_err(c_str)
+    /// Return every controller mapping currently known to SDL.
+    #[doc(alias = "SDL_GetGamepadMappings")]
+    pub fn mappings(&self) -> Result<Vec<String>, Error> {
+        let mut count: i32 = 0;
+        unsafe {
+            let mappings = sys::gamepad::SDL_GetGamepadMappings(&mut count);
+            if mappings.is_null() {
+                return Err(get_error());
+            }
+            // the array and the strings are a single allocation
+            let result = std::slice::from_raw_parts(mappings, count as usize)
+                .iter()
+                .map(|&mapping| c_str_to_string(mapping))
+                .collect();
+            sys::stdinc::SDL_free(mappings as *mut c_void);
+            Ok(result)
+        }
+    }
+
+    /// Replace the mapping of the controller with instance id
+    /// `joystick_index`, or restore its default mapping with `None`.
+    ///
+    /// Unlike [`add_mapping`](Self::add_mapping), this only affects this one
+    /// controller.
+    #[doc(alias = "SDL_SetGamepadMapping")]
+    pub fn set_mapping(
+        &self,
+        joystick_index: u32,
+        mapping: Option<&str>,
+    ) -> Result<(), AddMappingError> {
+        use self::AddMappingError::*;
+        let mapping = match mapping.map(CString::new).transpose() {
+            Ok(s) => s,
+            Err(err) => return Err(InvalidMapping(err)),
+        };
+        let mapping_ptr = mapping.as_ref().map_or(std::ptr::null(), |s| s.as_ptr());
+
+        if unsafe { sys::gamepad::SDL_SetGamepadMapping(joystick_index, mapping_ptr) } {
+            Ok(())
+        } else {
+            Err(SdlError(get_error()))
+        }
+    }
+
+    /// Reinitialize the mappings from SDL's built-in database and the
+    /// `SDL_HINT_GAMECONTROLLERCONFIG` hints, dropping mappings added since.
+    #[doc(alias = "SDL_ReloadGamepadMappings")]
+    pub fn reload_mappings(&self) -> Result<(), Error> {
+        if unsafe { sys::gamepad::SDL_ReloadGamepadMappings() } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     #[inline]
@@ -395,6 +498,124 @@ pub enum MappingStatus {
     Updated = 0,
 }
 
+/// The kind of a gamepad, e.g. to show matching button prompts.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum GamepadType {
+    Unknown,
+    Standard,
+    Xbox360,
+    XboxOne,
+    PS3,
+    PS4,
+    PS5,
+    NintendoSwitchPro,
+    NintendoSwitchJoyconLeft,
+    NintendoSwitchJoyconRight,
+    NintendoSwitchJoyconPair,
+}
+
+impl GamepadType {
+    pub fn from_ll(raw: SDL_GamepadType) -> GamepadType {
+        match raw {
+            SDL_GamepadType::STANDARD => GamepadType::Standard,
+            SDL_GamepadType::XBOX360 => GamepadType::Xbox360,
+            SDL_GamepadType::XBOXONE => GamepadType::XboxOne,
+            SDL_GamepadType::PS3 => GamepadType::PS3,
+            SDL_GamepadType::PS4 => GamepadType::PS4,
+            SDL_GamepadType::PS5 => GamepadType::PS5,
+            SDL_GamepadType::NINTENDO_SWITCH_PRO => GamepadType::NintendoSwitchPro,
+            SDL_GamepadType::NINTENDO_SWITCH_JOYCON_LEFT => GamepadType::NintendoSwitchJoyconLeft,
+            SDL_GamepadType::NINTENDO_SWITCH_JOYCON_RIGHT => GamepadType::NintendoSwitchJoyconRight,
+            SDL_GamepadType::NINTENDO_SWITCH_JOYCON_PAIR => GamepadType::NintendoSwitchJoyconPair,
+            _ => GamepadType::Unknown,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_GamepadType {
+        match self {
+            GamepadType::Unknown => SDL_GamepadType::UNKNOWN,
+            GamepadType::Standard => SDL_GamepadType::STANDARD,
+            GamepadType::Xbox360 => SDL_GamepadType::XBOX360,
+            GamepadType::XboxOne => SDL_GamepadType::XBOXONE,
+            GamepadType::PS3 => SDL_GamepadType::PS3,
+            GamepadType::PS4 => SDL_GamepadType::PS4,
+            GamepadType::PS5 => SDL_GamepadType::PS5,
+            GamepadType::NintendoSwitchPro => SDL_GamepadType::NINTENDO_SWITCH_PRO,
+            GamepadType::NintendoSwitchJoyconLeft => SDL_GamepadType::NINTENDO_SWITCH_JOYCON_LEFT,
+            GamepadType::NintendoSwitchJoyconRight => SDL_GamepadType::NINTENDO_SWITCH_JOYCON_RIGHT,
+            GamepadType::NintendoSwitchJoyconPair => SDL_GamepadType::NINTENDO_SWITCH_JOYCON_PAIR,
+        }
+    }
+
+    /// Return a `GamepadType` from its SDL string name, e.g. `"ps5"`.
+    #[doc(alias = "SDL_GetGamepadTypeFromString")]
+    pub fn from_string(name: &str) -> GamepadType {
+        let name = match CString::new(name) {
+            Ok(name) => name,
+            Err(_) => return GamepadType::Unknown,
+        };
+        GamepadType::from_ll(unsafe { sys::gamepad::SDL_GetGamepadTypeFromString(name.as_ptr()) })
+    }
+
+    /// Return the SDL string name of this type, as used in mappings.
+    #[doc(alias = "SDL_GetGamepadStringForType")]
+    pub fn string(self) -> String {
+        c_str_to_string(unsafe { sys::gamepad::SDL_GetGamepadStringForType(self.to_ll()) })
+    }
+}
+
+/// The label printed on a face button, which differs between gamepad types.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ButtonLabel {
+    Unknown,
+    A,
+    B,
+    X,
+    Y,
+    Cross,
+    Circle,
+    Square,
+    Triangle,
+}
+
+impl ButtonLabel {
+    pub fn from_ll(raw: SDL_GamepadButtonLabel) -> ButtonLabel {
+        match raw {
+            SDL_GamepadButtonLabel::A => ButtonLabel::A,
+            SDL_GamepadButtonLabel::B => ButtonLabel::B,
+            SDL_GamepadButtonLabel::X => ButtonLabel::X,
+            SDL_GamepadButtonLabel::Y => ButtonLabel::Y,
+            SDL_GamepadButtonLabel::CROSS => ButtonLabel::Cross,
+            SDL_GamepadButtonLabel::CIRCLE => ButtonLabel::Circle,
+            SDL_GamepadButtonLabel::SQUARE => ButtonLabel::Square,
+            SDL_GamepadButtonLabel::TRIANGLE => ButtonLabel::Triangle,
+            _ => ButtonLabel::Unknown,
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_GamepadButtonLabel {
+        match self {
+            ButtonLabel::Unknown => SDL_GamepadButtonLabel::UNKNOWN,
+            ButtonLabel::A => SDL_GamepadButtonLabel::A,
+            ButtonLabel::B => SDL_GamepadButtonLabel::B,
+            ButtonLabel::X => SDL_GamepadButtonLabel::X,
+            ButtonLabel::Y => SDL_GamepadButtonLabel::Y,
+            ButtonLabel::Cross => SDL_GamepadButtonLabel::CROSS,
+            ButtonLabel::Circle => SDL_GamepadButtonLabel::CIRCLE,
+            ButtonLabel::Square => SDL_GamepadButtonLabel::SQUARE,
+            ButtonLabel::Triangle => SDL_GamepadButtonLabel::TRIANGLE,
+        }
+    }
+
+    /// Return the label of `button` on gamepads of type `gamepad_type`.
+    #[doc(alias = "SDL_GetGamepadButtonLabelForType")]
+    pub fn for_type(gamepad_type: GamepadType, button: Button) -> ButtonLabel {
+        ButtonLabel::from_ll(unsafe {
+            sys::gamepad::SDL_GetGamepadButtonLabelForType(gamepad_type.to_ll(), button.to_ll())
+        })
+    }
+}
+
 /// Wrapper around the `SDL_Gamepad` object
 pub struct Gamepad {
     subsystem: GamepadSubsystem,
@@ -416,13 +637,28 @@ impl Gamepad {
         c_str_to_string(name)
     }
 
+    /// Return the type of the controller, or `GamepadType::Unknown` if
+    /// it could not be determined.
+    #[doc(alias = "SDL_GetGamepadType")]
+    pub fn gamepad_type(&self) -> GamepadType {
+        GamepadType::from_ll(unsafe { sys::gamepad::SDL_GetGamepadType(self.raw) })
+    }
+
+    /// Return the implementation-dependent path of the controller, or an
+    /// empty string if no path is found.
+    #[doc(alias = "SDL_GetGamepadPath")]
+    pub fn path(&self) -> String {
+        let path = unsafe { sys::gamepad::SDL_GetGamepadPath(self.raw) };
+
+        c_str_to_string(path)
+    }
+
     /// Return a String describing the controller's button and axis
     /// mappings
     #[doc(alias = "SDL_GetGamepadMapping")]
     pub fn mapping(&self) -> String {
-        let mapping = unsafe { sys::gamepad::SDL_GetGamepadMapping(self.raw) };
-
-        c_str_to_string(mapping)
+        unsafe { sdl_string_to_string_or_err(sys::gamepad::SDL_GetGamepadMapping(self.raw)) }
+            .unwrap_or_default()
     }
 
     /// Return true if the controller has been opened and currently
@@ -432,6 +668,24 @@ impl Gamepad {
         unsafe { sys::gamepad::SDL_GamepadConnected(self.raw) }
     }
 
+    /// Retrieve the battery level of this controller.
+    #[doc(alias = "SDL_GetGamepadPowerInfo")]
+    pub fn power_info(&self) -> PowerInfo {
+        let mut percentage: core::ffi::c_int = 0;
+        let state = unsafe { sys::gamepad::SDL_GetGamepadPowerInfo(self.raw, &mut percentage) };
+        PowerInfo {
+            state: PowerLevel::from_ll(state),
+            percentage,
+        }
+    }
+
+    /// Retrieve whether this controller is wired or wireless.
+    #[doc(alias = "SDL_GetGamepadConnectionState")]
+    pub fn connection_state(&self) -> Result<ConnectionState, Error> {
+        let result = unsafe { sys::gamepad::SDL_GetGamepadConnectionState(self.raw) };
+        ConnectionState::from_ll(result).ok_or_else(get_error)
+    }
+
     /// Return the joystick instance id of this controller
     #[doc(alias = "SDL_GetGamepadJoystick")]
     pub fn instance_id(&self) -> u32 {
@@ -474,6 +728,15 @@ impl Gamepad {
         unsafe { sys::gamepad::SDL_GetGamepadButton(self.raw, raw_button) }
     }
 
+    /// Return the label printed on `button`, e.g. `ButtonLabel::Cross` for
+    /// `Button::South` on PlayStation controllers.
+    #[doc(alias = "SDL_GetGamepadButtonLabel")]
+    pub fn button_label(&self, button: Button) -> ButtonLabel {
+        ButtonLabel::from_ll(unsafe {
+            sys::gamepad::SDL_GetGamepadButtonLabel(self.raw, button.to_ll())
+        })
+    }
+
     /// Set the rumble motors to their specified intensities, if supported.
     /// Automatically resets back to zero after `duration_ms` milliseconds have passed.
     ///
@@ -684,17 +947,16 @@ fn c_str_to_string(c_str: *const c_char) -> String {
     }
 }
 
-/// Convert C string `c_str` to a String. Return an SDL error if
-/// `c_str` is NULL.
-fn c_str_to_string_or_err(c_str: *const c_char) -> Result<String, Error> {
+/// Convert C string `c_str` allocated by SDL to a String and free it.
+/// Return an SDL error if `c_str` is NULL.
+unsafe fn sdl_string_to_string_or_err(c_str: *mut c_char) -> Result<String, Error> {
     if c_str.is_null() {
         Err(get_error())
     } else {
-        Ok(unsafe {
-            CStr::from_ptr(c_str as *const _)
-                .to_str()
-                .unwrap()
-                .to_owned()
-        })
+        let string = CStr::from_ptr(c_str as *const _)
+            .to_string_lossy()
+            .into_owned();
+        sys::stdinc::SDL_free(c_str as *mut c_void);
+        Ok(string)
     }
 }
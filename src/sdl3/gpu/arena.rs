@@ -0,0 +1,222 @@
+use crate::{
+    gpu::{Buffer, BufferBinding, BufferRegion},
+    Error,
+};
+
+/// Hands out aligned sub-ranges of a single large GPU buffer.
+///
+/// Creating many small buffers is slow on most backends; an arena creates one buffer up front
+/// and binds its allocations with an offset instead. Created with
+/// [`Device::create_buffer_arena`](crate::gpu::Device::create_buffer_arena).
+pub struct BufferArena {
+    buffer: Buffer,
+    alignment: u32,
+    ranges: FreeList,
+    generation: u64,
+}
+impl BufferArena {
+    pub(super) fn new(buffer: Buffer, alignment: u32) -> Self {
+        let ranges = FreeList::new(buffer.len());
+        Self {
+            buffer,
+            alignment: alignment.max(1),
+            ranges,
+            generation: 0,
+        }
+    }
+
+    /// The buffer every allocation lives in.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// The offset of every allocation is a multiple of this many bytes.
+    pub fn alignment(&self) -> u32 {
+        self.alignment
+    }
+
+    /// The total size of the arena in bytes.
+    pub fn capacity(&self) -> u32 {
+        self.buffer.len()
+    }
+
+    /// The number of bytes not currently handed out, including bytes lost to fragmentation.
+    pub fn available(&self) -> u32 {
+        self.ranges.available()
+    }
+
+    /// Reserves `size` bytes, starting at an offset that is a multiple of the arena's alignment.
+    pub fn allocate(&mut self, size: u32) -> Result<BufferAllocation, Error> {
+        let reserved = size.max(1);
+        let offset = self
+            .ranges
+            .allocate(reserved, self.alignment)
+            .ok_or_else(|| {
+                Error(format!(
+                    "no room for {} bytes in buffer arena ({} of {} bytes available)",
+                    size,
+                    self.available(),
+                    self.capacity()
+                ))
+            })?;
+        Ok(BufferAllocation {
+            buffer: self.buffer.clone(),
+            offset,
+            size,
+            reserved,
+            generation: self.generation,
+        })
+    }
+
+    /// Returns an allocation to the arena so its range can be handed out again.
+    ///
+    /// Allocations made before the last [`reset`](Self::reset) are ignored.
+    pub fn free(&mut self, allocation: BufferAllocation) {
+        assert!(
+            allocation.buffer.raw() == self.buffer.raw(),
+            "allocation does not belong to this arena"
+        );
+        if allocation.generation == self.generation {
+            self.ranges.free(allocation.offset, allocation.reserved);
+        }
+    }
+
+    /// Frees every allocation at once, e.g. at the start of a frame.
+    pub fn reset(&mut self) {
+        self.ranges = FreeList::new(self.buffer.len());
+        self.generation += 1;
+    }
+}
+
+/// A sub-range of a [`BufferArena`]'s buffer.
+pub struct BufferAllocation {
+    buffer: Buffer,
+    offset: u32,
+    size: u32,
+    reserved: u32,
+    generation: u64,
+}
+impl BufferAllocation {
+    /// The arena's buffer.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// The offset of this allocation in bytes from the start of the buffer.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// The requested size of this allocation in bytes.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// A binding of the buffer at this allocation's offset, for vertex or index buffers.
+    pub fn binding(&self) -> BufferBinding {
+        BufferBinding::new()
+            .with_buffer(&self.buffer)
+            .with_offset(self.offset)
+    }
+
+    /// The region covered by this allocation, for uploads and downloads in a copy pass.
+    pub fn region(&self) -> BufferRegion {
+        BufferRegion::new()
+            .with_buffer(&self.buffer)
+            .with_offset(self.offset)
+            .with_size(self.size)
+    }
+}
+
+/// Free byte ranges as `(offset, len)`, sorted by offset and never adjacent.
+struct FreeList {
+    ranges: Vec<(u32, u32)>,
+}
+impl FreeList {
+    fn new(len: u32) -> Self {
+        Self {
+            ranges: if len > 0 { vec![(0, len)] } else { vec![] },
+        }
+    }
+
+    fn available(&self) -> u32 {
+        self.ranges.iter().map(|&(_, len)| len).sum()
+    }
+
+    /// First-fit allocation of `size` bytes, returning the aligned offset.
+    fn allocate(&mut self, size: u32, alignment: u32) -> Option<u32> {
+        for i in 0..self.ranges.len() {
+            let (start, len) = self.ranges[i];
+            let end = start as u64 + len as u64;
+            let aligned = (start as u64).div_ceil(alignment as u64) * alignment as u64;
+            if aligned + size as u64 > end {
+                continue;
+            }
+            let aligned = aligned as u32;
+            let tail = (end - aligned as u64 - size as u64) as u32;
+            let head = aligned - start;
+            match (head, tail) {
+                (0, 0) => {
+                    self.ranges.remove(i);
+                }
+                (0, _) => self.ranges[i] = (aligned + size, tail),
+                (_, 0) => self.ranges[i] = (start, head),
+                _ => {
+                    self.ranges[i] = (start, head);
+                    self.ranges.insert(i + 1, (aligned + size, tail));
+                }
+            }
+            return Some(aligned);
+        }
+        None
+    }
+
+    fn free(&mut self, offset: u32, len: u32) {
+        let i = self.ranges.partition_point(|&(start, _)| start < offset);
+        self.ranges.insert(i, (offset, len));
+        if i + 1 < self.ranges.len() && offset + len == self.ranges[i + 1].0 {
+            self.ranges[i].1 += self.ranges[i + 1].1;
+            self.ranges.remove(i + 1);
+        }
+        if i > 0 && self.ranges[i - 1].0 + self.ranges[i - 1].1 == offset {
+            self.ranges[i - 1].1 += self.ranges[i].1;
+            self.ranges.remove(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FreeList;
+
+    #[test]
+    fn allocations_are_aligned() {
+        let mut list = FreeList::new(256);
+        assert_eq!(list.allocate(10, 16), Some(0));
+        assert_eq!(list.allocate(10, 16), Some(16));
+        assert_eq!(list.ranges, vec![(10, 6), (26, 230)]);
+        assert_eq!(list.allocate(4, 2), Some(10));
+    }
+
+    #[test]
+    fn free_coalesces_neighbours() {
+        let mut list = FreeList::new(64);
+        let a = list.allocate(16, 1).unwrap();
+        let b = list.allocate(16, 1).unwrap();
+        let c = list.allocate(16, 1).unwrap();
+        list.free(a, 16);
+        list.free(c, 16);
+        assert_eq!(list.ranges, vec![(0, 16), (32, 32)]);
+        list.free(b, 16);
+        assert_eq!(list.ranges, vec![(0, 64)]);
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        let mut list = FreeList::new(32);
+        assert_eq!(list.allocate(33, 1), None);
+        assert_eq!(list.allocate(20, 1), Some(0));
+        assert_eq!(list.allocate(8, 16), None);
+        assert_eq!(list.available(), 12);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    gpu::{
+        CopyPass, Device, Texture, TextureCreateInfo, TextureFormat, TextureLocation, TextureType,
+        TextureUsage,
+    },
+    rect::Rect,
+    render::FRect,
+    sys, Error,
+};
+
+/// Packs many small textures into a single 2D texture using a copy pass.
+///
+/// The texels are copied verbatim with `SDL_CopyGPUTextureToTexture`, so every texture
+/// added to the builder must have the same format as the atlas.
+pub struct TextureAtlasBuilder<'a> {
+    device: &'a Device,
+    max_width: u32,
+    max_height: u32,
+    padding: u32,
+    format: TextureFormat,
+    usage: TextureUsage,
+    sources: Vec<&'a Texture<'static>>,
+}
+impl<'a> TextureAtlasBuilder<'a> {
+    pub(super) fn new(device: &'a Device, max_width: u32, max_height: u32) -> Self {
+        Self {
+            device,
+            max_width,
+            max_height,
+            padding: 0,
+            format: TextureFormat::R8g8b8a8Unorm,
+            usage: TextureUsage::Sampler,
+            sources: Vec::new(),
+        }
+    }
+
+    /// The pixel format of the atlas texture. Defaults to `R8g8b8a8Unorm`.
+    pub fn with_format(mut self, format: TextureFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// How the atlas texture is intended to be used. Defaults to `Sampler`.
+    pub fn with_usage(mut self, usage: TextureUsage) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Number of empty texels kept between neighbouring entries, to avoid bleeding when filtering.
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Adds a texture to the atlas. Entries are indexed in the order they were added.
+    pub fn with_texture(mut self, texture: &'a Texture<'static>) -> Self {
+        self.sources.push(texture);
+        self
+    }
+
+    /// Creates the atlas texture and records the copies of every entry into `copy_pass`.
+    ///
+    /// Errors if a texture does not have the atlas format. Textures wrapped with
+    /// [`Texture::from_ll`] have no known format and are not checked.
+    ///
+    /// The atlas contents are only valid once the command buffer owning `copy_pass` has been submitted.
+    pub fn build(self, copy_pass: &CopyPass) -> Result<TextureAtlas, Error> {
+        let atlas_format = sys::gpu::SDL_GPUTextureFormat(self.format as i32);
+        if let Some(index) = self.sources.iter().position(|texture| {
+            texture
+                .raw_format()
+                .is_some_and(|format| format != atlas_format)
+        }) {
+            return Err(Error(format!(
+                "texture {} does not have the atlas format {:?}",
+                index, self.format
+            )));
+        }
+
+        let sizes: Vec<(u32, u32)> = self
+            .sources
+            .iter()
+            .map(|texture| (texture.width(), texture.height()))
+            .collect();
+        let (regions, width, height) =
+            pack_shelves(&sizes, self.max_width, self.max_height, self.padding).ok_or_else(
+                || {
+                    Error(format!(
+                        "textures do not fit in a {}x{} atlas",
+                        self.max_width, self.max_height
+                    ))
+                },
+            )?;
+
+        let texture = self.device.create_texture(
+            TextureCreateInfo::new()
+                .with_type(TextureType::_2D)
+                .with_format(self.format)
+                .with_usage(self.usage)
+                .with_width(width.max(1))
+                .with_height(height.max(1))
+                .with_layer_count_or_depth(1)
+                .with_num_levels(1),
+        )?;
+
+        for (source, region) in self.sources.iter().zip(&regions) {
+            copy_pass.copy_texture_to_texture(
+                TextureLocation::new().with_texture(source),
+                TextureLocation::new()
+                    .with_texture(&texture)
+                    .with_x(region.x() as u32)
+                    .with_y(region.y() as u32),
+                region.width(),
+                region.height(),
+                1,
+                false,
+            );
+        }
+
+        Ok(TextureAtlas { texture, regions })
+    }
+}
+
+/// A texture holding several packed sub-images, created by [`TextureAtlasBuilder`].
+pub struct TextureAtlas {
+    texture: Texture<'static>,
+    regions: Vec<Rect>,
+}
+impl TextureAtlas {
+    /// The texture every entry was copied into.
+    pub fn texture(&self) -> &Texture<'static> {
+        &self.texture
+    }
+
+    /// The number of entries in the atlas.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Whether the atlas has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// The area covered by the entry at `index`, in texels.
+    pub fn region(&self, index: usize) -> Option<Rect> {
+        self.regions.get(index).copied()
+    }
+
+    /// The area covered by the entry at `index`, in normalized texture coordinates.
+    pub fn uv_rect(&self, index: usize) -> Option<FRect> {
+        let width = self.texture.width() as f32;
+        let height = self.texture.height() as f32;
+        self.region(index).map(|region| {
+            FRect::new(
+                region.x() as f32 / width,
+                region.y() as f32 / height,
+                region.width() as f32 / width,
+                region.height() as f32 / height,
+            )
+        })
+    }
+}
+
+/// Places rectangles of the given sizes on horizontal shelves, tallest first.
+///
+/// Returns the placed rectangles in input order, along with the width and height actually used,
+/// or `None` if they don't all fit within `max_width` x `max_height`.
+fn pack_shelves(
+    sizes: &[(u32, u32)],
+    max_width: u32,
+    max_height: u32,
+    padding: u32,
+) -> Option<(Vec<Rect>, u32, u32)> {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(sizes[i].1));
+
+    let mut regions = vec![Rect::new(0, 0, 1, 1); sizes.len()];
+    let mut x = 0u32;
+    let mut y = 0u32;
+    let mut shelf_height = 0;
+    let mut used_width = 0;
+    for i in order {
+        let (w, h) = sizes[i];
+        if w > max_width {
+            return None;
+        }
+        if x.checked_add(w).is_none_or(|end| end > max_width) {
+            y = y.checked_add(shelf_height)?.checked_add(padding)?;
+            x = 0;
+            shelf_height = 0;
+        }
+        if y.checked_add(h).is_none_or(|end| end > max_height) {
+            return None;
+        }
+        regions[i] = Rect::new(x as i32, y as i32, w, h);
+        used_width = used_width.max(x + w);
+        shelf_height = shelf_height.max(h);
+        // a saturated position starts a new shelf for the next entry
+        x = (x + w).saturating_add(padding);
+    }
+    Some((regions, used_width, y + shelf_height))
+}
+
+#[cfg(test)]
+mod test {
+    use super::pack_shelves;
+    use crate::rect::Rect;
+
+    #[test]
+    fn packs_in_input_order() {
+        let (regions, width, height) = pack_shelves(&[(4, 2), (4, 8), (4, 4)], 8, 16, 0).unwrap();
+        assert_eq!(regions[1], Rect::new(0, 0, 4, 8));
+        assert_eq!(regions[2], Rect::new(4, 0, 4, 4));
+        assert_eq!(regions[0], Rect::new(0, 8, 4, 2));
+        assert_eq!((width, height), (8, 10));
+    }
+
+    #[test]
+    fn padding_separates_entries() {
+        let (regions, width, height) = pack_shelves(&[(4, 4), (4, 4)], 16, 16, 2).unwrap();
+        assert_eq!(regions[0], Rect::new(0, 0, 4, 4));
+        assert_eq!(regions[1], Rect::new(6, 0, 4, 4));
+        assert_eq!((width, height), (10, 4));
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert!(pack_shelves(&[(9, 1)], 8, 8, 0).is_none());
+        assert!(pack_shelves(&[(8, 5), (8, 5)], 8, 8, 0).is_none());
+    }
+
+    #[test]
+    fn huge_padding_does_not_overflow() {
+        let (regions, width, height) = pack_shelves(&[(4, 4)], 8, 8, u32::MAX).unwrap();
+        assert_eq!(regions[0], Rect::new(0, 0, 4, 4));
+        assert_eq!((width, height), (4, 4));
+        assert!(pack_shelves(&[(4, 4), (4, 4)], 16, u32::MAX, u32::MAX).is_none());
+    }
+}
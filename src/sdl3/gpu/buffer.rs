@@ -1,6 +1,9 @@
 use crate::{
     get_error,
-    gpu::{device::WeakDevice, BufferUsageFlags, Device, TransferBufferUsage, VertexInputRate},
+    gpu::{
+        device::WeakDevice, validation, BufferUsageFlags, Device, Fence, TransferBufferUsage,
+        VertexInputRate,
+    },
     sys, Error,
 };
 use std::sync::Arc;
@@ -117,6 +120,7 @@ struct BufferContainer {
 impl Drop for BufferContainer {
     #[doc(alias = "SDL_ReleaseGPUBuffer")]
     fn drop(&mut self) {
+        validation::resource_released(self.raw);
         if let Some(device) = self.device.upgrade() {
             unsafe {
                 SDL_ReleaseGPUBuffer(device.raw(), self.raw);
@@ -132,6 +136,28 @@ pub struct Buffer {
     len: u32,
 }
 impl Buffer {
+    /// Takes ownership of a buffer that was created outside of this crate, e.g. through
+    /// `sys::gpu::SDL_CreateGPUBuffer` with custom properties.
+    ///
+    /// SDL 3.2 cannot wrap backend-native buffers (Vulkan, D3D12, Metal), so `raw` must be an
+    /// `SDL_GPUBuffer`.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid buffer created by `device`, at least `len` bytes long, and must not
+    /// be released by anyone else: it is released when the last clone of the returned `Buffer`
+    /// is dropped.
+    pub unsafe fn from_ll(device: &Device, raw: *mut SDL_GPUBuffer, len: u32) -> Buffer {
+        validation::resource_created(raw);
+        Buffer {
+            len,
+            inner: Arc::new(BufferContainer {
+                raw,
+                device: device.weak(),
+            }),
+        }
+    }
+
     /// Yields the raw SDL_GPUBuffer pointer.
     #[inline]
     pub fn raw(&self) -> *mut SDL_GPUBuffer {
@@ -171,13 +197,7 @@ impl<'a> BufferBuilder<'a> {
         if raw_buffer.is_null() {
             Err(get_error())
         } else {
-            Ok(Buffer {
-                len: self.inner.size,
-                inner: Arc::new(BufferContainer {
-                    raw: raw_buffer,
-                    device: self.device.weak(),
-                }),
-            })
+            Ok(unsafe { Buffer::from_ll(self.device, raw_buffer, self.inner.size) })
         }
     }
 }
@@ -211,6 +231,27 @@ where
     }
 }
 
+/// Read-only mapped memory of a download transfer buffer, unmapped on drop.
+///
+/// Obtained from [`TransferBuffer::map_read`].
+pub struct BufferReadMap<'a> {
+    device: &'a Device,
+    transfer_buffer: &'a TransferBuffer,
+    mem: *const u8,
+}
+impl BufferReadMap<'_> {
+    /// The downloaded bytes.
+    pub fn bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.mem, self.transfer_buffer.len() as usize) }
+    }
+}
+impl Drop for BufferReadMap<'_> {
+    #[doc(alias = "SDL_UnmapGPUTransferBuffer")]
+    fn drop(&mut self) {
+        unsafe { SDL_UnmapGPUTransferBuffer(self.device.raw(), self.transfer_buffer.raw()) };
+    }
+}
+
 /// Manages the raw `SDL_GPUTransferBuffer` pointer and releases it on drop
 struct TransferBufferContainer {
     raw: *mut SDL_GPUTransferBuffer,
@@ -219,6 +260,7 @@ struct TransferBufferContainer {
 impl Drop for TransferBufferContainer {
     #[doc(alias = "SDL_ReleaseGPUTransferBuffer")]
     fn drop(&mut self) {
+        validation::resource_released(self.raw);
         if let Some(device) = self.device.upgrade() {
             unsafe {
                 SDL_ReleaseGPUTransferBuffer(device.raw(), self.raw);
@@ -231,6 +273,7 @@ impl Drop for TransferBufferContainer {
 pub struct TransferBuffer {
     inner: Arc<TransferBufferContainer>,
     len: u32,
+    usage: TransferBufferUsage,
 }
 impl TransferBuffer {
     #[inline]
@@ -247,26 +290,63 @@ impl TransferBuffer {
         }
     }
 
+    /// Waits for `fence`, then maps the buffer for reading what a copy pass downloaded into it.
+    ///
+    /// `fence` must belong to the command buffer that recorded the download, see
+    /// [`CommandBuffer::submit_and_acquire_fence`](crate::gpu::CommandBuffer::submit_and_acquire_fence).
+    /// Fails if the buffer was not created with [`TransferBufferUsage::Download`].
+    #[doc(alias = "SDL_MapGPUTransferBuffer")]
+    pub fn map_read<'a>(
+        &'a self,
+        device: &'a Device,
+        fence: &Fence,
+    ) -> Result<BufferReadMap<'a>, Error> {
+        if self.usage != TransferBufferUsage::Download {
+            return Err(Error(
+                "map_read requires a transfer buffer with Download usage".to_owned(),
+            ));
+        }
+        device.wait_for_fences(true, &[fence])?;
+        let mem = unsafe { SDL_MapGPUTransferBuffer(device.raw(), self.raw(), false) };
+        if mem.is_null() {
+            Err(get_error())
+        } else {
+            Ok(BufferReadMap {
+                device,
+                transfer_buffer: self,
+                mem: mem as *const u8,
+            })
+        }
+    }
+
     /// The length of this buffer in bytes.
     pub fn len(&self) -> u32 {
         self.len
     }
+
+    /// Whether this buffer is used for uploads or downloads.
+    pub fn usage(&self) -> TransferBufferUsage {
+        self.usage
+    }
 }
 
 pub struct TransferBufferBuilder<'a> {
     device: &'a Device,
     inner: SDL_GPUTransferBufferCreateInfo,
+    usage: TransferBufferUsage,
 }
 impl<'a> TransferBufferBuilder<'a> {
     pub(super) fn new(device: &'a Device) -> Self {
         Self {
             device,
             inner: Default::default(),
+            usage: TransferBufferUsage::default(),
         }
     }
 
     /// How the buffer will be used.
     pub fn with_usage(mut self, value: TransferBufferUsage) -> Self {
+        self.usage = value;
         self.inner.usage = SDL_GPUTransferBufferUsage(value as i32);
         self
     }
@@ -282,12 +362,14 @@ impl<'a> TransferBufferBuilder<'a> {
         if raw_buffer.is_null() {
             Err(get_error())
         } else {
+            validation::resource_created(raw_buffer);
             Ok(TransferBuffer {
                 inner: Arc::new(TransferBufferContainer {
                     raw: raw_buffer,
                     device: self.device.weak(),
                 }),
                 len: self.inner.size,
+                usage: self.usage,
             })
         }
     }
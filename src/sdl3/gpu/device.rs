@@ -1,22 +1,29 @@
 use crate::{
     get_error,
     gpu::{
-        BufferBuilder, ColorTargetInfo, CommandBuffer, CopyPass, DepthStencilTargetInfo,
-        GraphicsPipelineBuilder, RenderPass, Sampler, SamplerCreateInfo, ShaderBuilder,
-        ShaderFormat, Texture, TextureCreateInfo, TextureFormat, TransferBufferBuilder,
+        BufferArena, BufferBuilder, BufferUsageFlags, ColorTargetInfo, CommandBuffer, CopyPass,
+        DepthStencilTargetInfo, Fence, FrameContext, GraphicsPipelineBuilder, RenderPass, Sampler,
+        SamplerCreateInfo, ShaderBuilder, ShaderFormat, Texture, TextureAtlasBuilder,
+        TextureCreateInfo, TextureFormat, TransferBufferBuilder,
     },
     sys, Error,
 };
-use std::sync::{Arc, Weak};
+use std::{
+    ffi::{CStr, CString},
+    sync::{Arc, Weak},
+};
 use sys::gpu::{
     SDL_BeginGPUComputePass, SDL_BeginGPUCopyPass, SDL_BeginGPURenderPass, SDL_CreateGPUDevice,
     SDL_CreateGPUSampler, SDL_CreateGPUTexture, SDL_DestroyGPUDevice, SDL_GPUColorTargetInfo,
-    SDL_GPUDepthStencilTargetInfo, SDL_GPUDevice, SDL_GPUViewport,
-    SDL_GetGPUSwapchainTextureFormat, SDL_SetGPUViewport,
+    SDL_GPUDepthStencilTargetInfo, SDL_GPUDevice, SDL_GPUViewport, SDL_GetGPUDeviceDriver,
+    SDL_GetGPUDriver, SDL_GetGPUSwapchainTextureFormat, SDL_GetNumGPUDrivers, SDL_QueryGPUFence,
+    SDL_SetGPUViewport, SDL_WaitForGPUFences, SDL_WaitForGPUIdle, SDL_WaitForGPUSwapchain,
 };
 
 use super::{
     pipeline::{StorageBufferReadWriteBinding, StorageTextureReadWriteBinding},
+    trace,
+    validation::PassState,
     ComputePass, ComputePipelineBuilder,
 };
 
@@ -36,11 +43,16 @@ impl Drop for DeviceContainer {
 
 pub(super) type WeakDevice = Weak<DeviceContainer>;
 
+/// A handle to an `SDL_GPUDevice`.
+///
+/// SDL 3.2 does not expose the backend-native objects behind a device (`VkDevice`,
+/// `ID3D12Device`, `MTLDevice`, ...), so [`Device::raw`] is the lowest level of access available.
 #[derive(Clone)]
 pub struct Device {
     inner: Arc<DeviceContainer>,
 }
 impl Device {
+    /// Yields the raw `SDL_GPUDevice` pointer, for use with the `sys::gpu` functions.
     #[inline]
     pub fn raw(&self) -> *mut SDL_GPUDevice {
         self.inner.0
@@ -53,6 +65,25 @@ impl Device {
     #[doc(alias = "SDL_CreateGPUDevice")]
     pub fn new(flags: ShaderFormat, debug_mode: bool) -> Result<Self, Error> {
         let raw_device = unsafe { SDL_CreateGPUDevice(flags as u32, debug_mode, std::ptr::null()) };
+        Self::from_created(raw_device)
+    }
+
+    /// Like [`Device::new`], but requests a specific backend by name, as returned by [`drivers`].
+    ///
+    /// Fails if that driver is unavailable or doesn't support any of the shader formats in `flags`.
+    #[doc(alias = "SDL_CreateGPUDevice")]
+    pub fn new_with_driver(
+        flags: ShaderFormat,
+        debug_mode: bool,
+        driver: &str,
+    ) -> Result<Self, Error> {
+        let driver = CString::new(driver)
+            .map_err(|_| Error("driver name contains a nul byte".to_owned()))?;
+        let raw_device = unsafe { SDL_CreateGPUDevice(flags as u32, debug_mode, driver.as_ptr()) };
+        Self::from_created(raw_device)
+    }
+
+    fn from_created(raw_device: *mut SDL_GPUDevice) -> Result<Self, Error> {
         if raw_device.is_null() {
             Err(get_error())
         } else {
@@ -62,6 +93,19 @@ impl Device {
         }
     }
 
+    /// The name of the backend this device was created with, e.g. `"vulkan"`.
+    #[doc(alias = "SDL_GetGPUDeviceDriver")]
+    pub fn driver(&self) -> String {
+        let name = unsafe { SDL_GetGPUDeviceDriver(self.raw()) };
+        if name.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(name) }
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
     #[doc(alias = "SDL_ClaimWindowForGPUDevice")]
     pub fn with_window(self, w: &crate::video::Window) -> Result<Self, Error> {
         let p = unsafe { sys::gpu::SDL_ClaimWindowForGPUDevice(self.inner.0, w.raw()) };
@@ -74,14 +118,74 @@ impl Device {
 
     #[doc(alias = "SDL_AcquireGPUCommandBuffer")]
     pub fn acquire_command_buffer(&self) -> Result<CommandBuffer, Error> {
+        let _span = trace::scope("acquire_command_buffer");
         let raw_buffer = unsafe { sys::gpu::SDL_AcquireGPUCommandBuffer(self.inner.0) };
         if raw_buffer.is_null() {
             Err(get_error())
         } else {
-            Ok(CommandBuffer::new(raw_buffer))
+            Ok(CommandBuffer::new(self.weak(), raw_buffer))
         }
     }
 
+    /// Blocks until one (or all, if `wait_all` is true) of the given fences is signaled.
+    #[doc(alias = "SDL_WaitForGPUFences")]
+    pub fn wait_for_fences(&self, wait_all: bool, fences: &[&Fence]) -> Result<(), Error> {
+        let raw_fences = fences.iter().map(|fence| fence.raw()).collect::<Vec<_>>();
+        let success = unsafe {
+            SDL_WaitForGPUFences(
+                self.raw(),
+                wait_all,
+                raw_fences.as_ptr(),
+                raw_fences.len() as u32,
+            )
+        };
+        if success {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Blocks until the GPU has finished all submitted work, e.g. before releasing resources
+    /// on shutdown.
+    #[doc(alias = "SDL_WaitForGPUIdle")]
+    pub fn wait_for_idle(&self) -> Result<(), Error> {
+        if unsafe { SDL_WaitForGPUIdle(self.raw()) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Blocks until a swapchain texture is available for `w`, so the next
+    /// [`CommandBuffer::acquire_swapchain_texture`] will not come back empty because too many
+    /// frames are in flight.
+    #[doc(alias = "SDL_WaitForGPUSwapchain")]
+    pub fn wait_for_swapchain(&self, w: &crate::video::Window) -> Result<(), Error> {
+        if unsafe { SDL_WaitForGPUSwapchain(self.raw(), w.raw()) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Returns true if the fence has been signaled, without blocking.
+    #[doc(alias = "SDL_QueryGPUFence")]
+    pub fn query_fence(&self, fence: &Fence) -> bool {
+        unsafe { SDL_QueryGPUFence(self.raw(), fence.raw()) }
+    }
+
+    /// Creates a [`FrameContext`] with `frames` slots, calling `init` once per slot to create
+    /// its resources.
+    pub fn create_frame_context<T>(
+        &self,
+        frames: usize,
+        init: impl FnMut(usize) -> Result<T, Error>,
+    ) -> Result<FrameContext<T>, Error> {
+        let resources = (0..frames).map(init).collect::<Result<Vec<_>, _>>()?;
+        Ok(FrameContext::new(self, resources))
+    }
+
     pub fn create_shader(&self) -> ShaderBuilder {
         ShaderBuilder::new(self)
     }
@@ -91,6 +195,22 @@ impl Device {
         BufferBuilder::new(self)
     }
 
+    /// Creates a buffer of `size` bytes and wraps it in a [`BufferArena`] whose allocations
+    /// start at multiples of `alignment` bytes.
+    pub fn create_buffer_arena(
+        &self,
+        usage: BufferUsageFlags,
+        size: u32,
+        alignment: u32,
+    ) -> Result<BufferArena, Error> {
+        let buffer = self
+            .create_buffer()
+            .with_usage(usage)
+            .with_size(size)
+            .build()?;
+        Ok(BufferArena::new(buffer, alignment))
+    }
+
     #[doc(alias = "SDL_CreateGPUTransferBuffer")]
     pub fn create_transfer_buffer(&self) -> TransferBufferBuilder {
         TransferBufferBuilder::new(self)
@@ -120,12 +240,21 @@ impl Device {
                 raw_texture,
                 create_info.inner.width,
                 create_info.inner.height,
+                create_info.inner.format,
             ))
         }
     }
 
+    /// Starts building a [`TextureAtlas`](crate::gpu::TextureAtlas) no larger than `max_width` x `max_height` texels.
+    pub fn create_texture_atlas(&self, max_width: u32, max_height: u32) -> TextureAtlasBuilder<'_> {
+        TextureAtlasBuilder::new(self, max_width, max_height)
+    }
+
     #[doc(alias = "SDL_SetGPUViewport")]
     pub fn set_viewport(&self, render_pass: &RenderPass, viewport: SDL_GPUViewport) {
+        render_pass
+            .validation
+            .check_recording("Device::set_viewport");
         unsafe { SDL_SetGPUViewport(render_pass.inner, &viewport) }
     }
 
@@ -141,6 +270,9 @@ impl Device {
         color_info: &[ColorTargetInfo],
         depth_stencil_target: Option<&DepthStencilTargetInfo>,
     ) -> Result<RenderPass, Error> {
+        command_buffer
+            .validation
+            .check_recording("Device::begin_render_pass");
         let p = unsafe {
             SDL_BeginGPURenderPass(
                 command_buffer.inner,
@@ -154,7 +286,11 @@ impl Device {
             )
         };
         if !p.is_null() {
-            Ok(RenderPass { inner: p })
+            Ok(RenderPass {
+                inner: p,
+                validation: PassState::new(&command_buffer.validation),
+                span: trace::pass("render"),
+            })
         } else {
             Err(get_error())
         }
@@ -162,6 +298,8 @@ impl Device {
 
     #[doc(alias = "SDL_EndGPURenderPass")]
     pub fn end_render_pass(&self, pass: RenderPass) {
+        pass.validation.check_recording("Device::end_render_pass");
+        let _span = pass.span;
         unsafe {
             sys::gpu::SDL_EndGPURenderPass(pass.inner);
         }
@@ -169,15 +307,24 @@ impl Device {
 
     #[doc(alias = "SDL_BeginGPUCopyPass")]
     pub fn begin_copy_pass(&self, command_buffer: &CommandBuffer) -> Result<CopyPass, Error> {
+        command_buffer
+            .validation
+            .check_recording("Device::begin_copy_pass");
         let p = unsafe { SDL_BeginGPUCopyPass(command_buffer.inner) };
         if !p.is_null() {
-            Ok(CopyPass { inner: p })
+            Ok(CopyPass {
+                inner: p,
+                validation: PassState::new(&command_buffer.validation),
+                span: trace::pass("copy"),
+            })
         } else {
             Err(get_error())
         }
     }
     #[doc(alias = "SDL_EndGPUCopyPass")]
     pub fn end_copy_pass(&self, pass: CopyPass) {
+        pass.validation.check_recording("Device::end_copy_pass");
+        let _span = pass.span;
         unsafe {
             sys::gpu::SDL_EndGPUCopyPass(pass.inner);
         }
@@ -190,6 +337,9 @@ impl Device {
         storage_texture_bindings: &[StorageTextureReadWriteBinding],
         storage_buffer_bindings: &[StorageBufferReadWriteBinding],
     ) -> Result<ComputePass, Error> {
+        command_buffer
+            .validation
+            .check_recording("Device::begin_compute_pass");
         let p = unsafe {
             SDL_BeginGPUComputePass(
                 command_buffer.inner,
@@ -200,13 +350,19 @@ impl Device {
             )
         };
         if !p.is_null() {
-            Ok(ComputePass { inner: p })
+            Ok(ComputePass {
+                inner: p,
+                validation: PassState::new(&command_buffer.validation),
+                span: trace::pass("compute"),
+            })
         } else {
             Err(get_error())
         }
     }
     #[doc(alias = "SDL_EndGPUComputePass")]
     pub fn end_compute_pass(&self, pass: ComputePass) {
+        pass.validation.check_recording("Device::end_compute_pass");
+        let _span = pass.span;
         unsafe {
             sys::gpu::SDL_EndGPUComputePass(pass.inner);
         }
@@ -241,3 +397,53 @@ impl Device {
         }
     }
 }
+
+#[derive(Copy, Clone)]
+#[doc(alias = "SDL_GetGPUDriver")]
+pub struct DriverIterator {
+    length: i32,
+    index: i32,
+}
+
+impl Iterator for DriverIterator {
+    type Item = String;
+
+    #[inline]
+    #[doc(alias = "SDL_GetGPUDriver")]
+    fn next(&mut self) -> Option<String> {
+        if self.index >= self.length {
+            None
+        } else {
+            let result = unsafe { SDL_GetGPUDriver(self.index) };
+            self.index += 1;
+
+            Some(
+                unsafe { CStr::from_ptr(result) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let l = (self.length - self.index) as usize;
+        (l, Some(l))
+    }
+}
+
+impl ExactSizeIterator for DriverIterator {}
+
+/// Gets an iterator of all GPU drivers compiled into the SDL library, e.g. `"vulkan"`,
+/// `"direct3d12"` or `"metal"`.
+///
+/// A driver being listed doesn't mean it works on this machine; use [`Device::new_with_driver`]
+/// to find out.
+#[inline]
+#[doc(alias = "SDL_GetNumGPUDrivers")]
+pub fn drivers() -> DriverIterator {
+    DriverIterator {
+        length: unsafe { SDL_GetNumGPUDrivers() },
+        index: 0,
+    }
+}
@@ -0,0 +1,30 @@
+use crate::gpu::WeakDevice;
+use sys::gpu::{SDL_GPUFence, SDL_ReleaseGPUFence};
+
+/// Signaled once the GPU has finished executing a submitted command buffer.
+///
+/// Obtained from [`CommandBuffer::submit_and_acquire_fence`](crate::gpu::CommandBuffer::submit_and_acquire_fence)
+/// and released on drop.
+#[doc(alias = "SDL_GPUFence")]
+pub struct Fence {
+    raw: *mut SDL_GPUFence,
+    device: WeakDevice,
+}
+impl Fence {
+    pub(super) fn new(device: WeakDevice, raw: *mut SDL_GPUFence) -> Self {
+        Self { raw, device }
+    }
+
+    #[inline]
+    pub fn raw(&self) -> *mut SDL_GPUFence {
+        self.raw
+    }
+}
+impl Drop for Fence {
+    #[doc(alias = "SDL_ReleaseGPUFence")]
+    fn drop(&mut self) {
+        if let Some(device) = self.device.upgrade() {
+            unsafe { SDL_ReleaseGPUFence(device.raw(), self.raw) }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    gpu::{CommandBuffer, Device, Fence},
+    Error,
+};
+
+/// Rotates between several sets of per-frame resources so the CPU can record a frame while
+/// the GPU is still working on previous ones.
+///
+/// Each slot holds user data `T` (transfer buffers, uniform staging, ...) and the fence of the
+/// last command buffer submitted with it. [`begin_frame`](Self::begin_frame) waits for that
+/// fence before handing the slot out again, so its resources are never overwritten while in use.
+/// Created with [`Device::create_frame_context`].
+pub struct FrameContext<T> {
+    device: Device,
+    frames: Vec<FrameSlot<T>>,
+    current: usize,
+}
+
+struct FrameSlot<T> {
+    fence: Option<Fence>,
+    resources: T,
+}
+
+impl<T> FrameContext<T> {
+    pub(super) fn new(device: &Device, frames: Vec<T>) -> Self {
+        assert!(
+            !frames.is_empty(),
+            "a frame context needs at least one frame"
+        );
+        Self {
+            device: device.clone(),
+            frames: frames
+                .into_iter()
+                .map(|resources| FrameSlot {
+                    fence: None,
+                    resources,
+                })
+                .collect(),
+            current: 0,
+        }
+    }
+
+    /// The number of frames that can be in flight at once.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// The index of the slot used by the current frame.
+    pub fn frame_index(&self) -> usize {
+        self.current
+    }
+
+    /// Waits until the GPU is done with the current slot, then acquires a command buffer for it.
+    ///
+    /// Record the frame into the command buffer using the returned resources, then hand it to
+    /// [`end_frame`](Self::end_frame).
+    pub fn begin_frame(&mut self) -> Result<(CommandBuffer, &mut T), Error> {
+        let slot = &mut self.frames[self.current];
+        // keep the fence until the wait succeeds, so a failed wait is retried next time
+        if let Some(fence) = &slot.fence {
+            self.device.wait_for_fences(true, &[fence])?;
+            slot.fence = None;
+        }
+        let command_buffer = self.device.acquire_command_buffer()?;
+        Ok((command_buffer, &mut slot.resources))
+    }
+
+    /// Submits the frame's command buffer and moves on to the next slot.
+    pub fn end_frame(&mut self, command_buffer: CommandBuffer) -> Result<(), Error> {
+        let fence = command_buffer.submit_and_acquire_fence()?;
+        self.frames[self.current].fence = Some(fence);
+        self.current = (self.current + 1) % self.frames.len();
+        Ok(())
+    }
+
+    /// Blocks until every submitted frame has finished, e.g. before releasing resources.
+    pub fn wait_idle(&mut self) -> Result<(), Error> {
+        let fences: Vec<&Fence> = self
+            .frames
+            .iter()
+            .filter_map(|slot| slot.fence.as_ref())
+            .collect();
+        if fences.is_empty() {
+            return Ok(());
+        }
+        self.device.wait_for_fences(true, &fences)?;
+        for slot in &mut self.frames {
+            slot.fence = None;
+        }
+        Ok(())
+    }
+
+    /// The resources of every slot, e.g. to resize them all at once after calling
+    /// [`wait_idle`](Self::wait_idle).
+    pub fn resources_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.frames.iter_mut().map(|slot| &mut slot.resources)
+    }
+}
@@ -1,13 +1,24 @@
 use crate::gpu::device::WeakDevice;
 
+mod arena;
+pub use arena::{BufferAllocation, BufferArena};
+
+mod atlas;
+pub use atlas::{TextureAtlas, TextureAtlasBuilder};
+
 mod buffer;
 pub use buffer::{
-    Buffer, BufferBinding, BufferBuilder, BufferMemMap, BufferRegion, TransferBuffer,
-    TransferBufferBuilder, TransferBufferLocation, VertexBufferDescription,
+    Buffer, BufferBinding, BufferBuilder, BufferMemMap, BufferReadMap, BufferRegion,
+    TransferBuffer, TransferBufferBuilder, TransferBufferLocation, VertexBufferDescription,
 };
 
 mod device;
-pub use device::Device;
+pub use device::{drivers, Device, DriverIterator};
+
+mod fence;
+pub use fence::Fence;
+
+pub mod testing;
 
 mod enums;
 pub use enums::{
@@ -17,6 +28,9 @@ pub use enums::{
     TextureUsage, TransferBufferUsage, VertexElementFormat, VertexInputRate,
 };
 
+mod frame;
+pub use frame::FrameContext;
+
 mod pass;
 pub use pass::{
     ColorTargetInfo, CommandBuffer, ComputePass, CopyPass, DepthStencilTargetInfo, RenderPass,
@@ -31,9 +45,16 @@ pub use pipeline::{
 
 mod texture;
 pub use texture::{
-    Sampler, SamplerCreateInfo, Texture, TextureCreateInfo, TextureRegion, TextureSamplerBinding,
-    TextureTransferInfo,
+    Sampler, SamplerCreateInfo, Texture, TextureCreateInfo, TextureLocation, TextureRegion,
+    TextureSamplerBinding, TextureTransferInfo,
 };
 
 mod shader;
 pub use shader::{Shader, ShaderBuilder};
+
+mod trace;
+
+mod validation;
+
+mod vertex;
+pub use vertex::VertexElement;
@@ -1,30 +1,43 @@
 use crate::{
     get_error,
     gpu::{
-        BufferBinding, BufferRegion, GraphicsPipeline, IndexElementSize, LoadOp, StoreOp, Texture,
-        TextureRegion, TextureSamplerBinding, TextureTransferInfo, TransferBufferLocation,
+        device::WeakDevice, BufferBinding, BufferRegion, GraphicsPipeline, IndexElementSize,
+        LoadOp, StoreOp, Texture, TextureLocation, TextureRegion, TextureSamplerBinding,
+        TextureTransferInfo, TransferBufferLocation,
     },
     pixels::Color,
     Error,
 };
 use sys::gpu::{
     SDL_AcquireGPUSwapchainTexture, SDL_BindGPUFragmentSamplers, SDL_BindGPUIndexBuffer,
-    SDL_BindGPUVertexBuffers, SDL_DrawGPUIndexedPrimitives, SDL_GPUBufferBinding,
+    SDL_BindGPUVertexBuffers, SDL_CopyGPUTextureToTexture, SDL_DownloadFromGPUBuffer,
+    SDL_DownloadFromGPUTexture, SDL_DrawGPUIndexedPrimitives, SDL_GPUBufferBinding,
     SDL_GPUColorTargetInfo, SDL_GPUCommandBuffer, SDL_GPUComputePass, SDL_GPUCopyPass,
     SDL_GPUDepthStencilTargetInfo, SDL_GPUIndexElementSize, SDL_GPULoadOp, SDL_GPURenderPass,
     SDL_GPUStoreOp, SDL_GPUTextureSamplerBinding, SDL_PushGPUComputeUniformData,
-    SDL_PushGPUFragmentUniformData, SDL_PushGPUVertexUniformData, SDL_UploadToGPUBuffer,
-    SDL_UploadToGPUTexture, SDL_WaitAndAcquireGPUSwapchainTexture,
+    SDL_PushGPUFragmentUniformData, SDL_PushGPUVertexUniformData,
+    SDL_SubmitGPUCommandBufferAndAcquireFence, SDL_UploadToGPUBuffer, SDL_UploadToGPUTexture,
+    SDL_WaitAndAcquireGPUSwapchainTexture,
 };
 
-use super::{Buffer, ComputePipeline};
+use super::{
+    trace::{self, PassSpan},
+    validation::{self, CommandBufferState, PassState},
+    Buffer, ComputePipeline, Fence,
+};
 
 pub struct CommandBuffer {
     pub(super) inner: *mut SDL_GPUCommandBuffer,
+    device: WeakDevice,
+    pub(super) validation: CommandBufferState,
 }
 impl CommandBuffer {
-    pub(super) fn new(inner: *mut SDL_GPUCommandBuffer) -> Self {
-        Self { inner }
+    pub(super) fn new(device: WeakDevice, inner: *mut SDL_GPUCommandBuffer) -> Self {
+        Self {
+            inner,
+            device,
+            validation: CommandBufferState::new(),
+        }
     }
 
     #[inline]
@@ -32,6 +45,9 @@ impl CommandBuffer {
         self.inner
     }
 
+    /// Copies `data` into the uniform slot as raw bytes, so its layout must match the shader's
+    /// uniform block. Math types such as `glam::Mat4` or `mint::ColumnMatrix4<f32>` can be pushed
+    /// as they are.
     #[doc(alias = "SDL_PushGPUVertexUniformData")]
     pub fn push_vertex_uniform_data<T: Sized>(&self, slot_index: u32, data: &T) {
         unsafe {
@@ -44,6 +60,7 @@ impl CommandBuffer {
         }
     }
 
+    /// Like [`push_vertex_uniform_data`](Self::push_vertex_uniform_data), for the fragment stage.
     #[doc(alias = "SDL_PushGPUFragmentUniformData")]
     pub fn push_fragment_uniform_data<T: Sized>(&self, slot_index: u32, data: &T) {
         unsafe {
@@ -56,6 +73,7 @@ impl CommandBuffer {
         }
     }
 
+    /// Like [`push_vertex_uniform_data`](Self::push_vertex_uniform_data), for the compute stage.
     #[doc(alias = "SDL_PushGPUComputeUniformData")]
     pub fn push_compute_uniform_data<T: Sized>(&self, slot_index: u32, data: &T) {
         unsafe {
@@ -68,11 +86,17 @@ impl CommandBuffer {
         }
     }
 
+    /// Waits until a swapchain texture is available, then acquires it.
+    ///
+    /// Returns `Ok(None)` when there is no texture to render to this frame, e.g. while the
+    /// window is minimized; the command buffer should then be submitted or cancelled without
+    /// drawing to the window.
     #[doc(alias = "SDL_WaitAndAcquireGPUSwapchainTexture")]
     pub fn wait_and_acquire_swapchain_texture<'a>(
         &'a mut self,
         w: &crate::video::Window,
-    ) -> Result<Texture<'a>, Error> {
+    ) -> Result<Option<Texture<'a>>, Error> {
+        let _span = trace::scope("wait_and_acquire_swapchain_texture");
         let mut swapchain = std::ptr::null_mut();
         let mut width = 0;
         let mut height = 0;
@@ -85,18 +109,25 @@ impl CommandBuffer {
                 &mut height,
             )
         };
-        if success {
-            Ok(Texture::new_sdl_managed(swapchain, width, height))
-        } else {
+        if !success {
             Err(get_error())
+        } else if swapchain.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Texture::new_sdl_managed(swapchain, width, height)))
         }
     }
 
+    /// Acquires a swapchain texture without waiting.
+    ///
+    /// Returns `Ok(None)` when no texture is available yet, e.g. because too many frames are
+    /// in flight or the window is minimized.
     #[doc(alias = "SDL_AcquireGPUSwapchainTexture")]
     pub fn acquire_swapchain_texture<'a>(
         &'a mut self,
         w: &crate::video::Window,
-    ) -> Result<Texture<'a>, Error> {
+    ) -> Result<Option<Texture<'a>>, Error> {
+        let _span = trace::scope("acquire_swapchain_texture");
         let mut swapchain = std::ptr::null_mut();
         let mut width = 0;
         let mut height = 0;
@@ -109,15 +140,19 @@ impl CommandBuffer {
                 &mut height,
             )
         };
-        if success {
-            Ok(Texture::new_sdl_managed(swapchain, width, height))
-        } else {
+        if !success {
             Err(get_error())
+        } else if swapchain.is_null() {
+            Ok(None)
+        } else {
+            Ok(Some(Texture::new_sdl_managed(swapchain, width, height)))
         }
     }
 
     #[doc(alias = "SDL_SubmitGPUCommandBuffer")]
     pub fn submit(self) -> Result<(), Error> {
+        let _span = trace::scope("submit");
+        self.validation.mark_submitted();
         if unsafe { sys::gpu::SDL_SubmitGPUCommandBuffer(self.inner) } {
             Ok(())
         } else {
@@ -125,8 +160,23 @@ impl CommandBuffer {
         }
     }
 
+    /// Submits the command buffer and returns a [`Fence`] that is signaled once the GPU is done with it.
+    #[doc(alias = "SDL_SubmitGPUCommandBufferAndAcquireFence")]
+    pub fn submit_and_acquire_fence(self) -> Result<Fence, Error> {
+        let _span = trace::scope("submit_and_acquire_fence");
+        self.validation.mark_submitted();
+        let raw_fence = unsafe { SDL_SubmitGPUCommandBufferAndAcquireFence(self.inner) };
+        if raw_fence.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Fence::new(self.device, raw_fence))
+        }
+    }
+
     #[doc(alias = "SDL_CancelGPUCommandBuffer")]
     pub fn cancel(&mut self) {
+        let _span = trace::scope("cancel");
+        self.validation.mark_submitted();
         unsafe {
             sys::gpu::SDL_CancelGPUCommandBuffer(self.inner);
         }
@@ -217,6 +267,8 @@ impl ColorTargetInfo {
 
 pub struct RenderPass {
     pub(super) inner: *mut SDL_GPURenderPass,
+    pub(super) validation: PassState,
+    pub(super) span: PassSpan,
 }
 impl RenderPass {
     #[inline]
@@ -226,11 +278,19 @@ impl RenderPass {
 
     #[doc(alias = "SDL_BindGPUGraphicsPipeline")]
     pub fn bind_graphics_pipeline(&self, pipeline: &GraphicsPipeline) {
+        self.validation
+            .check_recording("RenderPass::bind_graphics_pipeline");
+        self.validation.pipeline_bound();
         unsafe { sys::gpu::SDL_BindGPUGraphicsPipeline(self.inner, pipeline.raw()) }
     }
 
     #[doc(alias = "SDL_BindGPUVertexBuffer")]
     pub fn bind_vertex_buffers(&self, first_slot: u32, bindings: &[BufferBinding]) {
+        self.validation
+            .check_recording("RenderPass::bind_vertex_buffers");
+        for binding in bindings {
+            validation::check_resource(binding.inner.buffer, "vertex buffer");
+        }
         unsafe {
             SDL_BindGPUVertexBuffers(
                 self.raw(),
@@ -243,6 +303,9 @@ impl RenderPass {
 
     #[doc(alias = "SDL_BindGPUIndexBuffer")]
     pub fn bind_index_buffer(&self, binding: &BufferBinding, index_element_size: IndexElementSize) {
+        self.validation
+            .check_recording("RenderPass::bind_index_buffer");
+        validation::check_resource(binding.inner.buffer, "index buffer");
         unsafe {
             SDL_BindGPUIndexBuffer(
                 self.raw(),
@@ -254,6 +317,12 @@ impl RenderPass {
 
     #[doc(alias = "SDL_BindGPUFragmentSamplers")]
     pub fn bind_fragment_sampler(&self, first_slot: u32, bindings: &[TextureSamplerBinding]) {
+        self.validation
+            .check_recording("RenderPass::bind_fragment_sampler");
+        for binding in bindings {
+            validation::check_resource(binding.inner.texture, "texture");
+            validation::check_resource(binding.inner.sampler, "sampler");
+        }
         unsafe {
             SDL_BindGPUFragmentSamplers(
                 self.raw(),
@@ -273,6 +342,8 @@ impl RenderPass {
         vertex_offset: i32,
         first_instance: u32,
     ) {
+        self.validation
+            .check_pipeline_bound("RenderPass::draw_indexed_primitives");
         unsafe {
             SDL_DrawGPUIndexedPrimitives(
                 self.raw(),
@@ -293,6 +364,8 @@ impl RenderPass {
         first_vertex: usize,
         first_instance: usize,
     ) {
+        self.validation
+            .check_pipeline_bound("RenderPass::draw_primitives");
         unsafe {
             sys::gpu::SDL_DrawGPUPrimitives(
                 self.inner,
@@ -307,6 +380,8 @@ impl RenderPass {
 
 pub struct CopyPass {
     pub(super) inner: *mut SDL_GPUCopyPass,
+    pub(super) validation: PassState,
+    pub(super) span: PassSpan,
 }
 impl CopyPass {
     #[inline]
@@ -321,6 +396,14 @@ impl CopyPass {
         buffer_region: BufferRegion,
         cycle: bool,
     ) {
+        self.validation
+            .check_recording("CopyPass::upload_to_gpu_buffer");
+        let _span = trace::upload("upload_to_gpu_buffer", buffer_region.inner.size as u64);
+        validation::check_resource(
+            transfer_buf_location.inner.transfer_buffer,
+            "transfer buffer",
+        );
+        validation::check_resource(buffer_region.inner.buffer, "buffer");
         unsafe {
             SDL_UploadToGPUBuffer(
                 self.raw(),
@@ -338,12 +421,84 @@ impl CopyPass {
         destination: TextureRegion,
         cycle: bool,
     ) {
+        self.validation
+            .check_recording("CopyPass::upload_to_gpu_texture");
+        let _span = trace::upload(
+            "upload_to_gpu_texture",
+            destination.inner.w as u64 * destination.inner.h as u64 * destination.inner.d as u64,
+        );
+        validation::check_resource(source.inner.transfer_buffer, "transfer buffer");
+        validation::check_resource(destination.inner.texture, "texture");
         unsafe { SDL_UploadToGPUTexture(self.raw(), &source.inner, &destination.inner, cycle) }
     }
+
+    /// Copies data from a texture region into a transfer buffer.
+    ///
+    /// The data is only available once the command buffer has completed, see [`Fence`].
+    #[doc(alias = "SDL_DownloadFromGPUTexture")]
+    pub fn download_from_gpu_texture(
+        &self,
+        source: TextureRegion,
+        destination: TextureTransferInfo,
+    ) {
+        self.validation
+            .check_recording("CopyPass::download_from_gpu_texture");
+        validation::check_resource(source.inner.texture, "texture");
+        validation::check_resource(destination.inner.transfer_buffer, "transfer buffer");
+        unsafe { SDL_DownloadFromGPUTexture(self.raw(), &source.inner, &destination.inner) }
+    }
+
+    /// Copies data from a buffer region into a transfer buffer.
+    ///
+    /// The data is only available once the command buffer has completed, see [`Fence`].
+    #[doc(alias = "SDL_DownloadFromGPUBuffer")]
+    pub fn download_from_gpu_buffer(
+        &self,
+        source: BufferRegion,
+        destination: TransferBufferLocation,
+    ) {
+        self.validation
+            .check_recording("CopyPass::download_from_gpu_buffer");
+        validation::check_resource(source.inner.buffer, "buffer");
+        validation::check_resource(destination.inner.transfer_buffer, "transfer buffer");
+        unsafe { SDL_DownloadFromGPUBuffer(self.raw(), &source.inner, &destination.inner) }
+    }
+
+    /// Copies a `width` x `height` x `depth` block of texels from one texture to another.
+    ///
+    /// Both textures must have the same format.
+    #[doc(alias = "SDL_CopyGPUTextureToTexture")]
+    pub fn copy_texture_to_texture(
+        &self,
+        source: TextureLocation,
+        destination: TextureLocation,
+        width: u32,
+        height: u32,
+        depth: u32,
+        cycle: bool,
+    ) {
+        self.validation
+            .check_recording("CopyPass::copy_texture_to_texture");
+        validation::check_resource(source.inner.texture, "texture");
+        validation::check_resource(destination.inner.texture, "texture");
+        unsafe {
+            SDL_CopyGPUTextureToTexture(
+                self.raw(),
+                &source.inner,
+                &destination.inner,
+                width,
+                height,
+                depth,
+                cycle,
+            )
+        }
+    }
 }
 
 pub struct ComputePass {
     pub(super) inner: *mut SDL_GPUComputePass,
+    pub(super) validation: PassState,
+    pub(super) span: PassSpan,
 }
 impl ComputePass {
     #[inline]
@@ -353,11 +508,16 @@ impl ComputePass {
 
     #[doc(alias = "SDL_BindGPUComputePipeline")]
     pub fn bind_compute_pipeline(&self, pipeline: &ComputePipeline) {
+        self.validation
+            .check_recording("ComputePass::bind_compute_pipeline");
+        self.validation.pipeline_bound();
         unsafe { sys::gpu::SDL_BindGPUComputePipeline(self.inner, pipeline.raw()) }
     }
 
     #[doc(alias = "SDL_BindGPUComputeStorageBuffers")]
     pub fn bind_compute_storage_buffers(&self, first_slot: u32, storage_buffers: &[Buffer]) {
+        self.validation
+            .check_recording("ComputePass::bind_compute_storage_buffers");
         let buffer_handles = storage_buffers.iter().map(|x| x.raw()).collect::<Vec<_>>();
         unsafe {
             sys::gpu::SDL_BindGPUComputeStorageBuffers(
@@ -371,6 +531,8 @@ impl ComputePass {
 
     #[doc(alias = "SDL_BindGPUComputeStorageTextures")]
     pub fn bind_compute_storage_textures(&self, first_slot: u32, storage_textures: &[Texture]) {
+        self.validation
+            .check_recording("ComputePass::bind_compute_storage_textures");
         let texture_handles = storage_textures.iter().map(|x| x.raw()).collect::<Vec<_>>();
         unsafe {
             sys::gpu::SDL_BindGPUComputeStorageTextures(
@@ -384,6 +546,8 @@ impl ComputePass {
 
     #[doc(alias = "SDL_DispatchGPUCompute")]
     pub fn dispatch(&self, groupcount_x: u32, groupcount_y: u32, groupcount_z: u32) {
+        self.validation
+            .check_pipeline_bound("ComputePass::dispatch");
         unsafe {
             sys::gpu::SDL_DispatchGPUCompute(self.inner, groupcount_x, groupcount_y, groupcount_z)
         }
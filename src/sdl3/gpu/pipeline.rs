@@ -3,7 +3,7 @@ use crate::{
     gpu::{
         device::WeakDevice, BlendFactor, BlendOp, ColorComponentFlags, CompareOp, CullMode, Device,
         FillMode, FrontFace, PrimitiveType, Shader, StencilOp, TextureFormat,
-        VertexBufferDescription, VertexElementFormat,
+        VertexBufferDescription, VertexElement, VertexElementFormat,
     },
     sys, Error,
 };
@@ -178,6 +178,11 @@ impl VertexAttribute {
         self
     }
 
+    /// Sets the format from the Rust type of the attribute, see [`VertexElement`].
+    pub fn with_format_of<T: VertexElement>(self) -> Self {
+        self.with_format(T::FORMAT)
+    }
+
     /// The byte offset of this attribute relative to the start of the vertex element.
     pub fn with_offset(mut self, value: u32) -> Self {
         self.inner.offset = value;
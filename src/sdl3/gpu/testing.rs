@@ -0,0 +1,316 @@
+//! Helpers for regression-testing GPU rendering code against reference images.
+//!
+//! Nothing here needs a window: an [`OffscreenTarget`] is rendered into, read back and compared
+//! with a BMP reference image, so tests can run on headless machines as long as a GPU driver
+//! (or a software implementation such as lavapipe) is available.
+//!
+//! ```no_run
+//! use sdl3::gpu::{testing::OffscreenTarget, ColorTargetInfo, Device, LoadOp, ShaderFormat};
+//! use sdl3::pixels::Color;
+//!
+//! let device = Device::new(ShaderFormat::SpirV, true).unwrap();
+//! let target = OffscreenTarget::new(&device, 64, 64).unwrap();
+//! let image = target
+//!     .render_to_surface(&device, |command_buffer, texture| {
+//!         let color_targets = [ColorTargetInfo::default()
+//!             .with_texture(texture)
+//!             .with_load_op(LoadOp::Clear)
+//!             .with_clear_color(Color::RGB(255, 0, 0))];
+//!         let pass = device.begin_render_pass(command_buffer, &color_targets, None)?;
+//!         device.end_render_pass(pass);
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! sdl3::gpu::testing::assert_matches_reference(&image, "tests/reference/red.bmp", 0);
+//! ```
+
+use crate::{
+    gpu::{
+        CommandBuffer, Device, Texture, TextureCreateInfo, TextureFormat, TextureRegion,
+        TextureTransferInfo, TextureType, TextureUsage, TransferBuffer, TransferBufferUsage,
+    },
+    pixels::PixelFormat,
+    surface::Surface,
+    Error,
+};
+use std::path::{Path, PathBuf};
+use sys::pixels::SDL_PixelFormat;
+
+/// When this environment variable is set, [`assert_matches_reference`] overwrites the reference
+/// images with the rendered output instead of comparing against them.
+pub const UPDATE_REFERENCE_ENV: &str = "SDL3_UPDATE_REFERENCE_IMAGES";
+
+/// A color texture that can be rendered into and read back without a swapchain.
+///
+/// The texture is always [`OffscreenTarget::FORMAT`], so pipelines drawing into it must use that
+/// format for their color target.
+pub struct OffscreenTarget {
+    texture: Texture<'static>,
+    download: TransferBuffer,
+}
+impl OffscreenTarget {
+    /// The format of every offscreen target.
+    pub const FORMAT: TextureFormat = TextureFormat::R8g8b8a8Unorm;
+
+    /// Creates a `width` x `height` target and the buffer its contents are read back through.
+    ///
+    /// Errors if the contents of such a target don't fit in a transfer buffer.
+    pub fn new(device: &Device, width: u32, height: u32) -> Result<Self, Error> {
+        let download_size = rgba_len(width, height)?;
+        let texture = device.create_texture(
+            TextureCreateInfo::new()
+                .with_type(TextureType::_2D)
+                .with_format(Self::FORMAT)
+                .with_usage(TextureUsage::ColorTarget)
+                .with_width(width)
+                .with_height(height)
+                .with_layer_count_or_depth(1)
+                .with_num_levels(1),
+        )?;
+        let download = device
+            .create_transfer_buffer()
+            .with_usage(TransferBufferUsage::Download)
+            .with_size(download_size)
+            .build()?;
+        Ok(Self { texture, download })
+    }
+
+    /// The texture to use as a color target.
+    pub fn texture(&self) -> &Texture<'static> {
+        &self.texture
+    }
+
+    /// The width of the target in pixels.
+    pub fn width(&self) -> u32 {
+        self.texture.width()
+    }
+
+    /// The height of the target in pixels.
+    pub fn height(&self) -> u32 {
+        self.texture.height()
+    }
+
+    /// Records rendering commands with `draw`, then waits for the GPU and returns the contents of
+    /// the target as tightly packed RGBA bytes.
+    pub fn render<F>(&self, device: &Device, draw: F) -> Result<Vec<u8>, Error>
+    where
+        F: FnOnce(&mut CommandBuffer, &Texture<'static>) -> Result<(), Error>,
+    {
+        let mut command_buffer = device.acquire_command_buffer()?;
+        if let Err(error) = draw(&mut command_buffer, &self.texture) {
+            command_buffer.cancel();
+            return Err(error);
+        }
+
+        let copy_pass = match device.begin_copy_pass(&command_buffer) {
+            Ok(copy_pass) => copy_pass,
+            Err(error) => {
+                command_buffer.cancel();
+                return Err(error);
+            }
+        };
+        copy_pass.download_from_gpu_texture(
+            TextureRegion::new()
+                .with_texture(&self.texture)
+                .with_width(self.width())
+                .with_height(self.height())
+                .with_depth(1),
+            TextureTransferInfo::new().with_transfer_buffer(&self.download),
+        );
+        device.end_copy_pass(copy_pass);
+
+        let fence = command_buffer.submit_and_acquire_fence()?;
+        let map = self.download.map_read(device, &fence)?;
+        Ok(map.bytes().to_vec())
+    }
+
+    /// Like [`OffscreenTarget::render`], but returns the contents as an RGBA32 [`Surface`].
+    pub fn render_to_surface<F>(&self, device: &Device, draw: F) -> Result<Surface<'static>, Error>
+    where
+        F: FnOnce(&mut CommandBuffer, &Texture<'static>) -> Result<(), Error>,
+    {
+        let pixels = self.render(device, draw)?;
+        let mut surface = Surface::new(self.width(), self.height(), rgba32())?;
+        let row_len = self.width() as usize * 4;
+        let pitch = surface.pitch() as usize;
+        surface.with_lock_mut(|dst| {
+            for (src_row, dst_row) in pixels.chunks_exact(row_len).zip(dst.chunks_mut(pitch)) {
+                dst_row[..row_len].copy_from_slice(src_row);
+            }
+        });
+        Ok(surface)
+    }
+}
+
+/// The result of comparing two images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageDiff {
+    /// Number of pixels where at least one channel differs by more than the tolerance.
+    pub mismatched_pixels: usize,
+    /// The largest difference seen in any channel of any pixel.
+    pub max_channel_difference: u8,
+}
+impl ImageDiff {
+    /// Whether every pixel is within the tolerance.
+    pub fn is_match(&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+}
+
+/// Compares two tightly packed RGBA images of the same size.
+///
+/// A pixel mismatches when any of its channels differs by more than `tolerance`.
+///
+/// # Panics
+///
+/// Panics if the two slices don't have the same length.
+pub fn compare_rgba(actual: &[u8], expected: &[u8], tolerance: u8) -> ImageDiff {
+    assert_eq!(
+        actual.len(),
+        expected.len(),
+        "images must have the same size"
+    );
+    let mut diff = ImageDiff {
+        mismatched_pixels: 0,
+        max_channel_difference: 0,
+    };
+    for (a, e) in actual.chunks(4).zip(expected.chunks(4)) {
+        let pixel_difference = a
+            .iter()
+            .zip(e)
+            .map(|(a, e)| a.abs_diff(*e))
+            .max()
+            .unwrap_or(0);
+        diff.max_channel_difference = diff.max_channel_difference.max(pixel_difference);
+        if pixel_difference > tolerance {
+            diff.mismatched_pixels += 1;
+        }
+    }
+    diff
+}
+
+/// Compares a surface against a BMP reference image.
+///
+/// Both images are converted to RGBA32 first, so any pixel format is accepted.
+pub fn compare_with_reference<P: AsRef<Path>>(
+    actual: &Surface,
+    reference: P,
+    tolerance: u8,
+) -> Result<ImageDiff, Error> {
+    let expected = Surface::load_bmp(reference)?;
+    if expected.size() != actual.size() {
+        return Err(Error(format!(
+            "reference image is {:?} but the rendered image is {:?}",
+            expected.size(),
+            actual.size()
+        )));
+    }
+    let actual = packed_rgba(actual)?;
+    let expected = packed_rgba(&expected)?;
+    Ok(compare_rgba(&actual, &expected, tolerance))
+}
+
+/// Panics unless `actual` matches the BMP at `reference` within `tolerance`.
+///
+/// On mismatch the rendered image is written next to the reference with an `.actual.bmp`
+/// extension. If [`UPDATE_REFERENCE_ENV`] is set, the reference is overwritten instead.
+pub fn assert_matches_reference<P: AsRef<Path>>(actual: &Surface, reference: P, tolerance: u8) {
+    let reference = reference.as_ref();
+    if std::env::var_os(UPDATE_REFERENCE_ENV).is_some() {
+        actual
+            .save_bmp(reference)
+            .unwrap_or_else(|e| panic!("failed to write {}: {}", reference.display(), e));
+        return;
+    }
+
+    let diff = compare_with_reference(actual, reference, tolerance)
+        .unwrap_or_else(|e| panic!("failed to compare with {}: {}", reference.display(), e));
+    if !diff.is_match() {
+        let actual_path = actual_path(reference);
+        let _ = actual.save_bmp(&actual_path);
+        panic!(
+            "rendered image differs from {}: {} pixels off by up to {} (tolerance {}), output written to {}",
+            reference.display(),
+            diff.mismatched_pixels,
+            diff.max_channel_difference,
+            tolerance,
+            actual_path.display()
+        );
+    }
+}
+
+/// The size in bytes of a tightly packed `width` x `height` RGBA image.
+fn rgba_len(width: u32, height: u32) -> Result<u32, Error> {
+    width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(4))
+        .ok_or_else(|| Error(format!("a {}x{} RGBA image is too large", width, height)))
+}
+
+fn actual_path(reference: &Path) -> PathBuf {
+    reference.with_extension("actual.bmp")
+}
+
+fn rgba32() -> PixelFormat {
+    unsafe { PixelFormat::from_ll(SDL_PixelFormat::RGBA32) }
+}
+
+/// Converts a surface to RGBA32 and strips any row padding.
+fn packed_rgba(surface: &Surface) -> Result<Vec<u8>, Error> {
+    let converted = surface.convert_format(rgba32())?;
+    let row_len = converted.width() as usize * 4;
+    let pitch = converted.pitch() as usize;
+    Ok(converted.with_lock(|pixels| {
+        pixels
+            .chunks(pitch)
+            .take(converted.height() as usize)
+            .flat_map(|row| &row[..row_len])
+            .copied()
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{actual_path, compare_rgba, rgba_len};
+    use std::path::Path;
+
+    #[test]
+    fn tolerance_is_per_channel() {
+        let actual = [10, 20, 30, 255, 0, 0, 0, 255];
+        let expected = [12, 20, 30, 255, 0, 0, 9, 255];
+        let diff = compare_rgba(&actual, &expected, 2);
+        assert_eq!(diff.mismatched_pixels, 1);
+        assert_eq!(diff.max_channel_difference, 9);
+        assert!(compare_rgba(&actual, &expected, 9).is_match());
+    }
+
+    #[test]
+    fn identical_images_match() {
+        let image = [1, 2, 3, 4, 5, 6, 7, 8];
+        let diff = compare_rgba(&image, &image, 0);
+        assert!(diff.is_match());
+        assert_eq!(diff.max_channel_difference, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "same size")]
+    fn different_sizes_panic() {
+        compare_rgba(&[0; 8], &[0; 4], 0);
+    }
+
+    #[test]
+    fn rgba_len_rejects_overflow() {
+        assert_eq!(rgba_len(64, 32).unwrap(), 64 * 32 * 4);
+        assert!(rgba_len(65536, 65536).is_err());
+        assert!(rgba_len(32768, 32768).is_err());
+    }
+
+    #[test]
+    fn actual_image_is_written_next_to_reference() {
+        assert_eq!(
+            actual_path(Path::new("tests/reference/red.bmp")),
+            Path::new("tests/reference/red.actual.bmp")
+        );
+    }
+}
@@ -1,13 +1,14 @@
 use crate::gpu::{
-    CompareOp, Device, Filter, SampleCount, SamplerAddressMode, SamplerMipmapMode, TextureFormat,
-    TextureType, TextureUsage, TransferBuffer, WeakDevice,
+    validation, CompareOp, Device, Filter, SampleCount, SamplerAddressMode, SamplerMipmapMode,
+    TextureFormat, TextureType, TextureUsage, TransferBuffer, WeakDevice,
 };
 use std::{marker::PhantomData, sync::Arc};
 use sys::gpu::{
     SDL_GPUCompareOp, SDL_GPUFilter, SDL_GPUSampleCount, SDL_GPUSampler, SDL_GPUSamplerAddressMode,
     SDL_GPUSamplerCreateInfo, SDL_GPUSamplerMipmapMode, SDL_GPUTexture, SDL_GPUTextureCreateInfo,
-    SDL_GPUTextureFormat, SDL_GPUTextureRegion, SDL_GPUTextureSamplerBinding,
-    SDL_GPUTextureTransferInfo, SDL_GPUTextureType, SDL_ReleaseGPUSampler, SDL_ReleaseGPUTexture,
+    SDL_GPUTextureFormat, SDL_GPUTextureLocation, SDL_GPUTextureRegion,
+    SDL_GPUTextureSamplerBinding, SDL_GPUTextureTransferInfo, SDL_GPUTextureType,
+    SDL_ReleaseGPUSampler, SDL_ReleaseGPUTexture,
 };
 
 #[derive(Default)]
@@ -108,6 +109,52 @@ impl TextureRegion {
     }
 }
 
+#[derive(Default)]
+pub struct TextureLocation {
+    pub(super) inner: SDL_GPUTextureLocation,
+}
+impl TextureLocation {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The texture used in the copy operation.
+    pub fn with_texture(mut self, texture: &Texture) -> Self {
+        self.inner.texture = texture.raw();
+        self
+    }
+
+    /// The mip level index of the location.
+    pub fn with_mip_level(mut self, mip_level: u32) -> Self {
+        self.inner.mip_level = mip_level;
+        self
+    }
+
+    /// The layer index of the location.
+    pub fn with_layer(mut self, layer: u32) -> Self {
+        self.inner.layer = layer;
+        self
+    }
+
+    /// The left offset of the location.
+    pub fn with_x(mut self, x: u32) -> Self {
+        self.inner.x = x;
+        self
+    }
+
+    /// The top offset of the location.
+    pub fn with_y(mut self, y: u32) -> Self {
+        self.inner.y = y;
+        self
+    }
+
+    /// The front offset of the location.
+    pub fn with_z(mut self, z: u32) -> Self {
+        self.inner.z = z;
+        self
+    }
+}
+
 #[derive(Default)]
 pub struct SamplerCreateInfo {
     pub(super) inner: SDL_GPUSamplerCreateInfo,
@@ -203,6 +250,7 @@ struct SamplerContainer {
 }
 impl Drop for SamplerContainer {
     fn drop(&mut self) {
+        validation::resource_released(self.raw);
         if let Some(device) = self.device.upgrade() {
             unsafe { SDL_ReleaseGPUSampler(device.raw(), self.raw) }
         }
@@ -215,6 +263,7 @@ pub struct Sampler {
 }
 impl Sampler {
     pub(super) fn new(device: &Device, raw_sampler: *mut SDL_GPUSampler) -> Self {
+        validation::resource_created(raw_sampler);
         Self {
             inner: Arc::new(SamplerContainer {
                 raw: raw_sampler,
@@ -232,7 +281,7 @@ impl Sampler {
 #[repr(C)]
 #[derive(Default)]
 pub struct TextureSamplerBinding {
-    inner: SDL_GPUTextureSamplerBinding,
+    pub(super) inner: SDL_GPUTextureSamplerBinding,
 }
 impl TextureSamplerBinding {
     pub fn new() -> Self {
@@ -273,6 +322,7 @@ impl TextureContainer {
 impl Drop for TextureContainer {
     #[doc(alias = "SDL_ReleaseGPUTexture")]
     fn drop(&mut self) {
+        validation::resource_released(self.raw());
         match self {
             Self::UserManaged { raw, device } => {
                 if let Some(device) = device.upgrade() {
@@ -291,6 +341,8 @@ pub struct Texture<'a> {
     inner: Arc<TextureContainer>,
     width: u32,
     height: u32,
+    // `SDL_GPU_TEXTUREFORMAT_INVALID` when unknown, e.g. for textures from `from_ll`
+    format: SDL_GPUTextureFormat,
     _phantom: PhantomData<&'a ()>,
 }
 impl<'a> Texture<'a> {
@@ -299,7 +351,9 @@ impl<'a> Texture<'a> {
         raw: *mut SDL_GPUTexture,
         width: u32,
         height: u32,
+        format: SDL_GPUTextureFormat,
     ) -> Texture<'a> {
+        validation::resource_created(raw);
         Texture {
             inner: Arc::new(TextureContainer::UserManaged {
                 raw,
@@ -307,6 +361,7 @@ impl<'a> Texture<'a> {
             }),
             width,
             height,
+            format,
             _phantom: Default::default(),
         }
     }
@@ -316,14 +371,36 @@ impl<'a> Texture<'a> {
         width: u32,
         height: u32,
     ) -> Texture<'a> {
+        validation::resource_created(raw);
         Texture {
             inner: Arc::new(TextureContainer::SdlManaged { raw }),
             width,
             height,
+            format: SDL_GPUTextureFormat::INVALID,
             _phantom: Default::default(),
         }
     }
 
+    /// Takes ownership of a texture that was created outside of this crate, e.g. through
+    /// `sys::gpu::SDL_CreateGPUTexture` with custom properties.
+    ///
+    /// SDL 3.2 cannot wrap backend-native images (Vulkan, D3D12, Metal), so `raw` must be an
+    /// `SDL_GPUTexture`.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a valid texture created by `device` with the given dimensions, and must not
+    /// be released by anyone else: it is released when the last clone of the returned `Texture`
+    /// is dropped.
+    pub unsafe fn from_ll(
+        device: &Device,
+        raw: *mut SDL_GPUTexture,
+        width: u32,
+        height: u32,
+    ) -> Texture<'static> {
+        Texture::new(device, raw, width, height, SDL_GPUTextureFormat::INVALID)
+    }
+
     #[inline]
     pub fn raw(&self) -> *mut SDL_GPUTexture {
         self.inner.raw()
@@ -336,6 +413,11 @@ impl<'a> Texture<'a> {
     pub fn height(&self) -> u32 {
         self.height
     }
+
+    /// The format the texture was created with, if it is known.
+    pub(super) fn raw_format(&self) -> Option<SDL_GPUTextureFormat> {
+        Some(self.format).filter(|&format| format != SDL_GPUTextureFormat::INVALID)
+    }
 }
 
 #[derive(Default)]
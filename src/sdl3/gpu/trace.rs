@@ -0,0 +1,52 @@
+//! Spans for GPU submission work, emitted with the `tracing` feature.
+//!
+//! Acquiring and submitting command buffers and uploading data get a span each, and every pass
+//! holds a span from `begin_*_pass` until it is ended, so profilers hooked up through a
+//! `tracing` subscriber (e.g. `tracing-tracy`) can attribute CPU time to GPU work.
+//! Without the feature every hook compiles down to nothing.
+
+pub(super) use imp::*;
+
+#[cfg(feature = "tracing")]
+mod imp {
+    /// Held for the duration of a scoped operation.
+    pub(in crate::gpu) type ScopeSpan = tracing::span::EnteredSpan;
+
+    /// Held by a pass from the moment it is begun until it is ended.
+    pub(in crate::gpu) type PassSpan = tracing::span::EnteredSpan;
+
+    pub(in crate::gpu) fn scope(name: &'static str) -> ScopeSpan {
+        tracing::trace_span!(target: "sdl3::gpu", "gpu", op = name).entered()
+    }
+
+    pub(in crate::gpu) fn pass(kind: &'static str) -> PassSpan {
+        tracing::trace_span!(target: "sdl3::gpu", "gpu_pass", kind).entered()
+    }
+
+    /// `size` is in bytes for buffers and in texels for textures.
+    pub(in crate::gpu) fn upload(name: &'static str, size: u64) -> ScopeSpan {
+        tracing::trace_span!(target: "sdl3::gpu", "gpu", op = name, size).entered()
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+mod imp {
+    pub(in crate::gpu) struct ScopeSpan;
+
+    pub(in crate::gpu) struct PassSpan;
+
+    #[inline(always)]
+    pub(in crate::gpu) fn scope(_name: &'static str) -> ScopeSpan {
+        ScopeSpan
+    }
+
+    #[inline(always)]
+    pub(in crate::gpu) fn pass(_kind: &'static str) -> PassSpan {
+        PassSpan
+    }
+
+    #[inline(always)]
+    pub(in crate::gpu) fn upload(_name: &'static str, _size: u64) -> ScopeSpan {
+        ScopeSpan
+    }
+}
@@ -0,0 +1,149 @@
+//! Optional misuse checks for the GPU wrappers, enabled with the `gpu-validation` feature.
+//!
+//! SDL usually crashes or silently renders garbage when a pass is used after its command buffer
+//! was submitted, when drawing without a bound pipeline, or when binding a resource that has
+//! already been released. With the feature enabled the safe types track just enough state to
+//! panic with a descriptive message instead; without it every check compiles down to nothing.
+
+pub(super) use imp::*;
+
+#[cfg(feature = "gpu-validation")]
+mod imp {
+    use std::{
+        cell::Cell,
+        collections::{hash_map::Entry, HashMap},
+        rc::Rc,
+        sync::{Mutex, MutexGuard, PoisonError},
+    };
+
+    lazy_static! {
+        /// Raw handles of the resources that are alive, with the number of wrappers owning
+        /// each. Handles are removed when their last wrapper releases them, so this only grows
+        /// with the number of live resources. A stale handle whose address SDL has already
+        /// handed out again can't be told apart from the new resource.
+        static ref LIVE: Mutex<HashMap<usize, usize>> = Mutex::new(HashMap::new());
+    }
+
+    fn live() -> MutexGuard<'static, HashMap<usize, usize>> {
+        LIVE.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub(in crate::gpu) fn resource_created<T>(raw: *mut T) {
+        *live().entry(raw as usize).or_insert(0) += 1;
+    }
+
+    pub(in crate::gpu) fn resource_released<T>(raw: *mut T) {
+        if let Entry::Occupied(mut entry) = live().entry(raw as usize) {
+            *entry.get_mut() -= 1;
+            if *entry.get() == 0 {
+                entry.remove();
+            }
+        }
+    }
+
+    pub(in crate::gpu) fn check_resource<T>(raw: *mut T, kind: &str) {
+        if raw.is_null() {
+            panic!("sdl3::gpu validation: binding a null {}", kind);
+        }
+        let is_live = live().contains_key(&(raw as usize));
+        if !is_live {
+            panic!("sdl3::gpu validation: binding a released {}", kind);
+        }
+    }
+
+    /// Shared between a command buffer and the passes recorded into it.
+    #[derive(Clone, Default)]
+    pub(in crate::gpu) struct CommandBufferState {
+        submitted: Rc<Cell<bool>>,
+    }
+    impl CommandBufferState {
+        pub(in crate::gpu) fn new() -> Self {
+            Self::default()
+        }
+
+        pub(in crate::gpu) fn mark_submitted(&self) {
+            self.submitted.set(true);
+        }
+
+        pub(in crate::gpu) fn check_recording(&self, what: &str) {
+            if self.submitted.get() {
+                panic!(
+                    "sdl3::gpu validation: {} used after its command buffer was submitted",
+                    what
+                );
+            }
+        }
+    }
+
+    pub(in crate::gpu) struct PassState {
+        command_buffer: CommandBufferState,
+        pipeline_bound: Cell<bool>,
+    }
+    impl PassState {
+        pub(in crate::gpu) fn new(command_buffer: &CommandBufferState) -> Self {
+            Self {
+                command_buffer: command_buffer.clone(),
+                pipeline_bound: Cell::new(false),
+            }
+        }
+
+        pub(in crate::gpu) fn check_recording(&self, what: &str) {
+            self.command_buffer.check_recording(what);
+        }
+
+        pub(in crate::gpu) fn pipeline_bound(&self) {
+            self.pipeline_bound.set(true);
+        }
+
+        pub(in crate::gpu) fn check_pipeline_bound(&self, what: &str) {
+            self.check_recording(what);
+            if !self.pipeline_bound.get() {
+                panic!("sdl3::gpu validation: {} without a bound pipeline", what);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "gpu-validation"))]
+mod imp {
+    #[inline(always)]
+    pub(in crate::gpu) fn resource_created<T>(_raw: *mut T) {}
+
+    #[inline(always)]
+    pub(in crate::gpu) fn resource_released<T>(_raw: *mut T) {}
+
+    #[inline(always)]
+    pub(in crate::gpu) fn check_resource<T>(_raw: *mut T, _kind: &str) {}
+
+    #[derive(Clone)]
+    pub(in crate::gpu) struct CommandBufferState;
+    impl CommandBufferState {
+        #[inline(always)]
+        pub(in crate::gpu) fn new() -> Self {
+            Self
+        }
+
+        #[inline(always)]
+        pub(in crate::gpu) fn mark_submitted(&self) {}
+
+        #[inline(always)]
+        pub(in crate::gpu) fn check_recording(&self, _what: &str) {}
+    }
+
+    pub(in crate::gpu) struct PassState;
+    impl PassState {
+        #[inline(always)]
+        pub(in crate::gpu) fn new(_command_buffer: &CommandBufferState) -> Self {
+            Self
+        }
+
+        #[inline(always)]
+        pub(in crate::gpu) fn check_recording(&self, _what: &str) {}
+
+        #[inline(always)]
+        pub(in crate::gpu) fn pipeline_bound(&self) {}
+
+        #[inline(always)]
+        pub(in crate::gpu) fn check_pipeline_bound(&self, _what: &str) {}
+    }
+}
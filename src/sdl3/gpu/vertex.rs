@@ -0,0 +1,76 @@
+use crate::gpu::VertexElementFormat;
+
+/// A type that can be read by the GPU as a single vertex attribute.
+///
+/// Implemented for scalars and arrays of `f32`, `i32` and `u32`, and, with the `glam` and
+/// `mint` features, for their vector types. Use it with
+/// [`VertexAttribute::with_format_of`](crate::gpu::VertexAttribute::with_format_of) so the
+/// attribute format follows the field type of the vertex struct.
+///
+/// # Safety
+///
+/// The type must have exactly the memory layout described by `FORMAT`.
+pub unsafe trait VertexElement: Copy {
+    const FORMAT: VertexElementFormat;
+}
+
+macro_rules! impl_vertex_element {
+    ($($ty:ty => $format:ident),* $(,)?) => {
+        $(
+            unsafe impl VertexElement for $ty {
+                const FORMAT: VertexElementFormat = VertexElementFormat::$format;
+            }
+        )*
+    };
+}
+
+impl_vertex_element! {
+    f32 => Float,
+    [f32; 2] => Float2,
+    [f32; 3] => Float3,
+    [f32; 4] => Float4,
+    i32 => Int,
+    [i32; 2] => Int2,
+    [i32; 3] => Int3,
+    [i32; 4] => Int4,
+    u32 => Uint,
+    [u32; 2] => Uint2,
+    [u32; 3] => Uint3,
+    [u32; 4] => Uint4,
+    [i8; 2] => Byte2,
+    [i8; 4] => Byte4,
+    [u8; 2] => Ubyte2,
+    [u8; 4] => Ubyte4,
+    [i16; 2] => Short2,
+    [i16; 4] => Short4,
+    [u16; 2] => Ushort2,
+    [u16; 4] => Ushort4,
+}
+
+#[cfg(feature = "glam")]
+impl_vertex_element! {
+    glam::Vec2 => Float2,
+    glam::Vec3 => Float3,
+    glam::Vec4 => Float4,
+    glam::IVec2 => Int2,
+    glam::IVec3 => Int3,
+    glam::IVec4 => Int4,
+    glam::UVec2 => Uint2,
+    glam::UVec3 => Uint3,
+    glam::UVec4 => Uint4,
+}
+
+#[cfg(feature = "mint")]
+impl_vertex_element! {
+    mint::Vector2<f32> => Float2,
+    mint::Vector3<f32> => Float3,
+    mint::Vector4<f32> => Float4,
+    mint::Point2<f32> => Float2,
+    mint::Point3<f32> => Float3,
+    mint::Vector2<i32> => Int2,
+    mint::Vector3<i32> => Int3,
+    mint::Vector4<i32> => Int4,
+    mint::Vector2<u32> => Uint2,
+    mint::Vector3<u32> => Uint3,
+    mint::Vector4<u32> => Uint4,
+}
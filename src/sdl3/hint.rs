@@ -77,6 +77,48 @@ pub fn get_video_minimize_on_focus_loss() -> bool {
     &*value == "1"
 }
 
+/// A hint that selects the video driver used when the video subsystem is initialized, e.g.
+/// `"wayland"` or `"x11"`. A comma-separated list is tried in order.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_VIDEO_DRIVER)
+///
+/// Must be set before calling `Sdl::video`. See `sdl3::video::drivers` for the drivers compiled
+/// into SDL and `VideoSubsystem::current_video_driver` for the one in use.
+///
+/// # Example
+/// ```rust,no_run
+/// sdl3::hint::set_video_driver("wayland,x11");
+/// let sdl_context = sdl3::init().unwrap();
+/// let video_subsystem = sdl_context.video().unwrap();
+/// println!("using {}", video_subsystem.current_video_driver());
+/// ```
+pub fn set_video_driver(driver: &str) -> bool {
+    set(names::VIDEO_DRIVER, driver)
+}
+
+/// A hint that selects the audio driver used when the audio subsystem is initialized, e.g.
+/// `"pipewire"`, `"alsa"` or `"wasapi"`. A comma-separated list is tried in order.
+///
+/// [Official SDL documentation](https://wiki.libsdl.org/SDL_HINT_AUDIO_DRIVER)
+///
+/// Must be set before calling `Sdl::audio`. See `sdl3::audio::drivers` for the drivers compiled
+/// into SDL and `AudioSubsystem::current_audio_driver` for the one in use.
+///
+/// # Example
+/// ```rust,no_run
+/// for driver in sdl3::audio::drivers() {
+///     println!("available: {}", driver);
+/// }
+///
+/// sdl3::hint::set_audio_driver("pipewire,pulseaudio,alsa");
+/// let sdl_context = sdl3::init().unwrap();
+/// let audio_subsystem = sdl_context.audio().unwrap();
+/// println!("using {}", audio_subsystem.current_audio_driver());
+/// ```
+pub fn set_audio_driver(driver: &str) -> bool {
+    set(names::AUDIO_DRIVER, driver)
+}
+
 #[doc(alias = "SDL_SetHint")]
 pub fn set(name: &str, value: &str) -> bool {
     let name = CString::new(name).unwrap();
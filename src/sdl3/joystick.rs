@@ -10,7 +10,7 @@ use crate::JoystickSubsystem;
 use libc::{c_char, c_void};
 use std::ffi::CStr;
 use std::fmt;
-use sys::joystick::SDL_JoystickID;
+use sys::joystick::{SDL_JoystickConnectionState, SDL_JoystickID};
 use sys::power::{SDL_PowerState, SDL_POWERSTATE_UNKNOWN};
 use sys::stdinc::SDL_free;
 
@@ -133,6 +133,34 @@ impl PowerLevel {
     }
 }
 
+/// How a joystick is connected to the system.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ConnectionState {
+    Unknown,
+    Wired,
+    Wireless,
+}
+
+impl ConnectionState {
+    /// Returns `None` for `SDL_JOYSTICK_CONNECTION_INVALID`, which SDL reports on error.
+    pub fn from_ll(raw: SDL_JoystickConnectionState) -> Option<ConnectionState> {
+        match raw {
+            SDL_JoystickConnectionState::WIRED => Some(ConnectionState::Wired),
+            SDL_JoystickConnectionState::WIRELESS => Some(ConnectionState::Wireless),
+            SDL_JoystickConnectionState::INVALID => None,
+            _ => Some(ConnectionState::Unknown),
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_JoystickConnectionState {
+        match self {
+            ConnectionState::Unknown => SDL_JoystickConnectionState::UNKNOWN,
+            ConnectionState::Wired => SDL_JoystickConnectionState::WIRED,
+            ConnectionState::Wireless => SDL_JoystickConnectionState::WIRELESS,
+        }
+    }
+}
+
 /// Wrapper around the `SDL_Joystick` object
 pub struct Joystick {
     subsystem: JoystickSubsystem,
@@ -218,6 +246,16 @@ impl Joystick {
         }
     }
 
+    /// Retrieve whether this joystick is wired or wireless.
+    ///
+    /// SDL sends no event when this changes; changes in battery state are reported by
+    /// `Event::JoyBatteryUpdated`.
+    #[doc(alias = "SDL_GetJoystickConnectionState")]
+    pub fn connection_state(&self) -> Result<ConnectionState, Error> {
+        let result = unsafe { sys::joystick::SDL_GetJoystickConnectionState(self.raw) };
+        ConnectionState::from_ll(result).ok_or_else(get_error)
+    }
+
     /// Retrieve the number of axes for this joystick
     #[doc(alias = "SDL_GetNumJoystickAxes")]
     pub fn num_axes(&self) -> u32 {
@@ -70,6 +70,7 @@ pub mod clipboard;
 pub mod cpuinfo;
 #[macro_use]
 mod macros;
+pub mod app;
 pub mod audio;
 pub mod dialog;
 pub mod event;
@@ -84,6 +85,7 @@ pub mod keyboard;
 pub mod log;
 pub mod messagebox;
 pub mod mouse;
+pub mod pen;
 pub mod pixels;
 pub mod properties;
 pub mod rect;
@@ -91,12 +93,15 @@ pub mod render;
 mod sdl;
 #[cfg(feature = "hidapi")]
 pub mod sensor;
+#[cfg(feature = "sound")]
+pub mod sound;
 pub mod surface;
 pub mod timer;
 pub mod touch;
 pub mod url;
 pub mod version;
 pub mod video;
+pub mod vulkan;
 
 // modules
 #[cfg(feature = "gfx")]
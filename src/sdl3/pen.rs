@@ -0,0 +1,76 @@
+//! Pen (stylus) input
+//!
+//! Pens report through the `Pen*` variants of [`Event`](crate::event::Event). Pens also emit
+//! mouse and touch events for compatibility; set the `SDL_HINT_PEN_MOUSE_EVENTS` and
+//! `SDL_HINT_PEN_TOUCH_EVENTS` hints to `"0"` to only receive pen events.
+
+use crate::sys;
+use sys::pen::SDL_PenAxis;
+
+/// Identifies a pen while it is in proximity of the device; ids may be reused afterwards.
+pub type PenId = sys::pen::SDL_PenID;
+
+bitflags! {
+    /// The state of a pen's tip and buttons at the time of an event.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct PenInputFlags: u32 {
+        /// The pen is touching the surface.
+        const DOWN = sys::pen::SDL_PEN_INPUT_DOWN;
+        const BUTTON_1 = sys::pen::SDL_PEN_INPUT_BUTTON_1;
+        const BUTTON_2 = sys::pen::SDL_PEN_INPUT_BUTTON_2;
+        const BUTTON_3 = sys::pen::SDL_PEN_INPUT_BUTTON_3;
+        const BUTTON_4 = sys::pen::SDL_PEN_INPUT_BUTTON_4;
+        const BUTTON_5 = sys::pen::SDL_PEN_INPUT_BUTTON_5;
+        /// The eraser end of the pen is used.
+        const ERASER_TIP = sys::pen::SDL_PEN_INPUT_ERASER_TIP;
+    }
+}
+
+/// A continuous pen input, reported with its value by `Event::PenAxisMotion`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PenAxis {
+    /// Pressure, from 0.0 to 1.0.
+    Pressure,
+    /// Horizontal tilt in degrees, from -90.0 (left) to 90.0 (right).
+    XTilt,
+    /// Vertical tilt in degrees, from -90.0 (up) to 90.0 (down).
+    YTilt,
+    /// Distance to the surface, from 0.0 to 1.0.
+    Distance,
+    /// Barrel rotation in degrees, from -180.0 to 179.9, clockwise with 0.0 facing up.
+    Rotation,
+    /// Finger wheel or slider, from 0.0 to 1.0.
+    Slider,
+    /// Pressure from squeezing the barrel.
+    TangentialPressure,
+    /// An axis added in a newer SDL version, with its raw value.
+    Unknown(i32),
+}
+
+impl PenAxis {
+    pub fn from_ll(axis: SDL_PenAxis) -> PenAxis {
+        match axis {
+            SDL_PenAxis::PRESSURE => PenAxis::Pressure,
+            SDL_PenAxis::XTILT => PenAxis::XTilt,
+            SDL_PenAxis::YTILT => PenAxis::YTilt,
+            SDL_PenAxis::DISTANCE => PenAxis::Distance,
+            SDL_PenAxis::ROTATION => PenAxis::Rotation,
+            SDL_PenAxis::SLIDER => PenAxis::Slider,
+            SDL_PenAxis::TANGENTIAL_PRESSURE => PenAxis::TangentialPressure,
+            SDL_PenAxis(raw) => PenAxis::Unknown(raw),
+        }
+    }
+
+    pub fn to_ll(self) -> SDL_PenAxis {
+        match self {
+            PenAxis::Pressure => SDL_PenAxis::PRESSURE,
+            PenAxis::XTilt => SDL_PenAxis::XTILT,
+            PenAxis::YTilt => SDL_PenAxis::YTILT,
+            PenAxis::Distance => SDL_PenAxis::DISTANCE,
+            PenAxis::Rotation => SDL_PenAxis::ROTATION,
+            PenAxis::Slider => SDL_PenAxis::SLIDER,
+            PenAxis::TangentialPressure => SDL_PenAxis::TANGENTIAL_PRESSURE,
+            PenAxis::Unknown(raw) => SDL_PenAxis(raw),
+        }
+    }
+}
@@ -214,6 +214,76 @@ impl From<(u8, u8, u8, u8)> for Color {
     }
 }
 
+/// A color with floating point components, usually between 0.0 and 1.0.
+///
+/// Components above 1.0 are meaningful when rendering in a linear colorspace for HDR output.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl FColor {
+    #[inline]
+    #[allow(non_snake_case)]
+    pub const fn RGB(r: f32, g: f32, b: f32) -> FColor {
+        FColor { r, g, b, a: 1.0 }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub const fn RGBA(r: f32, g: f32, b: f32, a: f32) -> FColor {
+        FColor { r, g, b, a }
+    }
+
+    #[inline]
+    pub const fn rgba(self) -> (f32, f32, f32, f32) {
+        (self.r, self.g, self.b, self.a)
+    }
+}
+
+impl From<Color> for FColor {
+    fn from(color: Color) -> FColor {
+        FColor::RGBA(
+            color.r as f32 / 255.0,
+            color.g as f32 / 255.0,
+            color.b as f32 / 255.0,
+            color.a as f32 / 255.0,
+        )
+    }
+}
+
+impl From<FColor> for sys::pixels::SDL_FColor {
+    fn from(val: FColor) -> Self {
+        sys::pixels::SDL_FColor {
+            r: val.r,
+            g: val.g,
+            b: val.b,
+            a: val.a,
+        }
+    }
+}
+
+impl From<sys::pixels::SDL_FColor> for FColor {
+    fn from(raw: sys::pixels::SDL_FColor) -> FColor {
+        FColor::RGBA(raw.r, raw.g, raw.b, raw.a)
+    }
+}
+
+impl From<(f32, f32, f32)> for FColor {
+    fn from((r, g, b): (f32, f32, f32)) -> FColor {
+        FColor::RGB(r, g, b)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for FColor {
+    fn from((r, g, b, a): (f32, f32, f32, f32)) -> FColor {
+        FColor::RGBA(r, g, b, a)
+    }
+}
+
 pub struct PixelMasks {
     /// Bits per pixel; usually 15, 16, or 32
     pub bpp: u8,
@@ -333,6 +403,23 @@ impl PixelFormat {
             | SDL_PixelFormat::ABGR8888
             | SDL_PixelFormat::BGRA8888
             | SDL_PixelFormat::ARGB2101010 => num_of_pixels * 4,
+            SDL_PixelFormat::RGB48
+            | SDL_PixelFormat::BGR48
+            | SDL_PixelFormat::RGB48_FLOAT
+            | SDL_PixelFormat::BGR48_FLOAT => num_of_pixels * 6,
+            SDL_PixelFormat::RGBA64
+            | SDL_PixelFormat::ARGB64
+            | SDL_PixelFormat::BGRA64
+            | SDL_PixelFormat::ABGR64
+            | SDL_PixelFormat::RGBA64_FLOAT
+            | SDL_PixelFormat::ARGB64_FLOAT
+            | SDL_PixelFormat::BGRA64_FLOAT
+            | SDL_PixelFormat::ABGR64_FLOAT => num_of_pixels * 8,
+            SDL_PixelFormat::RGB96_FLOAT | SDL_PixelFormat::BGR96_FLOAT => num_of_pixels * 12,
+            SDL_PixelFormat::RGBA128_FLOAT
+            | SDL_PixelFormat::ARGB128_FLOAT
+            | SDL_PixelFormat::BGRA128_FLOAT
+            | SDL_PixelFormat::ABGR128_FLOAT => num_of_pixels * 16,
             // YUV formats
             // FIXME: rounding error here?
             SDL_PixelFormat::YV12 | SDL_PixelFormat::IYUV => num_of_pixels / 2 * 3,
@@ -377,6 +464,23 @@ impl PixelFormat {
             | SDL_PixelFormat::ABGR8888
             | SDL_PixelFormat::BGRA8888
             | SDL_PixelFormat::ARGB2101010 => 4,
+            SDL_PixelFormat::RGB48
+            | SDL_PixelFormat::BGR48
+            | SDL_PixelFormat::RGB48_FLOAT
+            | SDL_PixelFormat::BGR48_FLOAT => 6,
+            SDL_PixelFormat::RGBA64
+            | SDL_PixelFormat::ARGB64
+            | SDL_PixelFormat::BGRA64
+            | SDL_PixelFormat::ABGR64
+            | SDL_PixelFormat::RGBA64_FLOAT
+            | SDL_PixelFormat::ARGB64_FLOAT
+            | SDL_PixelFormat::BGRA64_FLOAT
+            | SDL_PixelFormat::ABGR64_FLOAT => 8,
+            SDL_PixelFormat::RGB96_FLOAT | SDL_PixelFormat::BGR96_FLOAT => 12,
+            SDL_PixelFormat::RGBA128_FLOAT
+            | SDL_PixelFormat::ARGB128_FLOAT
+            | SDL_PixelFormat::BGRA128_FLOAT
+            | SDL_PixelFormat::ABGR128_FLOAT => 16,
             // YUV formats
             SDL_PixelFormat::YV12 | SDL_PixelFormat::IYUV => 1,
             SDL_PixelFormat::YUY2 | SDL_PixelFormat::UYVY | SDL_PixelFormat::YVYU => 2,
@@ -391,6 +495,12 @@ impl PixelFormat {
         }
     }
 
+    /// Returns whether the components are stored as floating point values, e.g.
+    /// `SDL_PIXELFORMAT_RGBA64_FLOAT` for HDR textures.
+    pub fn is_float(self) -> bool {
+        sys::pixels::SDL_ISPIXELFORMAT_FLOAT(self.raw)
+    }
+
     pub fn supports_alpha(self) -> bool {
         matches!(
             self.raw,
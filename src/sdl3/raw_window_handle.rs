@@ -28,8 +28,11 @@ impl HasWindowHandle for Window {
                 sys::video::SDL_PROP_WINDOW_WIN32_INSTANCE_POINTER,
                 std::ptr::null_mut(),
             );
-            let mut handle = Win32WindowHandle::new(NonZero::new_unchecked(hwnd.addr() as isize));
-            handle.hinstance = Some(NonZero::new_unchecked(hinstance.addr() as isize));
+            let Some(hwnd) = NonZero::new(hwnd.addr() as isize) else {
+                return Err(HandleError::Unavailable);
+            };
+            let mut handle = Win32WindowHandle::new(hwnd);
+            handle.hinstance = NonZero::new(hinstance.addr() as isize);
             let raw_window_handle = RawWindowHandle::Win32(handle);
 
             Ok(WindowHandle::borrow_raw(raw_window_handle))
@@ -70,7 +73,10 @@ impl HasWindowHandle for Window {
                 sys::video::SDL_PROP_WINDOW_UIKIT_WINDOW_POINTER,
                 std::ptr::null_mut(),
             );
-            let handle = UiKitWindowHandle::new(NonNull::new_unchecked(ui_view));
+            let Some(ui_view) = NonNull::new(ui_view) else {
+                return Err(HandleError::Unavailable);
+            };
+            let handle = UiKitWindowHandle::new(ui_view);
             let raw_window_handle = RawWindowHandle::UiKit(handle);
 
             Ok(WindowHandle::borrow_raw(raw_window_handle))
@@ -88,7 +94,10 @@ impl HasWindowHandle for Window {
                 sys::video::SDL_PROP_WINDOW_ANDROID_WINDOW_POINTER,
                 std::ptr::null_mut(),
             );
-            let handle = AndroidNdkWindowHandle::new(NonNull::new_unchecked(native_window));
+            let Some(native_window) = NonNull::new(native_window) else {
+                return Err(HandleError::Unavailable);
+            };
+            let handle = AndroidNdkWindowHandle::new(native_window);
             let raw_window_handle = RawWindowHandle::AndroidNdk(handle);
 
             Ok(WindowHandle::borrow_raw(raw_window_handle))
@@ -115,6 +124,9 @@ impl HasWindowHandle for Window {
                         sys::video::SDL_PROP_WINDOW_X11_WINDOW_NUMBER,
                         0,
                     );
+                    if window == 0 {
+                        return Err(HandleError::Unavailable);
+                    }
                     let handle = XlibWindowHandle::new(window as u64);
                     let raw_window_handle = RawWindowHandle::Xlib(handle);
 
@@ -130,14 +142,16 @@ impl HasWindowHandle for Window {
                         sys::video::SDL_PROP_WINDOW_WAYLAND_SURFACE_POINTER,
                         std::ptr::null_mut(),
                     );
-                    let handle = WaylandWindowHandle::new(NonNull::new_unchecked(window));
+                    let Some(window) = NonNull::new(window) else {
+                        return Err(HandleError::Unavailable);
+                    };
+                    let handle = WaylandWindowHandle::new(window);
                     let raw_window_handle = RawWindowHandle::Wayland(handle);
 
                     Ok(WindowHandle::borrow_raw(raw_window_handle))
                 }
-                _ => {
-                    panic!("{video_driver:?} video driver is not supported, please file an issue with raw-window-handle.",);
-                }
+                // e.g. the offscreen and dummy drivers, which have no native window
+                _ => Err(HandleError::NotSupported),
             }
         }
     }
@@ -230,16 +244,14 @@ impl HasDisplayHandle for Window {
                         std::ptr::null_mut(),
                     );
                     let Some(display) = core::ptr::NonNull::<libc::c_void>::new(display) else {
-                        return Err(HandleError::Unavailable); // I'm unsure if this is the right error type, of if we should just panic here if the display isn't available?
+                        return Err(HandleError::Unavailable);
                     };
                     let handle = WaylandDisplayHandle::new(display);
                     let raw_window_handle = RawDisplayHandle::Wayland(handle);
 
                     Ok(DisplayHandle::borrow_raw(raw_window_handle))
                 }
-                _ => {
-                    panic!("{video_driver:?} video driver is not supported, please file an issue with raw-window-handle.");
-                }
+                _ => Err(HandleError::NotSupported),
             }
         }
     }
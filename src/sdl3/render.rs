@@ -33,7 +33,7 @@ use crate::get_error;
 use crate::pixels;
 use crate::rect::Point;
 use crate::rect::Rect;
-use crate::surface::{Surface, SurfaceContext, SurfaceRef};
+use crate::surface::{FlipMode, Surface, SurfaceContext, SurfaceRef};
 use crate::sys;
 use crate::video::{Window, WindowContext};
 use crate::Error;
@@ -41,12 +41,12 @@ use libc::{c_double, c_int};
 use pixels::PixelFormat;
 use std::convert::{Into, TryFrom, TryInto};
 use std::error;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
 #[cfg(not(feature = "unsafe_textures"))]
 use std::marker::PhantomData;
 use std::mem;
-use std::mem::{transmute, MaybeUninit};
+use std::mem::MaybeUninit;
 use std::ops::Deref;
 use std::ptr;
 use std::rc::Rc;
@@ -55,7 +55,6 @@ use sys::blendmode::SDL_BlendMode;
 use sys::everything::SDL_PropertiesID;
 use sys::render::{SDL_GetTextureProperties, SDL_TextureAccess};
 use sys::stdinc::Sint64;
-use sys::surface::{SDL_FLIP_HORIZONTAL, SDL_FLIP_NONE, SDL_FLIP_VERTICAL};
 
 /// Possible errors returned by targeting a `Canvas` to render to a `Texture`
 #[derive(Debug, Clone)]
@@ -117,6 +116,7 @@ impl From<i64> for TextureAccess {
 }
 
 // floating-point point
+#[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct FPoint {
     pub x: f32,
@@ -132,6 +132,11 @@ impl FPoint {
             y: self.y,
         }
     }
+
+    #[doc(alias = "SDL_FPoint")]
+    pub fn raw_slice(slice: &[FPoint]) -> *const sys::rect::SDL_FPoint {
+        slice.as_ptr() as *const sys::rect::SDL_FPoint
+    }
 }
 
 impl From<Point> for FPoint {
@@ -140,7 +145,14 @@ impl From<Point> for FPoint {
     }
 }
 
+impl From<(f32, f32)> for FPoint {
+    fn from((x, y): (f32, f32)) -> Self {
+        FPoint::new(x, y)
+    }
+}
+
 // floating-point rectangle
+#[repr(C)]
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct FRect {
     pub x: f32,
@@ -160,6 +172,12 @@ impl FRect {
             h: self.h,
         }
     }
+
+    #[doc(alias = "SDL_FRect")]
+    pub fn raw_slice(slice: &[FRect]) -> *const sys::rect::SDL_FRect {
+        slice.as_ptr() as *const sys::rect::SDL_FRect
+    }
+
     pub fn set_x(&mut self, update: f32) {
         self.x = update;
     }
@@ -184,6 +202,109 @@ impl From<Rect> for FRect {
     }
 }
 
+/// The width and height in pixels of a glyph drawn by [`Canvas::render_debug_text`].
+pub const DEBUG_TEXT_FONT_CHARACTER_SIZE: u32 =
+    sys::render::SDL_DEBUG_TEXT_FONT_CHARACTER_SIZE as u32;
+
+/// A vertex for [`Canvas::render_geometry`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vertex {
+    pub position: FPoint,
+    pub color: pixels::Color,
+    /// Normalized texture coordinates, ignored when rendering without a texture.
+    pub tex_coord: FPoint,
+}
+impl Vertex {
+    pub fn new(position: FPoint, color: pixels::Color, tex_coord: FPoint) -> Vertex {
+        Vertex {
+            position,
+            color,
+            tex_coord,
+        }
+    }
+    pub fn to_ll(&self) -> sys::render::SDL_Vertex {
+        sys::render::SDL_Vertex {
+            position: self.position.to_ll(),
+            color: sys::pixels::SDL_FColor {
+                r: self.color.r as f32 / 255.0,
+                g: self.color.g as f32 / 255.0,
+                b: self.color.b as f32 / 255.0,
+                a: self.color.a as f32 / 255.0,
+            },
+            tex_coord: self.tex_coord.to_ll(),
+        }
+    }
+}
+
+/// One vertex attribute for [`Canvas::render_geometry_raw`], read from a slice with a fixed
+/// stride between consecutive vertices.
+///
+/// Positions and texture coordinates are `[f32; 2]`, colors are `[f32; 4]` in RGBA order.
+#[derive(Copy, Clone)]
+pub struct GeometryStream<'a, A> {
+    ptr: *const A,
+    stride: usize,
+    len: usize,
+    _marker: std::marker::PhantomData<&'a [A]>,
+}
+impl<'a, A> GeometryStream<'a, A> {
+    /// Attributes stored back to back, e.g. a `Vec<[f32; 2]>` of positions.
+    pub fn packed(data: &'a [A]) -> Self {
+        GeometryStream {
+            ptr: data.as_ptr(),
+            stride: mem::size_of::<A>(),
+            len: data.len(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// The attribute found `offset` bytes into every element of `data`, e.g. the position
+    /// field of a particle struct.
+    ///
+    /// Errors if an `A` at `offset` does not fit inside a `T`.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `data` must hold an initialized, suitably aligned `A` at `offset`.
+    pub unsafe fn strided<T>(data: &'a [T], offset: usize) -> Result<Self, Error> {
+        let end = offset.checked_add(mem::size_of::<A>());
+        if end.is_none_or(|end| end > mem::size_of::<T>()) {
+            return Err(Error(
+                "GeometryStream::strided: attribute does not fit inside the element".to_owned(),
+            ));
+        }
+        Ok(GeometryStream {
+            // the pointer of an empty slice dangles and is never read, so `add` would be UB there
+            ptr: (data.as_ptr() as *const u8).wrapping_add(offset) as *const A,
+            stride: mem::size_of::<T>(),
+            len: data.len(),
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// An index type accepted by [`Canvas::render_geometry_raw`].
+///
+/// # Safety
+///
+/// Implementors must be plain integers that are 1, 2 or 4 bytes wide, which SDL reads as
+/// unsigned. `i32`, the index type of [`Canvas::render_geometry`], qualifies because SDL checks
+/// every index against the number of vertices, so negative ones read as large unsigned values
+/// are rejected with an error instead of reading out of bounds.
+pub unsafe trait GeometryIndex: Copy {}
+unsafe impl GeometryIndex for u8 {}
+unsafe impl GeometryIndex for u16 {}
+unsafe impl GeometryIndex for u32 {}
+unsafe impl GeometryIndex for i32 {}
+
 #[derive(Debug)]
 pub struct InvalidTextureAccess(u32);
 
@@ -529,6 +650,7 @@ impl<'s> Canvas<Surface<'s>> {
         TextureCreator {
             context: self.context.clone(),
             default_pixel_format: self.default_pixel_format,
+            default_scale_mode: None,
         }
     }
 }
@@ -592,6 +714,7 @@ impl Canvas<Window> {
         TextureCreator {
             context: self.context.clone(),
             default_pixel_format: self.default_pixel_format(),
+            default_scale_mode: None,
         }
     }
 }
@@ -671,6 +794,36 @@ impl<T: RenderTarget> Canvas<T> {
         unsafe { self.set_raw_target(target) };
     }
 
+    /// Sets the target of the `Canvas` to `texture` while `f` runs, then restores whatever
+    /// the target was before, even if `f` panics.
+    ///
+    /// Unlike `with_texture_canvas`, failing to set the target is reported instead of silently
+    /// drawing to the previous target, and `f` may return a value.
+    ///
+    /// The texture must have been created with the texture access
+    /// `sdl3::render::TextureAccess::Target`.
+    ///
+    /// # Errors
+    ///
+    /// * returns `TargetRenderError::SdlError` if the target could not be set.
+    #[doc(alias = "SDL_SetRenderTarget")]
+    pub fn with_render_target<R, F>(
+        &mut self,
+        texture: &mut Texture,
+        f: F,
+    ) -> Result<R, TargetRenderError>
+    where
+        for<'r> F: FnOnce(&'r mut Canvas<T>) -> R,
+    {
+        let previous = unsafe { self.get_raw_target() };
+        unsafe { self.set_raw_target(texture.raw) }.map_err(TargetRenderError::SdlError)?;
+        let guard = RenderTargetGuard {
+            canvas: self,
+            previous,
+        };
+        Ok(f(guard.canvas))
+    }
+
     /// Same as `with_texture_canvas`, but allows to change multiple `Texture`s at once with the
     /// least amount of overhead. It means that between every iteration the Target is not reset to
     /// the source, and that the fact that the Canvas supports render target isn't checked every
@@ -767,6 +920,19 @@ impl<T: RenderTarget> Canvas<T> {
     }
 }
 
+/// Restores the render target of a `Canvas` when dropped, see `Canvas::with_render_target`.
+struct RenderTargetGuard<'a, T: RenderTarget> {
+    canvas: &'a mut Canvas<T>,
+    previous: *mut sys::render::SDL_Texture,
+}
+
+impl<T: RenderTarget> Drop for RenderTargetGuard<'_, T> {
+    fn drop(&mut self) {
+        // Restoring a target that was valid before can only fail on an invalid renderer
+        let _ = unsafe { self.canvas.set_raw_target(self.previous) };
+    }
+}
+
 /// Creates Textures that cannot outlive the creator
 ///
 /// The `TextureCreator` does not hold a lifetime to its Canvas by design choice.
@@ -782,6 +948,7 @@ impl<T: RenderTarget> Canvas<T> {
 pub struct TextureCreator<T> {
     context: Rc<RendererContext<T>>,
     default_pixel_format: PixelFormat,
+    default_scale_mode: Option<ScaleMode>,
 }
 
 /// Create a new renderer for a window.
@@ -790,22 +957,140 @@ pub fn create_renderer(
     window: Window,
     renderer_name: Option<&str>,
 ) -> Result<WindowCanvas, IntegerOrSdlError> {
-    use crate::common::IntegerOrSdlError::*;
-    let raw = unsafe {
-        sys::render::SDL_CreateRenderer(
-            window.raw(),
-            if let Some(renderer_name) = renderer_name {
-                renderer_name.as_ptr() as *const _
-            } else {
-                std::ptr::null()
-            },
-        )
-    };
+    let mut builder = RendererBuilder::new(window);
+    if let Some(renderer_name) = renderer_name {
+        builder = builder.driver(renderer_name);
+    }
+    builder.build().map_err(IntegerOrSdlError::SdlError)
+}
+
+/// The colorspace a renderer outputs in.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum RendererColorspace {
+    /// Gamma corrected sRGB, the default.
+    #[default]
+    Srgb,
+    /// Linear sRGB, where colors can exceed 1.0 for HDR output.
+    SrgbLinear,
+}
 
-    if raw.is_null() {
-        Err(SdlError(get_error()))
-    } else {
-        Ok(Canvas::from_window_and_renderer(window, raw))
+impl From<RendererColorspace> for sys::pixels::SDL_Colorspace {
+    fn from(colorspace: RendererColorspace) -> sys::pixels::SDL_Colorspace {
+        colorspace.to_ll()
+    }
+}
+
+impl RendererColorspace {
+    pub fn to_ll(self) -> sys::pixels::SDL_Colorspace {
+        match self {
+            RendererColorspace::Srgb => sys::pixels::SDL_COLORSPACE_SRGB,
+            RendererColorspace::SrgbLinear => sys::pixels::SDL_COLORSPACE_SRGB_LINEAR,
+        }
+    }
+}
+
+/// Creates a renderer for a window with extra options.
+///
+/// ```no_run
+/// use sdl3::render::{RendererBuilder, RendererVSync};
+///
+/// let sdl_context = sdl3::init().unwrap();
+/// let video_subsystem = sdl_context.video().unwrap();
+/// let window = video_subsystem.window("Example", 800, 600).build().unwrap();
+///
+/// let canvas = RendererBuilder::new(window)
+///     .driver("opengl")
+///     .vsync(RendererVSync::Every(1))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct RendererBuilder {
+    window: Window,
+    driver: Option<String>,
+    vsync: Option<RendererVSync>,
+    colorspace: Option<RendererColorspace>,
+}
+
+impl RendererBuilder {
+    /// Initializes a new `RendererBuilder` with the default settings, letting SDL pick the
+    /// driver.
+    pub fn new(window: Window) -> RendererBuilder {
+        RendererBuilder {
+            window,
+            driver: None,
+            vsync: None,
+            colorspace: None,
+        }
+    }
+
+    /// Requests a render driver by name, e.g. one returned by `drivers()`.
+    ///
+    /// The `"gpu"` driver renders through the SDL GPU API, see `Canvas::raw_gpu_device`.
+    pub fn driver(mut self, name: &str) -> RendererBuilder {
+        self.driver = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the vsync of the renderer at creation, which avoids a visible switch later on.
+    pub fn vsync(mut self, vsync: RendererVSync) -> RendererBuilder {
+        self.vsync = Some(vsync);
+        self
+    }
+
+    /// Sets the colorspace the renderer outputs in.
+    pub fn output_colorspace(mut self, colorspace: RendererColorspace) -> RendererBuilder {
+        self.colorspace = Some(colorspace);
+        self
+    }
+
+    /// Builds the renderer.
+    #[doc(alias = "SDL_CreateRendererWithProperties")]
+    pub fn build(self) -> Result<WindowCanvas, Error> {
+        let driver = match self.driver {
+            Some(driver) => Some(
+                CString::new(driver)
+                    .map_err(|_| Error("render driver name contains a nul byte".to_owned()))?,
+            ),
+            None => None,
+        };
+        let raw = unsafe {
+            let props = sys::properties::SDL_CreateProperties();
+            sys::properties::SDL_SetPointerProperty(
+                props,
+                sys::render::SDL_PROP_RENDERER_CREATE_WINDOW_POINTER,
+                self.window.raw() as *mut _,
+            );
+            if let Some(driver) = &driver {
+                sys::properties::SDL_SetStringProperty(
+                    props,
+                    sys::render::SDL_PROP_RENDERER_CREATE_NAME_STRING,
+                    driver.as_ptr(),
+                );
+            }
+            if let Some(vsync) = self.vsync {
+                sys::properties::SDL_SetNumberProperty(
+                    props,
+                    sys::render::SDL_PROP_RENDERER_CREATE_PRESENT_VSYNC_NUMBER,
+                    i32::from(vsync).into(),
+                );
+            }
+            if let Some(colorspace) = self.colorspace {
+                sys::properties::SDL_SetNumberProperty(
+                    props,
+                    sys::render::SDL_PROP_RENDERER_CREATE_OUTPUT_COLORSPACE_NUMBER,
+                    colorspace.to_ll().0.into(),
+                );
+            }
+            let raw = sys::render::SDL_CreateRendererWithProperties(props);
+            sys::properties::SDL_DestroyProperties(props);
+            raw
+        };
+
+        if raw.is_null() {
+            Err(get_error())
+        } else {
+            Ok(Canvas::from_window_and_renderer(self.window, raw))
+        }
     }
 }
 
@@ -887,6 +1172,10 @@ fn ll_create_texture(
     )
 }
 
+/// How a texture is sampled when it is drawn at a different size.
+///
+/// Use `ScaleMode::Nearest` for pixel art, either per texture with `Texture::set_scale_mode` or
+/// for all textures with `TextureCreator::set_default_scale_mode`.
 #[repr(i32)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum ScaleMode {
@@ -917,6 +1206,78 @@ impl TryFrom<sdl3_sys::everything::SDL_ScaleMode> for ScaleMode {
     }
 }
 
+/// The vsync setting of a renderer.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RendererVSync {
+    /// Present as soon as possible, possibly tearing.
+    Disabled,
+    /// Synchronize with the display, but present immediately when a frame arrives late
+    /// (late swap tearing). Not supported by every driver.
+    Adaptive,
+    /// Present once every `n` vertical refreshes; `Every(1)` is regular vsync.
+    Every(u32),
+}
+
+impl From<RendererVSync> for i32 {
+    fn from(vsync: RendererVSync) -> i32 {
+        match vsync {
+            RendererVSync::Disabled => sys::render::SDL_RENDERER_VSYNC_DISABLED,
+            RendererVSync::Adaptive => sys::render::SDL_RENDERER_VSYNC_ADAPTIVE,
+            RendererVSync::Every(0) => sys::render::SDL_RENDERER_VSYNC_DISABLED,
+            RendererVSync::Every(n) => n.min(i32::MAX as u32) as i32,
+        }
+    }
+}
+
+impl From<i32> for RendererVSync {
+    fn from(vsync: i32) -> RendererVSync {
+        match vsync {
+            sys::render::SDL_RENDERER_VSYNC_ADAPTIVE => RendererVSync::Adaptive,
+            n if n > 0 => RendererVSync::Every(n as u32),
+            _ => RendererVSync::Disabled,
+        }
+    }
+}
+
+/// How a logical resolution set with `Canvas::set_logical_size` is mapped onto the output.
+#[repr(i32)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum LogicalPresentation {
+    /// There is no logical size in effect.
+    Disabled = sys::render::SDL_LOGICAL_PRESENTATION_DISABLED.0,
+    /// The rendered content is stretched to the output resolution.
+    Stretch = sys::render::SDL_LOGICAL_PRESENTATION_STRETCH.0,
+    /// The rendered content is fit to the largest dimension and the other dimension is
+    /// letterboxed with black bars.
+    Letterbox = sys::render::SDL_LOGICAL_PRESENTATION_LETTERBOX.0,
+    /// The rendered content is fit to the smallest dimension and the other dimension extends
+    /// beyond the output bounds.
+    Overscan = sys::render::SDL_LOGICAL_PRESENTATION_OVERSCAN.0,
+    /// The rendered content is scaled up by integer multiples to fit the output resolution.
+    IntegerScale = sys::render::SDL_LOGICAL_PRESENTATION_INTEGER_SCALE.0,
+}
+
+impl From<LogicalPresentation> for sys::render::SDL_RendererLogicalPresentation {
+    fn from(mode: LogicalPresentation) -> sys::render::SDL_RendererLogicalPresentation {
+        sys::render::SDL_RendererLogicalPresentation(mode as i32)
+    }
+}
+
+impl TryFrom<sys::render::SDL_RendererLogicalPresentation> for LogicalPresentation {
+    type Error = ();
+
+    fn try_from(mode: sys::render::SDL_RendererLogicalPresentation) -> Result<Self, Self::Error> {
+        Ok(match mode {
+            sys::render::SDL_LOGICAL_PRESENTATION_DISABLED => Self::Disabled,
+            sys::render::SDL_LOGICAL_PRESENTATION_STRETCH => Self::Stretch,
+            sys::render::SDL_LOGICAL_PRESENTATION_LETTERBOX => Self::Letterbox,
+            sys::render::SDL_LOGICAL_PRESENTATION_OVERSCAN => Self::Overscan,
+            sys::render::SDL_LOGICAL_PRESENTATION_INTEGER_SCALE => Self::IntegerScale,
+            _ => return Err(()),
+        })
+    }
+}
+
 /// Texture-creating methods for the renderer
 impl<T> TextureCreator<T> {
     // this can prevent introducing UB until
@@ -930,6 +1291,25 @@ impl<T> TextureCreator<T> {
         self.default_pixel_format
     }
 
+    /// Sets the scale mode given to every texture created by this `TextureCreator` from now on,
+    /// e.g. `ScaleMode::Nearest` so pixel art stays crisp when scaled up.
+    ///
+    /// Without a default, textures use SDL's default of `ScaleMode::Linear`.
+    pub fn set_default_scale_mode(&mut self, scale: ScaleMode) {
+        self.default_scale_mode = Some(scale);
+    }
+
+    /// Gets the scale mode given to every texture created by this `TextureCreator`.
+    pub fn default_scale_mode(&self) -> ScaleMode {
+        self.default_scale_mode.unwrap_or(ScaleMode::Linear)
+    }
+
+    fn apply_default_scale_mode(&self, texture: &mut Texture) {
+        if let Some(scale) = self.default_scale_mode {
+            InternalTexture { raw: texture.raw }.set_scale_mode(scale);
+        }
+    }
+
     /// Creates a texture for a rendering context.
     ///
     /// If format is `None`, the format will be the one the parent Window or Surface uses.
@@ -957,7 +1337,9 @@ impl<T> TextureCreator<T> {
         if result.is_null() {
             Err(SdlError(get_error()))
         } else {
-            unsafe { Ok(self.raw_create_texture(result)) }
+            let mut texture = unsafe { self.raw_create_texture(result) };
+            self.apply_default_scale_mode(&mut texture);
+            Ok(texture)
         }
     }
 
@@ -1043,7 +1425,9 @@ impl<T> TextureCreator<T> {
         if result.is_null() {
             Err(SdlError(get_error()))
         } else {
-            unsafe { Ok(self.raw_create_texture(result)) }
+            let mut texture = unsafe { self.raw_create_texture(result) };
+            self.apply_default_scale_mode(&mut texture);
+            Ok(texture)
         }
     }
 
@@ -1062,6 +1446,179 @@ impl<T> TextureCreator<T> {
     pub const unsafe fn raw_create_texture(&self, raw: *mut sys::render::SDL_Texture) -> Texture {
         Texture { raw }
     }
+
+    /// Starts building a texture with options `create_texture` can't express, such as the
+    /// colorspace and HDR metadata.
+    ///
+    /// ```no_run
+    /// use sdl3::pixels::PixelFormat;
+    /// use sdl3::render::{Canvas, RendererColorspace, TextureAccess};
+    /// use sdl3::sys::pixels::SDL_PixelFormat;
+    /// use sdl3::video::Window;
+    ///
+    /// # let canvas: Canvas<Window> = unimplemented!();
+    /// let texture_creator = canvas.texture_creator();
+    /// let texture = texture_creator
+    ///     .texture_builder(1920, 1080)
+    ///     .format(PixelFormat::try_from(SDL_PixelFormat::RGBA64_FLOAT).unwrap())
+    ///     .access(TextureAccess::Streaming)
+    ///     .colorspace(RendererColorspace::SrgbLinear)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn texture_builder(&self, width: u32, height: u32) -> TextureBuilder<'_, T> {
+        TextureBuilder {
+            texture_creator: self,
+            width,
+            height,
+            format: None,
+            access: TextureAccess::Static,
+            colorspace: None,
+            sdr_white_point: None,
+            hdr_headroom: None,
+        }
+    }
+}
+
+/// Creates a texture with extra options, see `TextureCreator::texture_builder`.
+pub struct TextureBuilder<'a, T> {
+    texture_creator: &'a TextureCreator<T>,
+    width: u32,
+    height: u32,
+    format: Option<PixelFormat>,
+    access: TextureAccess,
+    colorspace: Option<sys::pixels::SDL_Colorspace>,
+    sdr_white_point: Option<f32>,
+    hdr_headroom: Option<f32>,
+}
+
+impl<'a, T> TextureBuilder<'a, T> {
+    /// Sets the pixel format, e.g. a float format for HDR content. Defaults to the format of
+    /// the target.
+    pub fn format(mut self, format: PixelFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the access of the texture. Defaults to `TextureAccess::Static`.
+    pub fn access(mut self, access: TextureAccess) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Sets the colorspace of the texture's contents. Defaults to sRGB for RGB formats, or a
+    /// colorspace appropriate for the resolution for YUV formats.
+    pub fn colorspace<C: Into<sys::pixels::SDL_Colorspace>>(mut self, colorspace: C) -> Self {
+        self.colorspace = Some(colorspace.into());
+        self
+    }
+
+    /// Sets the value of SDR white in the texture's colorspace, for HDR10 and float textures.
+    pub fn sdr_white_point(mut self, white_point: f32) -> Self {
+        self.sdr_white_point = Some(white_point);
+        self
+    }
+
+    /// Sets the maximum brightness of the texture's content relative to SDR white, for HDR10
+    /// and float textures.
+    pub fn hdr_headroom(mut self, headroom: f32) -> Self {
+        self.hdr_headroom = Some(headroom);
+        self
+    }
+
+    fn create_raw(&self) -> Result<*mut sys::render::SDL_Texture, TextureValueError> {
+        use self::TextureValueError::*;
+        let w = validate_int(self.width, "width").map_err(|_| WidthOverflows(self.width))?;
+        let h = validate_int(self.height, "height").map_err(|_| HeightOverflows(self.height))?;
+        let format = self
+            .format
+            .unwrap_or(self.texture_creator.default_pixel_format);
+        let raw_format = sys::pixels::SDL_PixelFormat::from(format);
+        if matches!(
+            raw_format,
+            sys::pixels::SDL_PIXELFORMAT_YV12 | sys::pixels::SDL_PIXELFORMAT_IYUV
+        ) && (w % 2 != 0 || h % 2 != 0)
+        {
+            return Err(WidthMustBeMultipleOfTwoForFormat(self.width, format));
+        }
+
+        let raw = unsafe {
+            let props = sys::properties::SDL_CreateProperties();
+            sys::properties::SDL_SetNumberProperty(
+                props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_FORMAT_NUMBER,
+                raw_format.0.into(),
+            );
+            sys::properties::SDL_SetNumberProperty(
+                props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_ACCESS_NUMBER,
+                (self.access as i32).into(),
+            );
+            sys::properties::SDL_SetNumberProperty(
+                props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_WIDTH_NUMBER,
+                w.into(),
+            );
+            sys::properties::SDL_SetNumberProperty(
+                props,
+                sys::render::SDL_PROP_TEXTURE_CREATE_HEIGHT_NUMBER,
+                h.into(),
+            );
+            if let Some(colorspace) = self.colorspace {
+                sys::properties::SDL_SetNumberProperty(
+                    props,
+                    sys::render::SDL_PROP_TEXTURE_CREATE_COLORSPACE_NUMBER,
+                    colorspace.0.into(),
+                );
+            }
+            if let Some(white_point) = self.sdr_white_point {
+                sys::properties::SDL_SetFloatProperty(
+                    props,
+                    sys::render::SDL_PROP_TEXTURE_CREATE_SDR_WHITE_POINT_FLOAT,
+                    white_point,
+                );
+            }
+            if let Some(headroom) = self.hdr_headroom {
+                sys::properties::SDL_SetFloatProperty(
+                    props,
+                    sys::render::SDL_PROP_TEXTURE_CREATE_HDR_HEADROOM_FLOAT,
+                    headroom,
+                );
+            }
+            let raw = sys::render::SDL_CreateTextureWithProperties(
+                self.texture_creator.context.raw(),
+                props,
+            );
+            sys::properties::SDL_DestroyProperties(props);
+            raw
+        };
+
+        if raw.is_null() {
+            Err(SdlError(get_error()))
+        } else {
+            Ok(raw)
+        }
+    }
+
+    /// Creates the texture.
+    #[cfg(not(feature = "unsafe_textures"))]
+    #[doc(alias = "SDL_CreateTextureWithProperties")]
+    pub fn build(self) -> Result<Texture<'a>, TextureValueError> {
+        let raw = self.create_raw()?;
+        let mut texture = unsafe { self.texture_creator.raw_create_texture(raw) };
+        self.texture_creator.apply_default_scale_mode(&mut texture);
+        Ok(texture)
+    }
+
+    /// Creates the texture.
+    #[cfg(feature = "unsafe_textures")]
+    #[doc(alias = "SDL_CreateTextureWithProperties")]
+    pub fn build(self) -> Result<Texture, TextureValueError> {
+        let raw = self.create_raw()?;
+        let mut texture = unsafe { self.texture_creator.raw_create_texture(raw) };
+        self.texture_creator.apply_default_scale_mode(&mut texture);
+        Ok(texture)
+    }
 }
 
 /// Drawing methods
@@ -1084,6 +1641,235 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Sets the color used for drawing operations (Rect, Line and Clear) with floating point
+    /// components, which may exceed 1.0 when rendering HDR content.
+    #[doc(alias = "SDL_SetRenderDrawColorFloat")]
+    pub fn set_draw_color_float<C: Into<pixels::FColor>>(&mut self, color: C) {
+        let (r, g, b, a) = color.into().rgba();
+        let ret = unsafe { sys::render::SDL_SetRenderDrawColorFloat(self.raw, r, g, b, a) };
+        // Should only fail on an invalid renderer
+        if !ret {
+            panic!("{}", get_error())
+        }
+    }
+
+    /// Gets the color used for drawing operations with floating point components.
+    #[doc(alias = "SDL_GetRenderDrawColorFloat")]
+    pub fn draw_color_float(&self) -> pixels::FColor {
+        let (mut r, mut g, mut b, mut a) = (0.0, 0.0, 0.0, 0.0);
+        let ret = unsafe {
+            sys::render::SDL_GetRenderDrawColorFloat(
+                self.context.raw,
+                &mut r,
+                &mut g,
+                &mut b,
+                &mut a,
+            )
+        };
+        // Should only fail on an invalid renderer
+        if !ret {
+            panic!("{}", get_error())
+        } else {
+            pixels::FColor::RGBA(r, g, b, a)
+        }
+    }
+
+    /// Sets the scale applied to every color when rendering, e.g. `sdr_white_point()` to
+    /// brighten SDR content on an HDR display. The default is 1.0.
+    #[doc(alias = "SDL_SetRenderColorScale")]
+    pub fn set_color_scale(&mut self, scale: f32) -> Result<(), Error> {
+        let ret = unsafe { sys::render::SDL_SetRenderColorScale(self.context.raw, scale) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Gets the scale applied to every color when rendering.
+    #[doc(alias = "SDL_GetRenderColorScale")]
+    pub fn color_scale(&self) -> Result<f32, Error> {
+        let mut scale = 0.0;
+        let ret = unsafe { sys::render::SDL_GetRenderColorScale(self.context.raw, &mut scale) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(scale)
+        }
+    }
+
+    /// Returns whether the renderer is outputting HDR content, which requires a
+    /// `RendererColorspace::SrgbLinear` output colorspace and an HDR display.
+    #[doc(alias = "SDL_PROP_RENDERER_HDR_ENABLED_BOOLEAN")]
+    pub fn hdr_enabled(&self) -> bool {
+        unsafe {
+            sys::properties::SDL_GetBooleanProperty(
+                sys::render::SDL_GetRendererProperties(self.context.raw),
+                sys::render::SDL_PROP_RENDERER_HDR_ENABLED_BOOLEAN,
+                false,
+            )
+        }
+    }
+
+    /// The value of SDR white in the linear colorspace, when HDR is enabled.
+    #[doc(alias = "SDL_PROP_RENDERER_SDR_WHITE_POINT_FLOAT")]
+    pub fn sdr_white_point(&self) -> f32 {
+        unsafe {
+            sys::properties::SDL_GetFloatProperty(
+                sys::render::SDL_GetRendererProperties(self.context.raw),
+                sys::render::SDL_PROP_RENDERER_SDR_WHITE_POINT_FLOAT,
+                1.0,
+            )
+        }
+    }
+
+    /// How far above SDR white the display can go, e.g. 4.0 if the brightest color is four
+    /// times as bright as SDR white. This is 1.0 without HDR.
+    #[doc(alias = "SDL_PROP_RENDERER_HDR_HEADROOM_FLOAT")]
+    pub fn hdr_headroom(&self) -> f32 {
+        unsafe {
+            sys::properties::SDL_GetFloatProperty(
+                sys::render::SDL_GetRendererProperties(self.context.raw),
+                sys::render::SDL_PROP_RENDERER_HDR_HEADROOM_FLOAT,
+                1.0,
+            )
+        }
+    }
+
+    fn number_property(&self, name: *const std::ffi::c_char) -> i64 {
+        unsafe {
+            sys::properties::SDL_GetNumberProperty(
+                sys::render::SDL_GetRendererProperties(self.context.raw),
+                name,
+                0,
+            )
+        }
+    }
+
+    fn pointer_property(&self, name: *const std::ffi::c_char) -> *mut std::ffi::c_void {
+        unsafe {
+            sys::properties::SDL_GetPointerProperty(
+                sys::render::SDL_GetRendererProperties(self.context.raw),
+                name,
+                ptr::null_mut(),
+            )
+        }
+    }
+
+    /// Yields the raw `SDL_GPUDevice` of a renderer created with the `"gpu"` driver, or null
+    /// for any other driver.
+    ///
+    /// This allows custom GPU work to share the device of the renderer, e.g. to create
+    /// textures for both. Mixing the renderer's output with custom shaders through
+    /// `SDL_GPURenderState` requires SDL 3.4 and is not available with the bundled bindings.
+    ///
+    /// The device is owned by the renderer and must not be destroyed.
+    #[doc(alias = "SDL_PROP_RENDERER_GPU_DEVICE_POINTER")]
+    pub fn raw_gpu_device(&self) -> *mut sys::gpu::SDL_GPUDevice {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_GPU_DEVICE_POINTER) as *mut _
+    }
+
+    /// Yields the `IDirect3DDevice9` of a `"direct3d"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D9_DEVICE_POINTER")]
+    pub fn raw_d3d9_device(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_D3D9_DEVICE_POINTER)
+    }
+
+    /// Yields the `ID3D11Device` of a `"direct3d11"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D11_DEVICE_POINTER")]
+    pub fn raw_d3d11_device(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_D3D11_DEVICE_POINTER)
+    }
+
+    /// Yields the `IDXGISwapChain1` of a `"direct3d11"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D11_SWAPCHAIN_POINTER")]
+    pub fn raw_d3d11_swapchain(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_D3D11_SWAPCHAIN_POINTER)
+    }
+
+    /// Yields the `ID3D12Device` of a `"direct3d12"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D12_DEVICE_POINTER")]
+    pub fn raw_d3d12_device(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_D3D12_DEVICE_POINTER)
+    }
+
+    /// Yields the `IDXGISwapChain4` of a `"direct3d12"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D12_SWAPCHAIN_POINTER")]
+    pub fn raw_d3d12_swapchain(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_D3D12_SWAPCHAIN_POINTER)
+    }
+
+    /// Yields the `ID3D12CommandQueue` of a `"direct3d12"` renderer, or null for any other
+    /// driver.
+    #[doc(alias = "SDL_PROP_RENDERER_D3D12_COMMAND_QUEUE_POINTER")]
+    pub fn raw_d3d12_command_queue(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_D3D12_COMMAND_QUEUE_POINTER)
+    }
+
+    /// Yields the `CAMetalLayer` of a `"metal"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_GetRenderMetalLayer")]
+    pub fn raw_metal_layer(&self) -> *mut std::ffi::c_void {
+        unsafe { sys::render::SDL_GetRenderMetalLayer(self.context.raw) }
+    }
+
+    /// Yields the `MTLRenderCommandEncoder` of a `"metal"` renderer for the current frame, or
+    /// null for any other driver.
+    ///
+    /// The renderer's pending commands are flushed first, so native commands recorded with the
+    /// encoder are ordered correctly. The encoder is only valid until the next render call.
+    #[doc(alias = "SDL_GetRenderMetalCommandEncoder")]
+    pub fn raw_metal_command_encoder(&mut self) -> *mut std::ffi::c_void {
+        unsafe { sys::render::SDL_GetRenderMetalCommandEncoder(self.context.raw) }
+    }
+
+    /// Yields the `VkInstance` of a `"vulkan"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_INSTANCE_POINTER")]
+    pub fn raw_vulkan_instance(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_VULKAN_INSTANCE_POINTER)
+    }
+
+    /// Yields the `VkPhysicalDevice` of a `"vulkan"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_PHYSICAL_DEVICE_POINTER")]
+    pub fn raw_vulkan_physical_device(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_VULKAN_PHYSICAL_DEVICE_POINTER)
+    }
+
+    /// Yields the `VkDevice` of a `"vulkan"` renderer, or null for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_DEVICE_POINTER")]
+    pub fn raw_vulkan_device(&self) -> *mut std::ffi::c_void {
+        self.pointer_property(sys::render::SDL_PROP_RENDERER_VULKAN_DEVICE_POINTER)
+    }
+
+    /// Yields the `VkSurfaceKHR` handle of a `"vulkan"` renderer, or 0 for any other driver.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_SURFACE_NUMBER")]
+    pub fn raw_vulkan_surface(&self) -> u64 {
+        self.number_property(sys::render::SDL_PROP_RENDERER_VULKAN_SURFACE_NUMBER) as u64
+    }
+
+    /// The queue family index used for rendering by a `"vulkan"` renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_GRAPHICS_QUEUE_FAMILY_INDEX_NUMBER")]
+    pub fn vulkan_graphics_queue_family_index(&self) -> u32 {
+        self.number_property(
+            sys::render::SDL_PROP_RENDERER_VULKAN_GRAPHICS_QUEUE_FAMILY_INDEX_NUMBER,
+        ) as u32
+    }
+
+    /// The queue family index used for presentation by a `"vulkan"` renderer.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_PRESENT_QUEUE_FAMILY_INDEX_NUMBER")]
+    pub fn vulkan_present_queue_family_index(&self) -> u32 {
+        self.number_property(
+            sys::render::SDL_PROP_RENDERER_VULKAN_PRESENT_QUEUE_FAMILY_INDEX_NUMBER,
+        ) as u32
+    }
+
+    /// The number of swapchain images of a `"vulkan"` renderer, i.e. the number of frames that
+    /// may be in flight at once.
+    #[doc(alias = "SDL_PROP_RENDERER_VULKAN_SWAPCHAIN_IMAGE_COUNT_NUMBER")]
+    pub fn vulkan_swapchain_image_count(&self) -> u32 {
+        self.number_property(sys::render::SDL_PROP_RENDERER_VULKAN_SWAPCHAIN_IMAGE_COUNT_NUMBER)
+            as u32
+    }
+
     /// Gets the color used for drawing operations (Rect, Line and Clear).
     #[doc(alias = "SDL_GetRenderDrawColor")]
     pub fn draw_color(&self) -> pixels::Color {
@@ -1167,18 +1953,26 @@ impl<T: RenderTarget> Canvas<T> {
     }
 
     /// Sets a device independent resolution for rendering.
+    ///
+    /// Everything is drawn at `width` x `height` and then scaled to the output according to
+    /// `mode`, e.g. `LogicalPresentation::IntegerScale` for crisp pixel art.
     #[doc(alias = "SDL_SetRenderLogicalPresentation")]
-    pub fn set_logical_size(
+    pub fn set_logical_size<M: Into<sys::render::SDL_RendererLogicalPresentation>>(
         &mut self,
         width: u32,
         height: u32,
-        mode: sys::render::SDL_RendererLogicalPresentation,
+        mode: M,
     ) -> Result<(), IntegerOrSdlError> {
         use crate::common::IntegerOrSdlError::*;
         let width = validate_int(width, "width")?;
         let height = validate_int(height, "height")?;
         let result = unsafe {
-            sys::render::SDL_SetRenderLogicalPresentation(self.context.raw, width, height, mode)
+            sys::render::SDL_SetRenderLogicalPresentation(
+                self.context.raw,
+                width,
+                height,
+                mode.into(),
+            )
         };
         match result {
             true => Ok(()),
@@ -1186,38 +1980,117 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
-    /// Gets device independent resolution for rendering.
-    #[doc(alias = "SDL_GetRenderLogicalPresentation")]
-    pub fn logical_size(&self) -> (u32, u32, sys::render::SDL_RendererLogicalPresentation) {
-        let mut width = 0;
-        let mut height = 0;
-        let mut mode: sys::render::SDL_RendererLogicalPresentation =
-            sys::render::SDL_LOGICAL_PRESENTATION_DISABLED;
-
-        unsafe {
-            sys::render::SDL_GetRenderLogicalPresentation(
+    /// Gets device independent resolution for rendering.
+    #[doc(alias = "SDL_GetRenderLogicalPresentation")]
+    pub fn logical_size(&self) -> (u32, u32, sys::render::SDL_RendererLogicalPresentation) {
+        let mut width = 0;
+        let mut height = 0;
+        let mut mode: sys::render::SDL_RendererLogicalPresentation =
+            sys::render::SDL_LOGICAL_PRESENTATION_DISABLED;
+
+        unsafe {
+            sys::render::SDL_GetRenderLogicalPresentation(
+                self.context.raw,
+                &mut width,
+                &mut height,
+                &mut mode,
+            )
+        };
+
+        (width as u32, height as u32, mode)
+    }
+
+    /// Gets the logical presentation mode set with `set_logical_size`.
+    #[doc(alias = "SDL_GetRenderLogicalPresentation")]
+    pub fn logical_presentation(&self) -> LogicalPresentation {
+        let (_, _, mode) = self.logical_size();
+        LogicalPresentation::try_from(mode).unwrap_or(LogicalPresentation::Disabled)
+    }
+
+    /// Gets the area of the output that the logical resolution is presented in, e.g. without
+    /// the letterbox bars.
+    #[doc(alias = "SDL_GetRenderLogicalPresentationRect")]
+    pub fn logical_presentation_rect(&self) -> Result<FRect, Error> {
+        let mut rect = MaybeUninit::uninit();
+        let ret = unsafe {
+            sys::render::SDL_GetRenderLogicalPresentationRect(self.context.raw, rect.as_mut_ptr())
+        };
+        if !ret {
+            Err(get_error())
+        } else {
+            let rect = unsafe { rect.assume_init() };
+            Ok(FRect::new(rect.x, rect.y, rect.w, rect.h))
+        }
+    }
+
+    /// Converts a point in window coordinates, e.g. from a mouse event, to render coordinates,
+    /// taking the logical presentation, viewport and scale into account.
+    #[doc(alias = "SDL_RenderCoordinatesFromWindow")]
+    pub fn render_coordinates_from_window(
+        &self,
+        window_x: f32,
+        window_y: f32,
+    ) -> Result<FPoint, Error> {
+        let (mut x, mut y) = (0.0, 0.0);
+        let ret = unsafe {
+            sys::render::SDL_RenderCoordinatesFromWindow(
                 self.context.raw,
-                &mut width,
-                &mut height,
-                &mut mode,
+                window_x,
+                window_y,
+                &mut x,
+                &mut y,
             )
         };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(FPoint::new(x, y))
+        }
+    }
 
-        (width as u32, height as u32, mode)
+    /// Converts a point in render coordinates to window coordinates, the inverse of
+    /// `render_coordinates_from_window`.
+    #[doc(alias = "SDL_RenderCoordinatesToWindow")]
+    pub fn render_coordinates_to_window(&self, x: f32, y: f32) -> Result<FPoint, Error> {
+        let (mut window_x, mut window_y) = (0.0, 0.0);
+        let ret = unsafe {
+            sys::render::SDL_RenderCoordinatesToWindow(
+                self.context.raw,
+                x,
+                y,
+                &mut window_x,
+                &mut window_y,
+            )
+        };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(FPoint::new(window_x, window_y))
+        }
     }
 
     /// Sets the drawing area for rendering on the current target.
+    ///
+    /// `None` resets the viewport to the entire target.
     #[doc(alias = "SDL_SetRenderViewport")]
-    pub fn set_viewport<R: Into<Option<Rect>>>(&mut self, rect: R) {
+    pub fn set_viewport<R: Into<Option<Rect>>>(&mut self, rect: R) -> Result<(), Error> {
         let rect = rect.into();
         // as_ref is important because we need rect to live until the end of the FFI call, but map_or consumes an Option<T>
         let ptr = rect.as_ref().map_or(ptr::null(), |rect| rect.raw());
         let ret = unsafe { sys::render::SDL_SetRenderViewport(self.context.raw, ptr) };
         if !ret {
-            panic!("Could not set viewport: {}", get_error())
+            Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
+    /// Returns whether an explicit viewport has been set on the current target.
+    #[doc(alias = "SDL_RenderViewportSet")]
+    pub fn viewport_set(&self) -> bool {
+        unsafe { sys::render::SDL_RenderViewportSet(self.context.raw) }
+    }
+
     /// Gets the drawing area for the current target.
     #[doc(alias = "SDL_GetRenderViewport")]
     pub fn viewport(&self) -> Rect {
@@ -1229,7 +2102,7 @@ impl<T: RenderTarget> Canvas<T> {
 
     /// Sets the clip rectangle for rendering on the specified target.
     #[doc(alias = "SDL_SetRenderClipRect")]
-    pub fn set_clip_rect<R>(&mut self, arg: R)
+    pub fn set_clip_rect<R>(&mut self, arg: R) -> Result<(), Error>
     where
         R: Into<ClippingRect>,
     {
@@ -1253,16 +2126,22 @@ impl<T: RenderTarget> Canvas<T> {
             },
         };
         if !ret {
-            panic!("Could not set clip rect: {}", get_error())
+            Err(get_error())
+        } else {
+            Ok(())
         }
     }
 
+    /// Returns whether clipping is enabled on the current target.
+    #[doc(alias = "SDL_RenderClipEnabled")]
+    pub fn clip_enabled(&self) -> bool {
+        unsafe { sys::render::SDL_RenderClipEnabled(self.context.raw) }
+    }
+
     /// Gets the clip rectangle for the current target.
     #[doc(alias = "SDL_GetRenderClipRect")]
     pub fn clip_rect(&self) -> ClippingRect {
-        let clip_enabled = unsafe { sdl3_sys::everything::SDL_RenderClipEnabled(self.context.raw) };
-
-        if !clip_enabled {
+        if !self.clip_enabled() {
             return ClippingRect::None;
         }
 
@@ -1297,6 +2176,31 @@ impl<T: RenderTarget> Canvas<T> {
         (scale_x, scale_y)
     }
 
+    /// Sets the vsync of the renderer. This can be changed at any time, e.g. from a settings menu.
+    ///
+    /// Errors if the driver doesn't support the requested mode, e.g. `RendererVSync::Adaptive`.
+    #[doc(alias = "SDL_SetRenderVSync")]
+    pub fn set_vsync<V: Into<RendererVSync>>(&mut self, vsync: V) -> Result<(), Error> {
+        let ret = unsafe { sys::render::SDL_SetRenderVSync(self.context.raw, vsync.into().into()) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Gets the vsync of the renderer.
+    #[doc(alias = "SDL_GetRenderVSync")]
+    pub fn vsync(&self) -> Result<RendererVSync, Error> {
+        let mut vsync = 0;
+        let ret = unsafe { sys::render::SDL_GetRenderVSync(self.context.raw, &mut vsync) };
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(RendererVSync::from(vsync))
+        }
+    }
+
     /// Draws a point on the current rendering target.
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderPoint")]
@@ -1318,7 +2222,7 @@ impl<T: RenderTarget> Canvas<T> {
         let result = unsafe {
             sys::render::SDL_RenderPoints(
                 self.context.raw,
-                points.as_ptr() as *const sys::rect::SDL_FPoint,
+                FPoint::raw_slice(points),
                 points.len() as c_int,
             )
         };
@@ -1329,6 +2233,32 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
+    /// Draws text with SDL's built-in 8x8 bitmap font, using the drawing color, with the
+    /// top-left corner of the first glyph at `point`.
+    ///
+    /// Meant for FPS counters and debug overlays: only ASCII is supported and the font can't
+    /// be changed, use SDL_ttf for real text. Use `format!` where C would call
+    /// `SDL_RenderDebugTextFormat`.
+    /// Errors if drawing fails for any reason (e.g. driver failure)
+    #[doc(alias = "SDL_RenderDebugText")]
+    #[doc(alias = "SDL_RenderDebugTextFormat")]
+    pub fn render_debug_text<P: Into<FPoint>>(
+        &mut self,
+        point: P,
+        text: &str,
+    ) -> Result<(), Error> {
+        let point = point.into();
+        let text = CString::new(text).map_err(|_| Error("text contains a nul byte".to_owned()))?;
+        let result = unsafe {
+            sys::render::SDL_RenderDebugText(self.context.raw, point.x, point.y, text.as_ptr())
+        };
+        if !result {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
     /// Draws a line on the current rendering target.
     /// Errors if drawing fails for any reason (e.g. driver failure)
     #[doc(alias = "SDL_RenderLine")]
@@ -1357,11 +2287,7 @@ impl<T: RenderTarget> Canvas<T> {
         let result = unsafe {
             sys::render::SDL_RenderLines(
                 self.context.raw,
-                points
-                    .iter()
-                    .map(|p| p.to_ll())
-                    .collect::<Vec<_>>()
-                    .as_ptr(),
+                FPoint::raw_slice(points),
                 points.len() as c_int,
             )
         };
@@ -1393,7 +2319,7 @@ impl<T: RenderTarget> Canvas<T> {
         let result = unsafe {
             sys::render::SDL_RenderRects(
                 self.context.raw,
-                rects.iter().map(|r| r.to_ll()).collect::<Vec<_>>().as_ptr(),
+                FRect::raw_slice(rects),
                 rects.len() as c_int,
             )
         };
@@ -1432,7 +2358,7 @@ impl<T: RenderTarget> Canvas<T> {
         let result = unsafe {
             sys::render::SDL_RenderFillRects(
                 self.context.raw,
-                rects.iter().map(|r| r.to_ll()).collect::<Vec<_>>().as_ptr(),
+                FRect::raw_slice(rects),
                 rects.len() as c_int,
             )
         };
@@ -1511,18 +2437,43 @@ impl<T: RenderTarget> Canvas<T> {
         R2: Into<Option<FRect>>,
         P: Into<Option<FPoint>>,
     {
-        let flip = unsafe {
-            match (flip_horizontal, flip_vertical) {
-                (false, false) => SDL_FLIP_NONE,
-                (true, false) => SDL_FLIP_HORIZONTAL,
-                (false, true) => SDL_FLIP_VERTICAL,
-                (true, true) => transmute::<u32, sys::surface::SDL_FlipMode>(
-                    transmute::<sys::surface::SDL_FlipMode, u32>(SDL_FLIP_HORIZONTAL)
-                        | transmute::<sys::surface::SDL_FlipMode, u32>(SDL_FLIP_VERTICAL),
-                ),
-            }
-        };
+        self.copy_rotated(
+            texture,
+            src,
+            dst,
+            angle,
+            center,
+            FlipMode::new(flip_horizontal, flip_vertical),
+        )
+    }
 
+    /// Copies a portion of the texture to the current rendering target,
+    /// rotating it clockwise by `angle` degrees around `center` and mirroring
+    /// it according to `flip`.
+    ///
+    /// * If `src` is `None`, the entire texture is copied.
+    /// * If `dst` is `None`, the texture will be stretched to fill the given
+    ///   rectangle.
+    /// * If `center` is `None`, rotation will be done around the center point
+    ///   of `dst`. `center` is relative to the top-left corner of `dst`.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTextureRotated")]
+    pub fn copy_rotated<R1, R2, P>(
+        &mut self,
+        texture: &Texture,
+        src: R1,
+        dst: R2,
+        angle: f64,
+        center: P,
+        flip: FlipMode,
+    ) -> Result<(), Error>
+    where
+        R1: Into<Option<FRect>>,
+        R2: Into<Option<FRect>>,
+        P: Into<Option<FPoint>>,
+    {
         let src = src.into().map(|rect| rect.to_ll());
         let dst = dst.into().map(|rect| rect.to_ll());
         let center = center.into().map(|point| point.to_ll());
@@ -1544,7 +2495,202 @@ impl<T: RenderTarget> Canvas<T> {
                     Some(ref point) => point,
                     None => ptr::null(),
                 },
-                flip,
+                flip.to_ll(),
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Tiles a portion of the texture over the destination rectangle, e.g. for a repeating
+    /// background.
+    ///
+    /// The tiles are `scale` times the size of `src`, starting at the top-left corner of `dst`
+    /// and clipped at its other edges.
+    ///
+    /// * If `src` is `None`, the entire texture is tiled.
+    /// * If `dst` is `None`, the entire rendering target is filled.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTextureTiled")]
+    pub fn copy_tiled<R1, R2>(
+        &mut self,
+        texture: &Texture,
+        src: R1,
+        scale: f32,
+        dst: R2,
+    ) -> Result<(), Error>
+    where
+        R1: Into<Option<FRect>>,
+        R2: Into<Option<FRect>>,
+    {
+        let src = src.into().map(|rect| rect.to_ll());
+        let dst = dst.into().map(|rect| rect.to_ll());
+
+        let ret = unsafe {
+            sys::render::SDL_RenderTextureTiled(
+                self.context.raw,
+                texture.raw,
+                match src {
+                    Some(ref rect) => rect,
+                    None => ptr::null(),
+                },
+                scale,
+                match dst {
+                    Some(ref rect) => rect,
+                    None => ptr::null(),
+                },
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Copies a portion of the texture to the current rendering target using the 9-grid
+    /// algorithm, e.g. for resizable UI panels.
+    ///
+    /// `src` is split into a 3x3 grid by the given border sizes. The corners are drawn
+    /// `scale` times their size without stretching, the edges are stretched along one axis and
+    /// the center is stretched to fill the rest of `dst`.
+    ///
+    /// * If `src` is `None`, the entire texture is used.
+    /// * If `dst` is `None`, the entire rendering target is filled.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderTexture9Grid")]
+    pub fn copy_9grid<R1, R2>(
+        &mut self,
+        texture: &Texture,
+        src: R1,
+        left_width: f32,
+        right_width: f32,
+        top_height: f32,
+        bottom_height: f32,
+        scale: f32,
+        dst: R2,
+    ) -> Result<(), Error>
+    where
+        R1: Into<Option<FRect>>,
+        R2: Into<Option<FRect>>,
+    {
+        let src = src.into().map(|rect| rect.to_ll());
+        let dst = dst.into().map(|rect| rect.to_ll());
+
+        let ret = unsafe {
+            sys::render::SDL_RenderTexture9Grid(
+                self.context.raw,
+                texture.raw,
+                match src {
+                    Some(ref rect) => rect,
+                    None => ptr::null(),
+                },
+                left_width,
+                right_width,
+                top_height,
+                bottom_height,
+                scale,
+                match dst {
+                    Some(ref rect) => rect,
+                    None => ptr::null(),
+                },
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Renders a list of triangles, optionally textured.
+    ///
+    /// Every three vertices form a triangle, or every three entries of `indices` if given.
+    /// Without a texture the triangles are filled with the interpolated vertex colors; with a
+    /// texture the colors modulate it.
+    ///
+    /// Errors if drawing fails for any reason (e.g. driver failure),
+    /// or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderGeometry")]
+    pub fn render_geometry(
+        &mut self,
+        vertices: &[Vertex],
+        texture: Option<&Texture>,
+        indices: Option<&[i32]>,
+    ) -> Result<(), Error> {
+        let vertices = vertices.iter().map(|v| v.to_ll()).collect::<Vec<_>>();
+        let ret = unsafe {
+            sys::render::SDL_RenderGeometry(
+                self.context.raw,
+                texture.map_or(ptr::null_mut(), |t| t.raw),
+                vertices.as_ptr(),
+                vertices.len() as c_int,
+                indices.map_or(ptr::null(), |i| i.as_ptr()),
+                indices.map_or(0, |i| i.len() as c_int),
+            )
+        };
+
+        if !ret {
+            Err(get_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Like [`Canvas::render_geometry`], but reads positions, colors and texture coordinates
+    /// from separate streams, so data kept as a struct of arrays can be drawn without
+    /// interleaving it first.
+    ///
+    /// `uv` is required when a texture is given. Indices may be 8, 16 or 32 bits wide; pass
+    /// `None::<&[u16]>` to draw the vertices in order.
+    ///
+    /// Errors if the streams have different lengths, if drawing fails for any reason
+    /// (e.g. driver failure), or if the provided texture does not belong to the renderer.
+    #[doc(alias = "SDL_RenderGeometryRaw")]
+    pub fn render_geometry_raw<I: GeometryIndex>(
+        &mut self,
+        texture: Option<&Texture>,
+        xy: GeometryStream<[f32; 2]>,
+        color: GeometryStream<[f32; 4]>,
+        uv: Option<GeometryStream<[f32; 2]>>,
+        indices: Option<&[I]>,
+    ) -> Result<(), Error> {
+        let num_vertices = xy.len();
+        if color.len() != num_vertices || uv.is_some_and(|uv| uv.len() != num_vertices) {
+            return Err(Error(
+                "render_geometry_raw: all vertex streams must have the same length".to_owned(),
+            ));
+        }
+        if texture.is_some() && uv.is_none() {
+            return Err(Error(
+                "render_geometry_raw: texture coordinates are required with a texture".to_owned(),
+            ));
+        }
+
+        let ret = unsafe {
+            sys::render::SDL_RenderGeometryRaw(
+                self.context.raw,
+                texture.map_or(ptr::null_mut(), |t| t.raw),
+                xy.ptr as *const f32,
+                xy.stride as c_int,
+                color.ptr as *const sys::pixels::SDL_FColor,
+                color.stride as c_int,
+                uv.map_or(ptr::null(), |uv| uv.ptr as *const f32),
+                uv.map_or(0, |uv| uv.stride as c_int),
+                num_vertices as c_int,
+                indices.map_or(ptr::null(), |i| i.as_ptr() as *const libc::c_void),
+                indices.map_or(0, |i| i.len() as c_int),
+                mem::size_of::<I>() as c_int,
             )
         };
 
@@ -1555,23 +2701,32 @@ impl<T: RenderTarget> Canvas<T> {
         }
     }
 
-    /// Reads pixels from the current rendering target.
+    /// Reads pixels from the current rendering target into a new `Surface`, e.g. to take a
+    /// screenshot or to compare the output against a reference image in a test.
+    ///
+    /// * If `rect` is `None`, the entire viewport is read.
+    ///
+    /// The surface is in the format of the target. Call this after drawing but before
+    /// `present`, as the contents of the backbuffer are undefined afterwards.
+    ///
+    /// ```no_run
+    /// # use sdl3::render::Canvas;
+    /// # use sdl3::video::Window;
+    /// # let mut canvas: Canvas<Window> = unimplemented!();
+    /// // ... draw the frame ...
+    /// let screenshot = canvas.read_pixels(None).unwrap();
+    /// screenshot.save_bmp("screenshot.bmp").unwrap();
+    /// canvas.present();
+    /// ```
     /// # Remarks
     /// WARNING: This is a very slow operation, and should not be used frequently.
     #[doc(alias = "SDL_RenderReadPixels")]
-    pub fn read_pixels<R: Into<Option<Rect>>>(
-        &self,
-        rect: R,
-        // format: pixels::PixelFormat,
-    ) -> Result<Surface, Error> {
+    pub fn read_pixels<R: Into<Option<Rect>>>(&self, rect: R) -> Result<Surface<'static>, Error> {
         unsafe {
             let rect = rect.into();
-            let (actual_rect, _w, _h) = match rect {
-                Some(ref rect) => (rect.raw(), rect.width() as usize, rect.height() as usize),
-                None => {
-                    let (w, h) = self.output_size()?;
-                    (ptr::null(), w as usize, h as usize)
-                }
+            let actual_rect = match rect {
+                Some(ref rect) => rect.raw(),
+                None => ptr::null(),
             };
 
             let surface_ptr = sys::render::SDL_RenderReadPixels(self.context.raw, actual_rect);
@@ -2163,6 +3318,35 @@ impl InternalTexture {
         }
     }
 
+    pub fn update_from_surface<P>(
+        &mut self,
+        position: P,
+        surface: &SurfaceRef,
+    ) -> Result<(), UpdateTextureError>
+    where
+        P: Into<Point>,
+    {
+        let format = self.get_format();
+        let converted;
+        let surface = if surface.pixel_format() == format {
+            surface
+        } else {
+            converted = surface
+                .convert_format(format)
+                .map_err(UpdateTextureError::SdlError)?;
+            &*converted
+        };
+        let position = position.into();
+        let rect = Rect::new(
+            position.x(),
+            position.y(),
+            surface.width(),
+            surface.height(),
+        );
+        let pitch = surface.pitch() as usize;
+        surface.with_lock(|pixels| self.update(rect, pixels, pitch))
+    }
+
     #[doc(alias = "SDL_UpdateYUVTexture")]
     pub fn update_yuv<R>(
         &mut self,
@@ -2461,6 +3645,22 @@ impl Texture<'_> {
         InternalTexture { raw: self.raw }.update(rect, pixel_data, pitch)
     }
 
+    /// Copies the pixels of `surface` into the texture with its top-left corner at `position`.
+    ///
+    /// The surface is converted to the texture's pixel format first if they differ, so
+    /// e.g. decoded video frames can be uploaded every frame without any unsafe code.
+    #[inline]
+    pub fn update_from_surface<P>(
+        &mut self,
+        position: P,
+        surface: &SurfaceRef,
+    ) -> Result<(), UpdateTextureError>
+    where
+        P: Into<Point>,
+    {
+        InternalTexture { raw: self.raw }.update_from_surface(position, surface)
+    }
+
     /// Updates a rectangle within a planar YV12 or IYUV texture with new pixel data.
     #[inline]
     pub fn update_yuv<R>(
@@ -2653,6 +3853,18 @@ impl Texture {
         InternalTexture { raw: self.raw }.blend_mode()
     }
 
+    /// Sets the scale mode for use when rendered.
+    #[inline]
+    pub fn set_scale_mode(&mut self, scale: ScaleMode) {
+        InternalTexture { raw: self.raw }.set_scale_mode(scale)
+    }
+
+    /// Gets the scale mode for use when rendered.
+    #[inline]
+    pub fn scale_mode(&self) -> ScaleMode {
+        InternalTexture { raw: self.raw }.scale_mode()
+    }
+
     /// Updates the given texture rectangle with new pixel data.
     ///
     /// `pitch` is the number of bytes in a row of pixel data, including padding
@@ -2672,6 +3884,22 @@ impl Texture {
         InternalTexture { raw: self.raw }.update(rect, pixel_data, pitch)
     }
 
+    /// Copies the pixels of `surface` into the texture with its top-left corner at `position`.
+    ///
+    /// The surface is converted to the texture's pixel format first if they differ, so
+    /// e.g. decoded video frames can be uploaded every frame without any unsafe code.
+    #[inline]
+    pub fn update_from_surface<P>(
+        &mut self,
+        position: P,
+        surface: &SurfaceRef,
+    ) -> Result<(), UpdateTextureError>
+    where
+        P: Into<Point>,
+    {
+        InternalTexture { raw: self.raw }.update_from_surface(position, surface)
+    }
+
     /// Updates a rectangle within a planar YV12 or IYUV texture with new pixel data.
     #[inline]
     pub fn update_yuv<R>(
@@ -1,11 +1,13 @@
-use libc::c_char;
+use libc::{c_char, c_void};
 use std::cell::Cell;
 use std::error;
 use std::ffi::{CStr, CString, NulError};
 use std::fmt;
 use std::marker::PhantomData;
 use std::mem;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::thread;
 use sys::init::{
     SDL_INIT_AUDIO, SDL_INIT_CAMERA, SDL_INIT_EVENTS, SDL_INIT_GAMEPAD, SDL_INIT_HAPTIC,
     SDL_INIT_JOYSTICK, SDL_INIT_SENSOR, SDL_INIT_VIDEO,
@@ -153,6 +155,9 @@ impl Sdl {
     /// At most one `EventPump` is allowed to be alive during the program's execution.
     /// If this function is called while an `EventPump` instance is alive, the function will return
     /// an error.
+    ///
+    /// Code that doesn't own the `EventPump` can still read queued events with
+    /// [`EventSubsystem::poll_queued_event`].
     #[inline]
     pub fn event_pump(&self) -> Result<EventPump, Error> {
         EventPump::new(self)
@@ -410,3 +415,85 @@ pub fn clear_error() {
         sys::error::SDL_ClearError();
     }
 }
+
+/// Returns whether the current thread is the main thread, the one windows must be created and
+/// events pumped on.
+#[doc(alias = "SDL_IsMainThread")]
+pub fn is_main_thread() -> bool {
+    unsafe { sys::init::SDL_IsMainThread() }
+}
+
+/// Runs `callback` on the main thread and waits for its result, e.g. so a loader thread can
+/// create GPU resources or change a window.
+///
+/// The callback runs during the main thread's next event pump, or right away if this is
+/// called on the main thread. Calling this from another thread while the main thread waits for
+/// that thread deadlocks. If the callback panics, the panic is resumed on the calling thread.
+///
+/// # Example
+/// ```no_run
+/// let sdl_context = sdl3::init().unwrap();
+/// let _event_pump = sdl_context.event_pump().unwrap();
+///
+/// std::thread::spawn(|| {
+///     let on_main = sdl3::run_on_main_thread(sdl3::is_main_thread).unwrap();
+///     assert!(on_main);
+/// });
+/// ```
+#[doc(alias = "SDL_RunOnMainThread")]
+pub fn run_on_main_thread<F, R>(callback: F) -> Result<R, Error>
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    type Call<F, R> = (Option<F>, Option<thread::Result<R>>);
+
+    unsafe extern "C" fn run<F: FnOnce() -> R, R>(userdata: *mut c_void) {
+        let (callback, result) = &mut *(userdata as *mut Call<F, R>);
+        if let Some(callback) = callback.take() {
+            // panics must not unwind into SDL, hand them to the calling thread instead
+            *result = Some(panic::catch_unwind(AssertUnwindSafe(callback)));
+        }
+    }
+
+    let mut call: Call<F, R> = (Some(callback), None);
+    let ok = unsafe {
+        sys::init::SDL_RunOnMainThread(
+            Some(run::<F, R>),
+            &mut call as *mut Call<F, R> as *mut c_void,
+            true,
+        )
+    };
+    match call.1 {
+        Some(Err(payload)) => panic::resume_unwind(payload),
+        Some(Ok(result)) if ok => Ok(result),
+        _ => Err(get_error()),
+    }
+}
+
+/// Queues `callback` to run on the main thread during its next event pump, without waiting
+/// for it.
+///
+/// Callbacks still queued when SDL shuts down are never run, and are leaked together with
+/// everything they captured, as SDL discards them without telling the crate. A panic in the
+/// callback is caught and discarded after the panic hook has reported it.
+#[doc(alias = "SDL_RunOnMainThread")]
+pub fn spawn_on_main_thread<F>(callback: F) -> Result<(), Error>
+where
+    F: FnOnce() + Send + 'static,
+{
+    unsafe extern "C" fn run(userdata: *mut c_void) {
+        let callback = Box::from_raw(userdata as *mut Box<dyn FnOnce() + Send>);
+        // panics must not unwind into SDL, and there is nobody waiting to receive them
+        let _ = panic::catch_unwind(AssertUnwindSafe(callback));
+    }
+
+    let callback: Box<Box<dyn FnOnce() + Send>> = Box::new(Box::new(callback));
+    let userdata = Box::into_raw(callback) as *mut c_void;
+    if unsafe { sys::init::SDL_RunOnMainThread(Some(run), userdata, false) } {
+        Ok(())
+    } else {
+        drop(unsafe { Box::from_raw(userdata as *mut Box<dyn FnOnce() + Send>) });
+        Err(get_error())
+    }
+}
@@ -0,0 +1,217 @@
+//! Fire-and-forget sound playback, enabled with the `sound` feature.
+//!
+//! A small layer over [`AudioStream`]s for games that just need to play sound effects: load
+//! [`Sound`]s once, then [`SoundPlayer::play`] them as often as needed. Each playing sound takes
+//! one voice from a fixed pool; when every voice is busy, the sound that started first is cut
+//! off. Anything more involved, such as music streaming or effects, is better built on
+//! [`AudioStream`] directly.
+//!
+//! # Example
+//! ```no_run
+//! use sdl3::sound::{Sound, SoundPlayer};
+//!
+//! let sdl_context = sdl3::init().unwrap();
+//! let audio_subsystem = sdl_context.audio().unwrap();
+//! let mut player = SoundPlayer::new(&audio_subsystem, 8).unwrap();
+//!
+//! let click = Sound::load_wav("click.wav").unwrap();
+//! player.play(&click).unwrap();
+//! player.play_with_volume(&click, 0.5).unwrap();
+//! ```
+
+use crate::audio::{AudioDevice, AudioFormat, AudioSpec, AudioSpecWAV, AudioStream};
+use crate::AudioSubsystem;
+use crate::Error;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Samples in memory along with their format, cheap to clone and play any number of times.
+#[derive(Clone, Debug)]
+pub struct Sound {
+    spec: AudioSpec,
+    data: Arc<[u8]>,
+}
+
+impl Sound {
+    /// Creates a sound from interleaved samples in the format of `spec`, which needs all its
+    /// fields set.
+    pub fn new(spec: AudioSpec, data: Vec<u8>) -> Sound {
+        Sound {
+            spec,
+            data: data.into(),
+        }
+    }
+
+    /// Loads a sound from a WAVE file.
+    pub fn load_wav<P: AsRef<Path>>(path: P) -> Result<Sound, Error> {
+        AudioSpecWAV::load_wav(path).map(|wav| Sound::from(&wav))
+    }
+
+    /// The format of the samples.
+    pub fn spec(&self) -> &AudioSpec {
+        &self.spec
+    }
+
+    /// The interleaved samples as bytes.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl From<&AudioSpecWAV> for Sound {
+    fn from(wav: &AudioSpecWAV) -> Sound {
+        Sound::new(wav.spec(), wav.buffer().to_vec())
+    }
+}
+
+/// Plays [`Sound`]s on the default playback device using a fixed pool of voices.
+pub struct SoundPlayer {
+    voices: Vec<Voice>,
+    next_start: u64,
+    // declared last so the streams are unbound before the device closes
+    device: AudioDevice,
+}
+
+struct Voice {
+    stream: AudioStream,
+    // orders voices by when they last started playing, to cut off the oldest
+    started: u64,
+}
+
+impl SoundPlayer {
+    /// Opens the default playback device with `voices` voices, the number of sounds that can
+    /// play at the same time.
+    ///
+    /// The device follows the system's default output, so playback continues when the user
+    /// switches headphones or speakers.
+    ///
+    /// Errors if `voices` is 0.
+    pub fn new(audio_subsystem: &AudioSubsystem, voices: usize) -> Result<SoundPlayer, Error> {
+        if voices == 0 {
+            return Err(Error("A sound player needs at least one voice".to_owned()));
+        }
+
+        // only a hint, SDL mixes into whatever format the hardware uses
+        let spec = AudioSpec::new(Some(48000), Some(2), Some(AudioFormat::f32_sys()));
+        let device = audio_subsystem.open_playback_device(&spec)?;
+        let voices = (0..voices)
+            .map(|_| {
+                let stream = audio_subsystem.new_stream(Some(&spec), None)?;
+                device.bind_stream(&stream)?;
+                Ok(Voice { stream, started: 0 })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(SoundPlayer {
+            voices,
+            next_start: 1,
+            device,
+        })
+    }
+
+    /// Plays `sound` at its full volume.
+    pub fn play(&mut self, sound: &Sound) -> Result<(), Error> {
+        self.play_with_volume(sound, 1.0)
+    }
+
+    /// Plays `sound` with its volume scaled by `volume`, where `1.0` is unchanged.
+    pub fn play_with_volume(&mut self, sound: &Sound, volume: f32) -> Result<(), Error> {
+        let started = self.next_start;
+        self.next_start += 1;
+        let voice = self.free_voice();
+        voice.started = started;
+
+        let stream = &voice.stream;
+        stream.clear()?;
+        stream.set_format(Some(&sound.spec), None)?;
+        stream.set_gain(volume)?;
+        stream.put_data(&sound.data)?;
+        // the whole sound is queued, so play out its last partial frame too
+        stream.flush()
+    }
+
+    /// Stops every sound that is playing.
+    pub fn stop_all(&mut self) -> Result<(), Error> {
+        for voice in &mut self.voices {
+            voice.stream.clear()?;
+            voice.started = 0;
+        }
+        Ok(())
+    }
+
+    /// Sets the volume of everything played, e.g. for a master volume slider, where `1.0` is
+    /// unchanged.
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        self.device.set_gain(volume)
+    }
+
+    /// Pauses playback of every sound, e.g. while the game is paused.
+    pub fn pause(&self) -> bool {
+        self.device.pause()
+    }
+
+    /// Resumes playback after [`pause`](Self::pause).
+    pub fn resume(&self) -> bool {
+        self.device.resume()
+    }
+
+    fn free_voice(&mut self) -> &mut Voice {
+        let index = pick_voice(self.voices.iter().map(|voice| {
+            let idle = voice
+                .stream
+                .available_bytes()
+                .map_or(true, |bytes| bytes == 0);
+            (idle, voice.started)
+        }));
+        &mut self.voices[index]
+    }
+}
+
+/// Returns the index of the first idle voice or, when every voice is busy, of the one that
+/// started first. `voices` yields whether each voice is idle and when it started, and is only
+/// consumed up to the first idle voice.
+fn pick_voice(voices: impl Iterator<Item = (bool, u64)>) -> usize {
+    let mut oldest: Option<(usize, u64)> = None;
+    for (index, (idle, started)) in voices.enumerate() {
+        if idle {
+            return index;
+        }
+        if oldest.is_none_or(|(_, oldest_started)| started < oldest_started) {
+            oldest = Some((index, started));
+        }
+    }
+    oldest.map_or(0, |(index, _)| index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::pick_voice;
+
+    #[test]
+    fn picks_first_idle_voice() {
+        let voices = [(false, 3), (true, 1), (true, 2)];
+        assert_eq!(pick_voice(voices.into_iter()), 1);
+    }
+
+    #[test]
+    fn steals_oldest_busy_voice() {
+        let voices = [(false, 5), (false, 2), (false, 7)];
+        assert_eq!(pick_voice(voices.into_iter()), 1);
+    }
+
+    #[test]
+    fn steals_first_of_equally_old_voices() {
+        let voices = [(false, 4), (false, 4)];
+        assert_eq!(pick_voice(voices.into_iter()), 0);
+    }
+
+    #[test]
+    fn stops_at_first_idle_voice() {
+        let mut checked = 0;
+        let voices = [(false, 1), (true, 2), (false, 3)]
+            .into_iter()
+            .inspect(|_| checked += 1);
+        assert_eq!(pick_voice(voices), 1);
+        assert_eq!(checked, 2);
+    }
+}
@@ -19,6 +19,43 @@ use std::ptr;
 use sys::blendmode::SDL_BLENDMODE_NONE;
 use sys::surface::{SDL_ScaleMode, SDL_MUSTLOCK, SDL_SCALEMODE_LINEAR};
 
+/// How an image is mirrored when it is drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum FlipMode {
+    /// Do not flip.
+    #[default]
+    None,
+    /// Flip left-right.
+    Horizontal,
+    /// Flip top-bottom.
+    Vertical,
+    /// Flip left-right and top-bottom, the same as rotating by 180 degrees.
+    Both,
+}
+
+impl FlipMode {
+    /// Picks the flip mode from separate horizontal and vertical flags.
+    pub fn new(horizontal: bool, vertical: bool) -> FlipMode {
+        match (horizontal, vertical) {
+            (false, false) => FlipMode::None,
+            (true, false) => FlipMode::Horizontal,
+            (false, true) => FlipMode::Vertical,
+            (true, true) => FlipMode::Both,
+        }
+    }
+
+    pub fn to_ll(self) -> sys::surface::SDL_FlipMode {
+        match self {
+            FlipMode::None => sys::surface::SDL_FLIP_NONE,
+            FlipMode::Horizontal => sys::surface::SDL_FLIP_HORIZONTAL,
+            FlipMode::Vertical => sys::surface::SDL_FLIP_VERTICAL,
+            FlipMode::Both => sys::surface::SDL_FlipMode(
+                sys::surface::SDL_FLIP_HORIZONTAL.0 | sys::surface::SDL_FLIP_VERTICAL.0,
+            ),
+        }
+    }
+}
+
 /// Holds a `SDL_Surface`
 ///
 /// When the `SurfaceContext` is dropped, it frees the `SDL_Surface`
@@ -613,6 +650,29 @@ impl SurfaceRef {
         }
     }
 
+    /// Adds a version of the image at another size, e.g. a 64x64 icon next to a 32x32 one.
+    ///
+    /// SDL picks the best-fitting image for the display scale when the surface is used as a
+    /// window icon or cursor. The surface keeps its own reference to `image`.
+    #[doc(alias = "SDL_AddSurfaceAlternateImage")]
+    pub fn add_alternate_image(&mut self, image: &SurfaceRef) -> Result<(), Error> {
+        if unsafe { sys::surface::SDL_AddSurfaceAlternateImage(self.raw(), image.raw()) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    #[doc(alias = "SDL_SurfaceHasAlternateImages")]
+    pub fn has_alternate_images(&self) -> bool {
+        unsafe { sys::surface::SDL_SurfaceHasAlternateImages(self.raw()) }
+    }
+
+    #[doc(alias = "SDL_RemoveSurfaceAlternateImages")]
+    pub fn remove_alternate_images(&mut self) {
+        unsafe { sys::surface::SDL_RemoveSurfaceAlternateImages(self.raw()) }
+    }
+
     /// Sets the clip rectangle for the surface.
     ///
     /// If the rectangle is `None`, clipping will be disabled.
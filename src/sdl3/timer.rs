@@ -45,6 +45,19 @@ pub fn delay(ms: u32) {
     unsafe { sys::timer::SDL_Delay(ms) }
 }
 
+/// Gets the number of nanoseconds elapsed since the timer subsystem was initialized.
+#[doc(alias = "SDL_GetTicksNS")]
+pub fn ticks_ns() -> u64 {
+    unsafe { sys::timer::SDL_GetTicksNS() }
+}
+
+/// Sleeps the current thread for the specified amount of nanoseconds, busy-waiting for the
+/// last stretch to be as accurate as possible.
+#[doc(alias = "SDL_DelayPrecise")]
+pub fn delay_precise(ns: u64) {
+    unsafe { sys::timer::SDL_DelayPrecise(ns) }
+}
+
 #[doc(alias = "SDL_GetPerformanceCounter")]
 pub fn performance_counter() -> u64 {
     unsafe { sys::timer::SDL_GetPerformanceCounter() }
@@ -55,6 +68,71 @@ pub fn performance_frequency() -> u64 {
     unsafe { sys::timer::SDL_GetPerformanceFrequency() }
 }
 
+/// Keeps a loop running at a fixed frame rate.
+///
+/// Call [`wait`](Self::wait) once per frame, e.g. right after `Canvas::present` or after
+/// submitting the GPU command buffer with the swapchain texture. Deadlines are scheduled from the
+/// previous deadline rather than from when `wait` returned, so oversleeping in one frame is made
+/// up in the next and the average rate doesn't drift. After falling more than a frame behind,
+/// e.g. after a loading stall, the schedule restarts instead of rushing to catch up.
+///
+/// ```no_run
+/// let mut pacer = sdl3::timer::FramePacer::new(60.0);
+/// loop {
+///     // ... update and draw ...
+///     pacer.wait();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FramePacer {
+    frame_ns: u64,
+    deadline: Option<u64>,
+}
+
+impl FramePacer {
+    /// Creates a pacer targeting `fps` frames per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` is not positive.
+    pub fn new(fps: f64) -> FramePacer {
+        assert!(fps > 0.0, "frame rate must be positive");
+        FramePacer {
+            frame_ns: (1_000_000_000.0 / fps) as u64,
+            deadline: None,
+        }
+    }
+
+    /// The target duration of a frame in nanoseconds.
+    pub fn frame_ns(&self) -> u64 {
+        self.frame_ns
+    }
+
+    /// Sleeps until the current frame is due to end. Returns the number of nanoseconds slept.
+    pub fn wait(&mut self) -> u64 {
+        let delay = self.advance(ticks_ns());
+        if delay > 0 {
+            delay_precise(delay);
+        }
+        delay
+    }
+
+    /// Restarts the schedule, e.g. after the loop was paused.
+    pub fn reset(&mut self) {
+        self.deadline = None;
+    }
+
+    /// Schedules the next deadline and returns how long to sleep from `now` to reach the current one.
+    fn advance(&mut self, now: u64) -> u64 {
+        let deadline = match self.deadline {
+            Some(deadline) if now <= deadline + self.frame_ns => deadline,
+            _ => now,
+        };
+        self.deadline = Some(deadline + self.frame_ns);
+        deadline.saturating_sub(now)
+    }
+}
+
 /// Type alias for the timer callback function.
 pub type TimerCallback = Box<dyn FnMut() -> u32 + Send + 'static>;
 
@@ -108,7 +186,26 @@ mod test {
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
-    use crate::timer::add_timer;
+    use crate::timer::{add_timer, FramePacer};
+
+    #[test]
+    fn test_frame_pacer_corrects_drift() {
+        let mut pacer = FramePacer::new(100.0);
+        assert_eq!(pacer.advance(0), 0);
+        assert_eq!(pacer.advance(2_000_000), 8_000_000);
+        // Finished the frame 1ms late, the next one is shortened to make up for it.
+        assert_eq!(pacer.advance(21_000_000), 0);
+        assert_eq!(pacer.advance(25_000_000), 5_000_000);
+    }
+
+    #[test]
+    fn test_frame_pacer_restarts_when_behind() {
+        let mut pacer = FramePacer::new(100.0);
+        assert_eq!(pacer.advance(0), 0);
+        // A long stall restarts the schedule instead of skipping every wait to catch up.
+        assert_eq!(pacer.advance(100_000_000), 0);
+        assert_eq!(pacer.advance(101_000_000), 9_000_000);
+    }
 
     #[test]
     fn test_timer_runs_multiple_times() {
@@ -2,30 +2,31 @@ use crate::common::{validate_int, IntegerOrSdlError};
 use crate::get_error;
 use crate::pixels::PixelFormat;
 use crate::properties::{Properties, PropertiesError};
-use crate::rect::Rect;
-use crate::render::{create_renderer, WindowCanvas};
+use crate::rect::{Point, Rect};
+use crate::render::{create_renderer, FPoint, RendererVSync, WindowCanvas};
 use crate::surface::SurfaceRef;
 use crate::Error;
 use crate::EventPump;
 use crate::VideoSubsystem;
-use libc::{c_char, c_int, c_uint, c_void};
+use libc::{c_char, c_int, c_void};
 use std::convert::TryFrom;
 use std::error;
 use std::ffi::{CStr, CString, NulError};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::ptr::{null, null_mut};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
 use std::{fmt, mem, ptr};
 use sys::properties::{
-    SDL_CreateProperties, SDL_DestroyProperties, SDL_SetNumberProperty, SDL_SetStringProperty,
+    SDL_CreateProperties, SDL_DestroyProperties, SDL_SetNumberProperty, SDL_SetPointerProperty,
+    SDL_SetStringProperty,
 };
 use sys::stdinc::{SDL_FunctionPointer, SDL_free, Uint32, Uint64};
 use sys::video::{
-    SDL_DisplayID, SDL_DisplayMode, SDL_DisplayModeData, SDL_DisplayOrientation,
-    SDL_GetSystemTheme, SDL_WindowFlags, SDL_SYSTEM_THEME_DARK, SDL_SYSTEM_THEME_LIGHT,
-    SDL_SYSTEM_THEME_UNKNOWN,
+    SDL_DisplayID, SDL_DisplayMode, SDL_DisplayModeData, SDL_GetSystemTheme, SDL_WindowFlags,
+    SDL_SYSTEM_THEME_DARK, SDL_SYSTEM_THEME_LIGHT, SDL_SYSTEM_THEME_UNKNOWN,
 };
 
 use crate::sys;
@@ -285,6 +286,16 @@ pub mod gl_attr {
             SHARE_WITH_CURRENT_CONTEXT, set_share_with_current_context, share_with_current_context, bool, "OpenGL context sharing; defaults to false";
             FRAMEBUFFER_SRGB_CAPABLE, set_framebuffer_srgb_compatible, framebuffer_srgb_compatible, bool, "requests sRGB capable visual; defaults to false (>= SDL 2.0.1)";
             CONTEXT_NO_ERROR, set_context_no_error, context_no_error, bool, "disables OpenGL error checking; defaults to false (>= SDL 2.0.6)";
+            FLOATBUFFERS, set_float_buffers, float_buffers, bool, "requests a floating point color buffer; defaults to false";
+        }
+
+        /// Resets every attribute to its default value.
+        #[doc(alias = "SDL_GL_ResetAttributes")]
+        pub fn reset(&self) {
+            // SDL forgets the EGL attribute callbacks too, so they can be freed
+            super::replace_egl_attribute_callbacks(None, || unsafe {
+                sys::video::SDL_GL_ResetAttributes()
+            });
         }
 
         /// **Sets** the OpenGL context major and minor versions.
@@ -545,6 +556,10 @@ pub struct WindowContext {
     subsystem: VideoSubsystem,
     raw: *mut sys::video::SDL_Window,
     pub(crate) metal_view: sys::metal::SDL_MetalView,
+    /// Popup and child windows are destroyed along with their parent, so they keep it alive.
+    parent: Mutex<Option<Arc<WindowContext>>>,
+    /// Freed after the window is destroyed, so SDL never calls into a dropped closure.
+    hit_test: Mutex<Option<Box<HitTestCallback>>>,
 }
 
 impl Drop for WindowContext {
@@ -572,6 +587,8 @@ impl WindowContext {
             subsystem: subsystem.clone(),
             raw,
             metal_view,
+            parent: Mutex::new(None),
+            hit_test: Mutex::new(None),
         }
     }
 }
@@ -580,8 +597,13 @@ impl WindowContext {
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(i32)]
 pub enum SwapInterval {
+    /// Swap buffers immediately, without waiting for the vertical retrace
     Immediate = 0,
+    /// Wait for the vertical retrace before swapping buffers
     VSync = 1,
+    /// Adaptive vsync: wait for the vertical retrace unless the frame is late, in which case
+    /// swap immediately and tear instead of stalling a whole frame. Not supported everywhere;
+    /// fall back to `VSync` if setting it fails.
     LateSwapTearing = -1,
 }
 
@@ -669,6 +691,208 @@ impl FlashOperation {
     }
 }
 
+/// What a point in a window does when clicked, as decided by a hit-test callback.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum HitTestResult {
+    /// The point behaves normally
+    Normal = sys::video::SDL_HitTestResult::NORMAL.0,
+    /// Dragging the point moves the window
+    Draggable = sys::video::SDL_HitTestResult::DRAGGABLE.0,
+    /// Dragging the point resizes the window from its top-left corner
+    ResizeTopLeft = sys::video::SDL_HitTestResult::RESIZE_TOPLEFT.0,
+    /// Dragging the point resizes the window from its top border
+    ResizeTop = sys::video::SDL_HitTestResult::RESIZE_TOP.0,
+    /// Dragging the point resizes the window from its top-right corner
+    ResizeTopRight = sys::video::SDL_HitTestResult::RESIZE_TOPRIGHT.0,
+    /// Dragging the point resizes the window from its right border
+    ResizeRight = sys::video::SDL_HitTestResult::RESIZE_RIGHT.0,
+    /// Dragging the point resizes the window from its bottom-right corner
+    ResizeBottomRight = sys::video::SDL_HitTestResult::RESIZE_BOTTOMRIGHT.0,
+    /// Dragging the point resizes the window from its bottom border
+    ResizeBottom = sys::video::SDL_HitTestResult::RESIZE_BOTTOM.0,
+    /// Dragging the point resizes the window from its bottom-left corner
+    ResizeBottomLeft = sys::video::SDL_HitTestResult::RESIZE_BOTTOMLEFT.0,
+    /// Dragging the point resizes the window from its left border
+    ResizeLeft = sys::video::SDL_HitTestResult::RESIZE_LEFT.0,
+}
+
+impl HitTestResult {
+    pub fn from_ll(result: sys::video::SDL_HitTestResult) -> HitTestResult {
+        match result {
+            sys::video::SDL_HITTEST_DRAGGABLE => HitTestResult::Draggable,
+            sys::video::SDL_HITTEST_RESIZE_TOPLEFT => HitTestResult::ResizeTopLeft,
+            sys::video::SDL_HITTEST_RESIZE_TOP => HitTestResult::ResizeTop,
+            sys::video::SDL_HITTEST_RESIZE_TOPRIGHT => HitTestResult::ResizeTopRight,
+            sys::video::SDL_HITTEST_RESIZE_RIGHT => HitTestResult::ResizeRight,
+            sys::video::SDL_HITTEST_RESIZE_BOTTOMRIGHT => HitTestResult::ResizeBottomRight,
+            sys::video::SDL_HITTEST_RESIZE_BOTTOM => HitTestResult::ResizeBottom,
+            sys::video::SDL_HITTEST_RESIZE_BOTTOMLEFT => HitTestResult::ResizeBottomLeft,
+            sys::video::SDL_HITTEST_RESIZE_LEFT => HitTestResult::ResizeLeft,
+            _ => HitTestResult::Normal,
+        }
+    }
+
+    pub fn to_ll(self) -> sys::video::SDL_HitTestResult {
+        sys::video::SDL_HitTestResult(self as i32)
+    }
+}
+
+/// Type alias for the hit-test callback, called with a point in window coordinates.
+pub type HitTestCallback = Box<dyn FnMut(Point) -> HitTestResult + 'static>;
+
+unsafe extern "C" fn c_hit_test_callback(
+    _window: *mut sys::video::SDL_Window,
+    area: *const sys::rect::SDL_Point,
+    data: *mut c_void,
+) -> sys::video::SDL_HitTestResult {
+    let callback = &mut *(data as *mut HitTestCallback);
+    let area = &*area;
+    // panics must not unwind into SDL, treat the point as a normal one instead
+    catch_unwind(AssertUnwindSafe(|| callback(Point::new(area.x, area.y))))
+        .unwrap_or(HitTestResult::Normal)
+        .to_ll()
+}
+
+/// Metal views only exist on Apple platforms, elsewhere this yields a null view.
+#[allow(unused_variables)]
+unsafe fn create_metal_view(
+    raw: *mut sys::video::SDL_Window,
+    requested: bool,
+) -> sys::metal::SDL_MetalView {
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    if requested {
+        return sys::metal::SDL_Metal_CreateView(raw);
+    }
+    null_mut()
+}
+
+pub type EGLDisplay = sys::video::SDL_EGLDisplay;
+pub type EGLConfig = sys::video::SDL_EGLConfig;
+pub type EGLSurface = sys::video::SDL_EGLSurface;
+pub type EGLAttrib = sys::video::SDL_EGLAttrib;
+pub type EGLint = sys::video::SDL_EGLint;
+
+/// Type alias for the EGL platform attribute callback.
+pub type EGLAttribArrayCallback = Box<dyn FnMut() -> Vec<EGLAttrib> + Send>;
+/// Type alias for the EGL surface and context attribute callbacks.
+pub type EGLIntArrayCallback = Box<dyn FnMut(EGLDisplay, EGLConfig) -> Vec<EGLint> + Send>;
+
+/// Callbacks returning extra `(name, value)` attribute pairs for EGL initialization, without
+/// the `EGL_NONE` terminator. Set with `VideoSubsystem::egl_set_attribute_callbacks`.
+#[derive(Default)]
+pub struct EGLAttributeCallbacks {
+    /// Attributes for `eglGetPlatformDisplay`
+    pub platform: Option<EGLAttribArrayCallback>,
+    /// Attributes for `eglCreateWindowSurface`
+    pub surface: Option<EGLIntArrayCallback>,
+    /// Attributes for `eglCreateContext`
+    pub context: Option<EGLIntArrayCallback>,
+}
+
+/// The callbacks SDL currently calls. The generation changes whenever they are replaced, so a
+/// callback that was running meanwhile isn't put back.
+struct EGLCallbackSlot {
+    callbacks: Option<EGLAttributeCallbacks>,
+    generation: u64,
+}
+
+static EGL_ATTRIBUTE_CALLBACKS: Mutex<EGLCallbackSlot> = Mutex::new(EGLCallbackSlot {
+    callbacks: None,
+    generation: 0,
+});
+
+/// Replaces the stored callbacks, with `apply` updating SDL under the lock so both always agree.
+fn replace_egl_attribute_callbacks(callbacks: Option<EGLAttributeCallbacks>, apply: impl FnOnce()) {
+    let mut slot = EGL_ATTRIBUTE_CALLBACKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    apply();
+    slot.generation += 1;
+    let previous = mem::replace(&mut slot.callbacks, callbacks);
+    // the closures may do anything when dropped, so not while locked
+    drop(slot);
+    drop(previous);
+}
+
+const EGL_NONE: i16 = 0x3038;
+
+/// Copies attributes into an `EGL_NONE` terminated array allocated for SDL to free.
+unsafe fn egl_attrib_array<T: Copy + From<i16>>(attribs: &[T]) -> *mut T {
+    let array = sys::stdinc::SDL_malloc((attribs.len() + 1) * mem::size_of::<T>()) as *mut T;
+    if !array.is_null() {
+        ptr::copy_nonoverlapping(attribs.as_ptr(), array, attribs.len());
+        *array.add(attribs.len()) = T::from(EGL_NONE);
+    }
+    array
+}
+
+/// Runs one of the attribute callbacks for SDL. Panics must not unwind into SDL, so a panicking
+/// callback adds no attributes.
+///
+/// The callbacks are taken out of the lock while they run, so they can set new callbacks
+/// without deadlocking.
+unsafe fn egl_callback_attribs<T: Copy + From<i16>>(
+    callback: impl FnOnce(&mut EGLAttributeCallbacks) -> Option<Vec<T>>,
+) -> *mut T {
+    let (generation, mut callbacks) = {
+        let mut slot = EGL_ATTRIBUTE_CALLBACKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        (slot.generation, slot.callbacks.take())
+    };
+    let attribs = callbacks.as_mut().and_then(|callbacks| {
+        catch_unwind(AssertUnwindSafe(|| callback(callbacks)))
+            .ok()
+            .flatten()
+    });
+
+    // put the callbacks back, unless they were replaced while running
+    let stale = {
+        let mut slot = EGL_ATTRIBUTE_CALLBACKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if slot.generation == generation {
+            slot.callbacks = callbacks;
+            None
+        } else {
+            callbacks
+        }
+    };
+    drop(stale);
+    egl_attrib_array(&attribs.unwrap_or_default())
+}
+
+unsafe extern "C" fn c_egl_platform_attribs(_userdata: *mut c_void) -> *mut EGLAttrib {
+    egl_callback_attribs(|callbacks| callbacks.platform.as_mut().map(|callback| callback()))
+}
+
+unsafe extern "C" fn c_egl_surface_attribs(
+    _userdata: *mut c_void,
+    display: EGLDisplay,
+    config: EGLConfig,
+) -> *mut EGLint {
+    egl_callback_attribs(|callbacks| {
+        callbacks
+            .surface
+            .as_mut()
+            .map(|callback| callback(display, config))
+    })
+}
+
+unsafe extern "C" fn c_egl_context_attribs(
+    _userdata: *mut c_void,
+    display: EGLDisplay,
+    config: EGLConfig,
+) -> *mut EGLint {
+    egl_callback_attribs(|callbacks| {
+        callbacks
+            .context
+            .as_mut()
+            .map(|callback| callback(display, config))
+    })
+}
+
 /// Represents the "shell" of a `Window`.
 ///
 /// You can set get and set many of the `SDL_Window` properties (i.e., border, size, `PixelFormat`, etc)
@@ -686,12 +910,28 @@ pub struct Window {
 
 impl From<WindowContext> for Window {
     fn from(context: WindowContext) -> Window {
-        Window {
-            context: Arc::new(context),
+        let context = Arc::new(context);
+        // Lets `VideoSubsystem::window_from_id` find the context again. SDL drops the reference
+        // together with the window's properties.
+        unsafe {
+            sys::properties::SDL_SetPointerPropertyWithCleanup(
+                sys::video::SDL_GetWindowProperties(context.raw),
+                WINDOW_CONTEXT_PROPERTY.as_ptr() as *const c_char,
+                Weak::into_raw(Arc::downgrade(&context)) as *mut c_void,
+                Some(drop_window_context_ref),
+                null_mut(),
+            );
         }
+        Window { context }
     }
 }
 
+const WINDOW_CONTEXT_PROPERTY: &[u8] = b"sdl3-rs.window.context\0";
+
+unsafe extern "C" fn drop_window_context_ref(_userdata: *mut c_void, value: *mut c_void) {
+    drop(Weak::from_raw(value as *const WindowContext));
+}
+
 impl_raw_accessors!((GLContext, sys::video::SDL_GLContext));
 
 /// System theme.
@@ -716,9 +956,15 @@ impl Display {
         Display { id: id }
     }
 
+    /// The SDL display ID. IDs are never reused while the video subsystem is running, so a
+    /// disconnected display keeps a stale ID.
+    pub fn id(&self) -> SDL_DisplayID {
+        self.id
+    }
+
     // There is no good method of determining this directly
     pub fn is_connected(&self) -> bool {
-        self.get_bounds().is_err()
+        self.get_bounds().is_ok()
     }
 
     #[doc(alias = "SDL_GetDisplayProperties")]
@@ -775,6 +1021,8 @@ impl Display {
         }
     }
 
+    /// Returns every fullscreen mode the display supports, sorted from largest to smallest
+    /// resolution and then from highest to lowest refresh rate.
     #[doc(alias = "SDL_GetFullscreenDisplayModes")]
     pub fn get_fullscreen_modes(&self) -> Result<Vec<DisplayMode>, Error> {
         unsafe {
@@ -796,6 +1044,8 @@ impl Display {
         }
     }
 
+    /// Returns the mode of the desktop, i.e. the mode the display was in before any window
+    /// switched it to an exclusive fullscreen mode.
     #[doc(alias = "SDL_GetDesktopDisplayMode")]
     pub fn get_mode(&self) -> Result<DisplayMode, Error> {
         unsafe {
@@ -807,11 +1057,44 @@ impl Display {
         }
     }
 
+    /// Returns the mode the display is currently in. This differs from
+    /// [`get_mode`](Self::get_mode) while a window is in exclusive fullscreen.
+    #[doc(alias = "SDL_GetCurrentDisplayMode")]
+    pub fn get_current_mode(&self) -> Result<DisplayMode, Error> {
+        unsafe {
+            let raw_mode = sys::video::SDL_GetCurrentDisplayMode(self.id);
+            if raw_mode.is_null() {
+                return Err(get_error());
+            }
+            Ok(DisplayMode::from_ll(&*raw_mode))
+        }
+    }
+
     #[doc(alias = "SDL_GetClosestFullscreenDisplayMode")]
     pub fn get_closest_display_mode(
         &self,
         mode: &DisplayMode,
         include_high_density_modes: bool,
+    ) -> Result<DisplayMode, Error> {
+        self.closest_fullscreen_mode(
+            mode.w,
+            mode.h,
+            mode.refresh_rate,
+            include_high_density_modes,
+        )
+    }
+
+    /// Finds the fullscreen mode closest to the requested size and refresh rate.
+    ///
+    /// Modes at least as large as `w`x`h` are preferred, then the refresh rate closest to
+    /// `refresh_rate`; pass `0.0` for the desktop refresh rate. Fails if no mode is large enough.
+    #[doc(alias = "SDL_GetClosestFullscreenDisplayMode")]
+    pub fn closest_fullscreen_mode(
+        &self,
+        w: i32,
+        h: i32,
+        refresh_rate: f32,
+        include_high_density_modes: bool,
     ) -> Result<DisplayMode, Error> {
         unsafe {
             // Allocate uninitialized memory for SDL_DisplayMode
@@ -820,9 +1103,9 @@ impl Display {
             // Call the SDL function, passing a pointer to the uninitialized memory
             let ok = sys::video::SDL_GetClosestFullscreenDisplayMode(
                 self.id,
-                mode.w,
-                mode.h,
-                mode.refresh_rate,
+                w,
+                h,
+                refresh_rate,
                 include_high_density_modes,
                 mode_out.as_mut_ptr(),
             );
@@ -838,17 +1121,34 @@ impl Display {
     }
 
     /// Return orientation of a display or Unknown if orientation could not be determined.
+    ///
+    /// Rotating the device sends a `DisplayEvent::Orientation` with the new orientation.
     #[doc(alias = "SDL_GetCurrentDisplayOrientation")]
-    pub fn get_orientation(&self) -> SDL_DisplayOrientation {
-        unsafe { sys::video::SDL_GetCurrentDisplayOrientation(self.id) }
+    pub fn get_orientation(&self) -> Orientation {
+        Orientation::from_ll(unsafe { sys::video::SDL_GetCurrentDisplayOrientation(self.id) })
     }
 
-    /// Return orientation of a display or Unknown if orientation could not be determined.
+    /// Return the orientation the display has without rotation, e.g. portrait for most phones,
+    /// or Unknown if orientation could not be determined.
     #[doc(alias = "SDL_GetNaturalDisplayOrientation")]
-    pub fn get_natural_orientation(&self) -> SDL_DisplayOrientation {
-        unsafe { sys::video::SDL_GetNaturalDisplayOrientation(self.id) }
+    pub fn get_natural_orientation(&self) -> Orientation {
+        Orientation::from_ll(unsafe { sys::video::SDL_GetNaturalDisplayOrientation(self.id) })
     }
 
+    /// Returns whether HDR output is currently enabled on the display. Windows on it receive
+    /// `WindowEvent::HDRStateChanged` when the user toggles HDR.
+    #[doc(alias = "SDL_PROP_DISPLAY_HDR_ENABLED_BOOLEAN")]
+    pub fn hdr_enabled(&self) -> bool {
+        unsafe {
+            sys::properties::SDL_GetBooleanProperty(
+                sys::video::SDL_GetDisplayProperties(self.id),
+                sys::video::SDL_PROP_DISPLAY_HDR_ENABLED_BOOLEAN,
+                false,
+            )
+        }
+    }
+
+    /// Returns the scale the user chose for content on the display, e.g. 1.5 for 150% scaling.
     #[doc(alias = "SDL_GetDisplayContentScale")]
     pub fn get_content_scale(&self) -> Result<f32, Error> {
         let value = unsafe { sys::video::SDL_GetDisplayContentScale(self.id) };
@@ -897,15 +1197,11 @@ impl VideoSubsystem {
     }
 
     /// Initializes a new `PopupWindowBuilder`; a convenience method that calls `PopupWindowBuilder::new()`.
-    pub unsafe fn popup_window(
-        &self,
-        window: &Window,
-        width: u32,
-        height: u32,
-    ) -> PopupWindowBuilder {
+    pub fn popup_window(&self, window: &Window, width: u32, height: u32) -> PopupWindowBuilder {
         PopupWindowBuilder::new(self, window, width, height)
     }
 
+    /// The name of the video driver in use, e.g. `"wayland"`, for diagnostics.
     #[doc(alias = "SDL_GetCurrentVideoDriver")]
     pub fn current_video_driver(&self) -> &'static str {
         use std::str;
@@ -947,6 +1243,34 @@ impl VideoSubsystem {
         }
     }
 
+    /// Returns every open window created by this crate.
+    #[doc(alias = "SDL_GetWindows")]
+    pub fn windows(&self) -> Result<Vec<Window>, Error> {
+        unsafe {
+            let mut count: c_int = 0;
+            let windows_ptr = sys::video::SDL_GetWindows(&mut count);
+            if windows_ptr.is_null() {
+                return Err(get_error());
+            }
+
+            let windows = std::slice::from_raw_parts(windows_ptr, count as usize)
+                .iter()
+                .filter_map(|&raw| Window::from_raw_window(raw))
+                .collect();
+            SDL_free(windows_ptr as *mut c_void);
+
+            Ok(windows)
+        }
+    }
+
+    /// Looks up a window created by this crate from its ID, e.g. the `window_id` of an event.
+    ///
+    /// Returns `None` if no such window exists or it was created outside of this crate.
+    #[doc(alias = "SDL_GetWindowFromID")]
+    pub fn window_from_id(&self, id: u32) -> Option<Window> {
+        unsafe { Window::from_raw_window(sys::video::SDL_GetWindowFromID(id)) }
+    }
+
     /// Get primary display ID.
     #[doc(alias = "SDL_GetPrimaryDisplay")]
     pub fn get_primary_display(&self) -> Result<Display, Error> {
@@ -958,6 +1282,32 @@ impl VideoSubsystem {
         }
     }
 
+    /// Get the display containing a point in desktop coordinates.
+    #[doc(alias = "SDL_GetDisplayForPoint")]
+    pub fn display_for_point<P: Into<Point>>(&self, point: P) -> Result<Display, Error> {
+        let point = point.into();
+        let id = unsafe { sys::video::SDL_GetDisplayForPoint(point.raw()) };
+        if id == 0 {
+            Err(get_error())
+        } else {
+            Ok(Display::from_ll(id))
+        }
+    }
+
+    /// Get the display which overlaps the most with a rectangle in desktop coordinates.
+    #[doc(alias = "SDL_GetDisplayForRect")]
+    pub fn display_for_rect<R: Into<Rect>>(&self, rect: R) -> Result<Display, Error> {
+        let rect = rect.into();
+        let id = unsafe { sys::video::SDL_GetDisplayForRect(rect.raw()) };
+        if id == 0 {
+            Err(get_error())
+        } else {
+            Ok(Display::from_ll(id))
+        }
+    }
+
+    /// Returns whether the screen saver and display sleep are allowed. SDL disables them by
+    /// default while the video subsystem is initialized.
     #[doc(alias = "SDL_ScreenSaverEnabled")]
     pub fn is_screen_saver_enabled(&self) -> bool {
         unsafe { sys::video::SDL_ScreenSaverEnabled() }
@@ -968,6 +1318,7 @@ impl VideoSubsystem {
         unsafe { sys::video::SDL_EnableScreenSaver() };
     }
 
+    /// Prevents the screen saver and display sleep, e.g. during video playback.
     #[doc(alias = "SDL_DisableScreenSaver")]
     pub fn disable_screen_saver(&self) {
         unsafe { sys::video::SDL_DisableScreenSaver() };
@@ -1034,6 +1385,25 @@ impl VideoSubsystem {
         }
     }
 
+    /// Gets the address of the named OpenGL function as a plain pointer, null if it doesn't
+    /// exist. This is the signature the `gl` and `glow` crates expect from their loaders:
+    ///
+    /// ```no_run
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// # let window = video_subsystem.window("", 800, 600).opengl().build().unwrap();
+    /// let _context = window.gl_create_context().unwrap();
+    /// let loader = |name: &str| video_subsystem.gl_get_proc_address_ptr(name);
+    /// // gl::load_with(loader);
+    /// // let glow = unsafe { glow::Context::from_loader_function(loader) };
+    /// # let _ = loader("glClear");
+    /// ```
+    #[doc(alias = "SDL_GL_GetProcAddress")]
+    pub fn gl_get_proc_address_ptr(&self, procname: &str) -> *const c_void {
+        self.gl_get_proc_address(procname)
+            .map_or(ptr::null(), |function| function as *const c_void)
+    }
+
     #[doc(alias = "SDL_GL_ExtensionSupported")]
     pub fn gl_extension_supported(&self, extension: &str) -> bool {
         match CString::new(extension) {
@@ -1091,6 +1461,73 @@ impl VideoSubsystem {
         }
     }
 
+    /// Gets the pointer to the named EGL function, for drivers that use EGL.
+    #[doc(alias = "SDL_EGL_GetProcAddress")]
+    pub fn egl_get_proc_address(&self, procname: &str) -> SDL_FunctionPointer {
+        match CString::new(procname) {
+            Ok(procname) => unsafe { sys::video::SDL_EGL_GetProcAddress(procname.as_ptr()) },
+            // string contains a nul byte - it won't match anything.
+            Err(_) => None,
+        }
+    }
+
+    /// Gets the EGL display of the current OpenGL context.
+    #[doc(alias = "SDL_EGL_GetCurrentDisplay")]
+    pub fn egl_current_display(&self) -> Result<EGLDisplay, Error> {
+        let display = unsafe { sys::video::SDL_EGL_GetCurrentDisplay() };
+        if display.is_null() {
+            Err(get_error())
+        } else {
+            Ok(display)
+        }
+    }
+
+    /// Gets the EGL config of the current OpenGL context.
+    #[doc(alias = "SDL_EGL_GetCurrentConfig")]
+    pub fn egl_current_config(&self) -> Result<EGLConfig, Error> {
+        let config = unsafe { sys::video::SDL_EGL_GetCurrentConfig() };
+        if config.is_null() {
+            Err(get_error())
+        } else {
+            Ok(config)
+        }
+    }
+
+    /// Adds attributes to the ones SDL passes to EGL when creating OpenGL windows and contexts,
+    /// e.g. to pick an ANGLE backend. Replaces any callbacks set before.
+    ///
+    /// The callbacks are reset by `GLAttr::reset`.
+    ///
+    /// ```no_run
+    /// use sdl3::video::EGLAttributeCallbacks;
+    ///
+    /// const EGL_PLATFORM_ANGLE_TYPE_ANGLE: isize = 0x3203;
+    /// const EGL_PLATFORM_ANGLE_TYPE_VULKAN_ANGLE: isize = 0x3450;
+    ///
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// video_subsystem.egl_set_attribute_callbacks(EGLAttributeCallbacks {
+    ///     platform: Some(Box::new(|| {
+    ///         vec![EGL_PLATFORM_ANGLE_TYPE_ANGLE, EGL_PLATFORM_ANGLE_TYPE_VULKAN_ANGLE]
+    ///     })),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    #[doc(alias = "SDL_EGL_SetAttributeCallbacks")]
+    pub fn egl_set_attribute_callbacks(&self, callbacks: EGLAttributeCallbacks) {
+        let platform: sys::video::SDL_EGLAttribArrayCallback = callbacks
+            .platform
+            .as_ref()
+            .and(Some(c_egl_platform_attribs));
+        let surface: sys::video::SDL_EGLIntArrayCallback =
+            callbacks.surface.as_ref().and(Some(c_egl_surface_attribs));
+        let context: sys::video::SDL_EGLIntArrayCallback =
+            callbacks.context.as_ref().and(Some(c_egl_context_attribs));
+        replace_egl_attribute_callbacks(Some(callbacks), || unsafe {
+            sys::video::SDL_EGL_SetAttributeCallbacks(platform, surface, context, null_mut());
+        });
+    }
+
     /// Loads the default Vulkan library.
     ///
     /// This should be done after initializing the video driver, but before creating any Vulkan windows.
@@ -1147,7 +1584,8 @@ impl VideoSubsystem {
         unsafe { sys::vulkan::SDL_Vulkan_GetVkGetInstanceProcAddr() }
     }
 
-    /// Get the current system theme.
+    /// Get the current system theme, light or dark. Listen for `Event::SystemThemeChanged` to
+    /// follow changes.
     #[doc(alias = "SDL_GetSystemTheme")]
     pub fn get_system_theme() -> SystemTheme {
         unsafe {
@@ -1195,6 +1633,20 @@ impl error::Error for WindowBuildError {
     }
 }
 
+/// A native window created outside of SDL, to be wrapped by
+/// [`WindowBuilder::external_window`].
+#[derive(Copy, Clone, Debug)]
+pub enum ExternalWindowHandle {
+    /// A Win32 `HWND`.
+    Win32Hwnd(*mut c_void),
+    /// A Cocoa `NSWindow`.
+    CocoaWindow(*mut c_void),
+    /// An X11 `Window` id.
+    X11Window(u64),
+    /// A Wayland `wl_surface`.
+    WaylandSurface(*mut c_void),
+}
+
 /// The type that allows you to build windows.
 #[derive(Debug)]
 pub struct WindowBuilder {
@@ -1205,6 +1657,7 @@ pub struct WindowBuilder {
     y: WindowPos,
     window_flags: u32,
     create_metal_view: bool,
+    external_window: Option<ExternalWindowHandle>,
     /// The window builder cannot be built on a non-main thread, so prevent cross-threaded moves and references.
     /// `!Send` and `!Sync`,
     subsystem: VideoSubsystem,
@@ -1222,6 +1675,7 @@ impl WindowBuilder {
             window_flags: 0,
             subsystem: v.clone(),
             create_metal_view: false,
+            external_window: None,
         }
     }
 
@@ -1275,25 +1729,51 @@ impl WindowBuilder {
                 sys::video::SDL_PROP_WINDOW_CREATE_HEIGHT_NUMBER,
                 raw_height.into(),
             );
-            let flags_cstr = CString::new("SDL.window.create.flags").unwrap();
-            SDL_SetNumberProperty(props, flags_cstr.as_ptr(), self.window_flags.into());
+            SDL_SetNumberProperty(
+                props,
+                sys::video::SDL_PROP_WINDOW_CREATE_FLAGS_NUMBER,
+                self.window_flags.into(),
+            );
 
-            let raw = sys::video::SDL_CreateWindowWithProperties(props);
-            SDL_DestroyProperties(props);
-            let mut metal_view = 0 as sys::metal::SDL_MetalView;
-            #[cfg(target_os = "macos")]
-            if self.create_metal_view {
-                {
-                    metal_view = sys::metal::SDL_Metal_CreateView(raw);
+            match self.external_window {
+                Some(ExternalWindowHandle::Win32Hwnd(hwnd)) => {
+                    SDL_SetPointerProperty(
+                        props,
+                        sys::video::SDL_PROP_WINDOW_CREATE_WIN32_HWND_POINTER,
+                        hwnd,
+                    );
+                }
+                Some(ExternalWindowHandle::CocoaWindow(window)) => {
+                    SDL_SetPointerProperty(
+                        props,
+                        sys::video::SDL_PROP_WINDOW_CREATE_COCOA_WINDOW_POINTER,
+                        window,
+                    );
+                }
+                Some(ExternalWindowHandle::X11Window(window)) => {
+                    SDL_SetNumberProperty(
+                        props,
+                        sys::video::SDL_PROP_WINDOW_CREATE_X11_WINDOW_NUMBER,
+                        window as i64,
+                    );
+                }
+                Some(ExternalWindowHandle::WaylandSurface(surface)) => {
+                    SDL_SetPointerProperty(
+                        props,
+                        sys::video::SDL_PROP_WINDOW_CREATE_WAYLAND_WL_SURFACE_POINTER,
+                        surface,
+                    );
                 }
+                None => {}
             }
 
+            let raw = sys::video::SDL_CreateWindowWithProperties(props);
+            SDL_DestroyProperties(props);
             if raw.is_null() {
                 Err(SdlError(get_error()))
             } else {
-                {
-                    Ok(Window::from_ll(self.subsystem.clone(), raw, metal_view))
-                }
+                let metal_view = create_metal_view(raw, self.create_metal_view);
+                Ok(Window::from_ll(self.subsystem.clone(), raw, metal_view))
             }
         }
     }
@@ -1379,15 +1859,58 @@ impl WindowBuilder {
     }
 
     /// Create a SDL_MetalView when constructing the window.
-    /// This is required when using the raw_window_handle feature on macOS.
-    /// Has no effect no other platforms.
+    /// This is required when using the raw_window_handle feature on macOS, and to render to the
+    /// window with your own Metal or MoltenVK code, see `Window::metal_layer`.
+    /// Has no effect on other platforms.
     pub fn metal_view(&mut self) -> &mut WindowBuilder {
         self.create_metal_view = true;
         self
     }
+
+    /// Sets the window to be transparent, so the desktop shows through wherever the alpha
+    /// of the rendered content is below 1.0.
+    pub fn transparent(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_TRANSPARENT as u32;
+        self
+    }
+
+    /// Sets the window to be shown on top of all other windows.
+    pub fn always_on_top(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_ALWAYS_ON_TOP as u32;
+        self
+    }
+
+    /// Sets the window to be a utility window, e.g. a tool palette, which isn't shown in the
+    /// taskbar and window list.
+    pub fn utility(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_UTILITY as u32;
+        self
+    }
+
+    /// Requests a backbuffer at the full resolution of high DPI displays.
+    pub fn high_pixel_density(&mut self) -> &mut WindowBuilder {
+        self.window_flags |= sys::video::SDL_WINDOW_HIGH_PIXEL_DENSITY as u32;
+        self
+    }
+
+    /// Wraps a native window created outside of SDL instead of creating a new one, e.g. to
+    /// render into a window owned by an editor or another toolkit.
+    ///
+    /// # Safety
+    ///
+    /// The handle must refer to a valid window of the current video driver's platform, which
+    /// must stay alive for as long as the SDL window exists.
+    pub unsafe fn external_window(&mut self, handle: ExternalWindowHandle) -> &mut WindowBuilder {
+        self.external_window = Some(handle);
+        self
+    }
 }
 
-/// The type that allows you to build popup windows.
+/// The type that allows you to build popup windows, i.e. tooltips and menus positioned
+/// relative to a parent window.
+///
+/// Popups can't be focused and move along with their parent. The parent is kept alive for as
+/// long as any of its popups exist, as SDL destroys popups together with their parent.
 pub struct PopupWindowBuilder {
     parent_window: Window,
     width: u32,
@@ -1403,14 +1926,16 @@ pub struct PopupWindowBuilder {
 
 impl PopupWindowBuilder {
     /// Initializes a new `PopupWindowBuilder`.
-    pub unsafe fn new(
+    ///
+    /// Either `tooltip` or `popup_menu` must be called before building.
+    pub fn new(
         v: &VideoSubsystem,
         parent_window: &Window,
         width: u32,
         height: u32,
     ) -> PopupWindowBuilder {
         PopupWindowBuilder {
-            parent_window: Window::from_ref(parent_window.context()),
+            parent_window: parent_window.clone(),
             width,
             height,
             offset_x: 0,
@@ -1457,16 +1982,13 @@ impl PopupWindowBuilder {
                 raw_height,
                 self.window_flags.into(),
             );
-            let mut metal_view = 0 as sys::metal::SDL_MetalView;
-            #[cfg(target_os = "macos")]
-            if self.create_metal_view {
-                metal_view = sys::metal::SDL_Metal_CreateView(raw);
-            }
-
             if raw.is_null() {
                 Err(SdlError(get_error()))
             } else {
-                Ok(Window::from_ll(self.subsystem.clone(), raw, metal_view))
+                let metal_view = create_metal_view(raw, self.create_metal_view);
+                let mut context = WindowContext::from_ll(self.subsystem.clone(), raw, metal_view);
+                *context.parent.get_mut().unwrap() = Some(self.parent_window.context());
+                Ok(context.into())
             }
         }
     }
@@ -1483,7 +2005,7 @@ impl PopupWindowBuilder {
         self
     }
 
-    /// Sets the window offset relative to the parent window.
+    /// Sets the position of the popup relative to the top-left corner of the parent window.
     pub fn offset(&mut self, x: i32, y: i32) -> &mut PopupWindowBuilder {
         self.offset_x = x;
         self.offset_y = y;
@@ -1521,8 +2043,9 @@ impl PopupWindowBuilder {
     }
 
     /// Create a SDL_MetalView when constructing the window.
-    /// This is required when using the raw_window_handle feature on MacOS.
-    /// Has no effect no other platforms.
+    /// This is required when using the raw_window_handle feature on macOS, and to render to the
+    /// window with your own Metal or MoltenVK code, see `Window::metal_layer`.
+    /// Has no effect on other platforms.
     pub fn metal_view(&mut self) -> &mut PopupWindowBuilder {
         self.create_metal_view = true;
         self
@@ -1568,6 +2091,114 @@ impl Window {
         self.context.raw
     }
 
+    /// Yields the window's `SDL_MetalView`, or null if it was not built with `metal_view()`.
+    /// On macOS this is an `NSView`, on iOS a `UIView`.
+    pub fn raw_metal_view(&self) -> sys::metal::SDL_MetalView {
+        self.context.metal_view
+    }
+
+    /// Yields the `CAMetalLayer` backing the window's Metal view, for attaching your own Metal
+    /// or MoltenVK rendering. Null if the window was not built with `metal_view()`.
+    #[doc(alias = "SDL_Metal_GetLayer")]
+    pub fn metal_layer(&self) -> *mut c_void {
+        if self.context.metal_view.is_null() {
+            return null_mut();
+        }
+        unsafe { sys::metal::SDL_Metal_GetLayer(self.context.metal_view) }
+    }
+
+    fn number_property(&self, name: *const c_char) -> i64 {
+        unsafe {
+            sys::properties::SDL_GetNumberProperty(
+                sys::video::SDL_GetWindowProperties(self.context.raw),
+                name,
+                0,
+            )
+        }
+    }
+
+    fn pointer_property(&self, name: *const c_char) -> *mut c_void {
+        unsafe {
+            sys::properties::SDL_GetPointerProperty(
+                sys::video::SDL_GetWindowProperties(self.context.raw),
+                name,
+                null_mut(),
+            )
+        }
+    }
+
+    /// Yields the window's `HWND` on Windows, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_WIN32_HWND_POINTER")]
+    pub fn raw_win32_hwnd(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_WIN32_HWND_POINTER)
+    }
+
+    /// Yields the window's `HDC` on Windows, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_WIN32_HDC_POINTER")]
+    pub fn raw_win32_hdc(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_WIN32_HDC_POINTER)
+    }
+
+    /// Yields the `HINSTANCE` owning the window on Windows, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_WIN32_INSTANCE_POINTER")]
+    pub fn raw_win32_instance(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_WIN32_INSTANCE_POINTER)
+    }
+
+    /// Yields the window's `NSWindow` on macOS, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_COCOA_WINDOW_POINTER")]
+    pub fn raw_cocoa_window(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_COCOA_WINDOW_POINTER)
+    }
+
+    /// Yields the window's `UIWindow` on iOS, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_UIKIT_WINDOW_POINTER")]
+    pub fn raw_uikit_window(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_UIKIT_WINDOW_POINTER)
+    }
+
+    /// Yields the X11 `Display` connection of the window, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_X11_DISPLAY_POINTER")]
+    pub fn raw_x11_display(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_X11_DISPLAY_POINTER)
+    }
+
+    /// Yields the X11 `Window` ID of the window, or 0 on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_X11_WINDOW_NUMBER")]
+    pub fn raw_x11_window(&self) -> u64 {
+        self.number_property(sys::video::SDL_PROP_WINDOW_X11_WINDOW_NUMBER) as u64
+    }
+
+    /// Yields the `wl_display` of the window on Wayland, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_WAYLAND_DISPLAY_POINTER")]
+    pub fn raw_wayland_display(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_WAYLAND_DISPLAY_POINTER)
+    }
+
+    /// Yields the window's `wl_surface` on Wayland, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_WAYLAND_SURFACE_POINTER")]
+    pub fn raw_wayland_surface(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_WAYLAND_SURFACE_POINTER)
+    }
+
+    /// Yields the window's `xdg_toplevel` role on Wayland, null for popups, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_WAYLAND_XDG_TOPLEVEL_POINTER")]
+    pub fn raw_wayland_xdg_toplevel(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_WAYLAND_XDG_TOPLEVEL_POINTER)
+    }
+
+    /// Yields the window's `ANativeWindow` on Android, or null on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_ANDROID_WINDOW_POINTER")]
+    pub fn raw_android_window(&self) -> *mut c_void {
+        self.pointer_property(sys::video::SDL_PROP_WINDOW_ANDROID_WINDOW_POINTER)
+    }
+
+    /// The X11 screen the window is on, or 0 on other platforms and video drivers.
+    #[doc(alias = "SDL_PROP_WINDOW_X11_SCREEN_NUMBER")]
+    pub fn x11_screen(&self) -> i32 {
+        self.number_property(sys::video::SDL_PROP_WINDOW_X11_SCREEN_NUMBER) as i32
+    }
+
     #[inline]
     pub unsafe fn from_ll(
         subsystem: VideoSubsystem,
@@ -1578,6 +2209,24 @@ impl Window {
         context.into()
     }
 
+    /// Finds the `Window` owning `raw`, if it was created by this crate.
+    unsafe fn from_raw_window(raw: *mut sys::video::SDL_Window) -> Option<Window> {
+        if raw.is_null() {
+            return None;
+        }
+        let context = sys::properties::SDL_GetPointerProperty(
+            sys::video::SDL_GetWindowProperties(raw),
+            WINDOW_CONTEXT_PROPERTY.as_ptr() as *const c_char,
+            null_mut(),
+        ) as *const WindowContext;
+        if context.is_null() {
+            return None;
+        }
+        // The property keeps its own reference, so only borrow it here
+        let context = mem::ManuallyDrop::new(Weak::from_raw(context));
+        context.upgrade().map(|context| Window { context })
+    }
+
     #[inline]
     /// Create a new `Window` without taking ownership of the `WindowContext`
     pub const unsafe fn from_ref(context: Arc<WindowContext>) -> Window {
@@ -1613,6 +2262,22 @@ impl Window {
         }
     }
 
+    /// Creates a context sharing textures, buffers and other objects with `share_with`, e.g.
+    /// for loading resources on another thread.
+    ///
+    /// This makes `share_with` current on this window while the new context is created, and
+    /// leaves the new context current afterwards.
+    #[doc(alias = "SDL_GL_CreateContext")]
+    pub fn gl_create_shared_context(&self, share_with: &GLContext) -> Result<GLContext, Error> {
+        self.gl_make_current(share_with)?;
+        let gl_attr = self.subsystem().gl_attr();
+        let was_sharing = gl_attr.share_with_current_context();
+        gl_attr.set_share_with_current_context(true);
+        let result = self.gl_create_context();
+        gl_attr.set_share_with_current_context(was_sharing);
+        result
+    }
+
     #[doc(alias = "SDL_GL_GetCurrentContext")]
     pub unsafe fn gl_get_current_context(&self) -> Option<GLContext> {
         let context_raw = sys::video::SDL_GL_GetCurrentContext();
@@ -1651,6 +2316,17 @@ impl Window {
         }
     }
 
+    /// Gets the EGL surface of the window, for drivers that use EGL.
+    #[doc(alias = "SDL_EGL_GetWindowSurface")]
+    pub fn egl_surface(&self) -> Result<EGLSurface, Error> {
+        let surface = unsafe { sys::video::SDL_EGL_GetWindowSurface(self.context.raw) };
+        if surface.is_null() {
+            Err(get_error())
+        } else {
+            Ok(surface)
+        }
+    }
+
     #[doc(alias = "SDL_GL_SwapWindow")]
     pub fn gl_swap_window(&self) {
         unsafe { sys::video::SDL_GL_SwapWindow(self.context.raw) };
@@ -1659,31 +2335,7 @@ impl Window {
     /// Get the names of the Vulkan instance extensions needed to create a surface with `vulkan_create_surface`.
     #[doc(alias = "SDL_Vulkan_GetInstanceExtensions")]
     pub fn vulkan_instance_extensions(&self) -> Result<Vec<String>, Error> {
-        let mut count: c_uint = 0;
-        // returns a pointer to an array of extension names
-        let extension_names_raw =
-            unsafe { sys::vulkan::SDL_Vulkan_GetInstanceExtensions(&mut count) };
-        if extension_names_raw.is_null() {
-            return Err(get_error());
-        }
-
-        // Create a slice from the raw pointer to the array
-        let names_slice =
-            unsafe { std::slice::from_raw_parts(extension_names_raw, count as usize) };
-
-        // Convert the C strings to Rust Strings
-        let mut extension_names = Vec::with_capacity(count as usize);
-        for &ext in names_slice {
-            if ext.is_null() {
-                return Err(Error(
-                    "Received null pointer for extension name".to_string(),
-                ));
-            }
-            let c_str = unsafe { CStr::from_ptr(ext) };
-            extension_names.push(c_str.to_string_lossy().into_owned());
-        }
-
-        Ok(extension_names)
+        crate::vulkan::instance_extensions()
     }
 
     /// Create a Vulkan rendering surface for a window.
@@ -1717,6 +2369,9 @@ impl Window {
         }
     }
 
+    /// Sets the mode used when the window is fullscreen, without entering fullscreen.
+    ///
+    /// `None` selects borderless fullscreen at the desktop resolution.
     #[doc(alias = "SDL_SetWindowFullscreenMode")]
     pub fn set_display_mode<D>(&mut self, display_mode: D) -> Result<(), Error>
     where
@@ -1740,6 +2395,22 @@ impl Window {
         }
     }
 
+    /// Switches the window to exclusive fullscreen in `mode`, which should be one of the
+    /// display's [fullscreen modes](Display::get_fullscreen_modes).
+    ///
+    /// ```no_run
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// # let mut window = video_subsystem.window("", 800, 600).build().unwrap();
+    /// let display = window.get_display().unwrap();
+    /// let mode = display.closest_fullscreen_mode(1920, 1080, 144.0, false).unwrap();
+    /// window.set_fullscreen_mode(mode).unwrap();
+    /// ```
+    pub fn set_fullscreen_mode(&mut self, mode: DisplayMode) -> Result<(), Error> {
+        self.set_display_mode(mode)?;
+        self.set_fullscreen(true)
+    }
+
     #[doc(alias = "SDL_GetWindowFullscreenMode")]
     pub fn display_mode(&self) -> Option<DisplayMode> {
         unsafe {
@@ -1799,6 +2470,36 @@ impl Window {
         0 != self.window_flags() & sys::video::SDL_WINDOW_MAXIMIZED as Uint64
     }
 
+    /// Is the window hidden?
+    pub fn is_hidden(&self) -> bool {
+        0 != self.window_flags() & sys::video::SDL_WINDOW_HIDDEN as Uint64
+    }
+
+    /// Does the window have decorations?
+    pub fn is_bordered(&self) -> bool {
+        0 == self.window_flags() & sys::video::SDL_WINDOW_BORDERLESS as Uint64
+    }
+
+    /// Can the user resize the window?
+    pub fn is_resizable(&self) -> bool {
+        0 != self.window_flags() & sys::video::SDL_WINDOW_RESIZABLE as Uint64
+    }
+
+    /// Is the window kept above all other windows?
+    pub fn is_always_on_top(&self) -> bool {
+        0 != self.window_flags() & sys::video::SDL_WINDOW_ALWAYS_ON_TOP as Uint64
+    }
+
+    /// Can the window receive input focus?
+    pub fn is_focusable(&self) -> bool {
+        0 == self.window_flags() & sys::video::SDL_WINDOW_NOT_FOCUSABLE as Uint64
+    }
+
+    /// Is the window modal to its parent?
+    pub fn is_modal(&self) -> bool {
+        0 != self.window_flags() & sys::video::SDL_WINDOW_MODAL as Uint64
+    }
+
     /// Is the window minimized?
     pub fn is_minimized(&self) -> bool {
         0 != self.window_flags() & sys::video::SDL_WINDOW_MINIMIZED as Uint64
@@ -1825,6 +2526,9 @@ impl Window {
 
     /// Use this function to set the icon for a window.
     ///
+    /// Add larger versions of the icon with `SurfaceRef::add_alternate_image` so it stays crisp
+    /// on high-DPI displays.
+    ///
     /// # Example:
     /// ```compile_fail
     /// // requires "--features 'image'"
@@ -1841,14 +2545,20 @@ impl Window {
     //pub fn SDL_SetWindowData(window: *SDL_Window, name: *c_char, userdata: *c_void) -> *c_void; //TODO: Figure out what this does
     //pub fn SDL_GetWindowData(window: *SDL_Window, name: *c_char) -> *c_void;
 
+    /// Moves the window, in screen coordinates.
     #[doc(alias = "SDL_SetWindowPosition")]
-    pub fn set_position(&mut self, x: WindowPos, y: WindowPos) -> bool {
-        unsafe {
+    pub fn set_position(&mut self, x: WindowPos, y: WindowPos) -> Result<(), Error> {
+        let result = unsafe {
             sys::video::SDL_SetWindowPosition(
                 self.context.raw,
                 to_ll_windowpos(x),
                 to_ll_windowpos(y),
             )
+        };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
         }
     }
 
@@ -1886,6 +2596,7 @@ impl Window {
         }
     }
 
+    /// Resizes the client area of the window, in screen coordinates.
     #[doc(alias = "SDL_SetWindowSize")]
     pub fn set_size(&mut self, width: u32, height: u32) -> Result<(), IntegerOrSdlError> {
         let w = validate_int(width, "width")?;
@@ -1907,11 +2618,72 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Returns the number of pixels per window coordinate, e.g. 2.0 on a Retina display
+    /// when the window was created with `WindowBuilder::high_pixel_density`.
     #[doc(alias = "SDL_GetWindowPixelDensity")]
     pub fn pixel_density(&self) -> f32 {
         unsafe { sys::video::SDL_GetWindowPixelDensity(self.context.raw) }
     }
 
+    /// Returns the scale UI should be drawn at for the window, combining the pixel density
+    /// with the content scale of its display.
+    ///
+    /// For example, on a 4K display set to 200% scaling this is 2.0 whether or not the window
+    /// has a high pixel density backbuffer. Listen for `WindowEvent::DisplayScaleChanged` to
+    /// notice the window moving to a display with a different scale.
+    #[doc(alias = "SDL_GetWindowDisplayScale")]
+    pub fn display_scale(&self) -> Result<f32, Error> {
+        let scale = unsafe { sys::video::SDL_GetWindowDisplayScale(self.context.raw) };
+        if scale == 0.0f32 {
+            Err(get_error())
+        } else {
+            Ok(scale)
+        }
+    }
+
+    /// Returns whether the window is on a display with HDR enabled.
+    ///
+    /// This, [`sdr_white_level`](Self::sdr_white_level) and [`hdr_headroom`](Self::hdr_headroom)
+    /// change when the window moves between displays or the user toggles HDR; listen for
+    /// `WindowEvent::HDRStateChanged` to adapt tone mapping.
+    #[doc(alias = "SDL_PROP_WINDOW_HDR_ENABLED_BOOLEAN")]
+    pub fn hdr_enabled(&self) -> bool {
+        unsafe {
+            sys::properties::SDL_GetBooleanProperty(
+                sys::video::SDL_GetWindowProperties(self.context.raw),
+                sys::video::SDL_PROP_WINDOW_HDR_ENABLED_BOOLEAN,
+                false,
+            )
+        }
+    }
+
+    /// The value of SDR white in the linear colorspace, when HDR is enabled.
+    #[doc(alias = "SDL_PROP_WINDOW_SDR_WHITE_LEVEL_FLOAT")]
+    pub fn sdr_white_level(&self) -> f32 {
+        unsafe {
+            sys::properties::SDL_GetFloatProperty(
+                sys::video::SDL_GetWindowProperties(self.context.raw),
+                sys::video::SDL_PROP_WINDOW_SDR_WHITE_LEVEL_FLOAT,
+                1.0,
+            )
+        }
+    }
+
+    /// How far above SDR white the display can go, e.g. 4.0 if the brightest color is four
+    /// times as bright as SDR white. This is 1.0 without HDR.
+    #[doc(alias = "SDL_PROP_WINDOW_HDR_HEADROOM_FLOAT")]
+    pub fn hdr_headroom(&self) -> f32 {
+        unsafe {
+            sys::properties::SDL_GetFloatProperty(
+                sys::video::SDL_GetWindowProperties(self.context.raw),
+                sys::video::SDL_PROP_WINDOW_HDR_HEADROOM_FLOAT,
+                1.0,
+            )
+        }
+    }
+
+    /// Returns the size of the window's backbuffer in pixels, which is what swapchains and
+    /// render targets covering the window should be sized to.
     #[doc(alias = "SDL_GetWindowSizeInPixels")]
     pub fn size_in_pixels(&self) -> (u32, u32) {
         let mut w: c_int = 0;
@@ -1920,6 +2692,49 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Converts a point in window coordinates, as reported by mouse and touch events, to pixel
+    /// coordinates in the window's backbuffer, e.g. for picking in a GPU framebuffer of
+    /// `size_in_pixels`.
+    ///
+    /// ```no_run
+    /// # use sdl3::event::Event;
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// # let window = video_subsystem.window("", 800, 600).high_pixel_density().build().unwrap();
+    /// # let event = Event::Quit { timestamp: 0 };
+    /// if let Event::MouseButtonDown { x, y, .. } = event {
+    ///     let pixel = window.point_to_pixels((x, y));
+    /// }
+    /// ```
+    pub fn point_to_pixels<P: Into<FPoint>>(&self, point: P) -> FPoint {
+        let point = point.into();
+        let (scale_x, scale_y) = self.pixel_scale();
+        FPoint::new(point.x * scale_x, point.y * scale_y)
+    }
+
+    /// Converts a point in the window's backbuffer pixels to window coordinates, the inverse of
+    /// [`point_to_pixels`](Self::point_to_pixels).
+    pub fn point_from_pixels<P: Into<FPoint>>(&self, point: P) -> FPoint {
+        let point = point.into();
+        let (scale_x, scale_y) = self.pixel_scale();
+        FPoint::new(point.x / scale_x, point.y / scale_y)
+    }
+
+    /// Pixels per window coordinate on each axis. Derived from the actual sizes as the
+    /// backbuffer may be rounded, falling back to the pixel density for empty windows, and to
+    /// 1.0 if that is unknown too, so the scale is never zero.
+    fn pixel_scale(&self) -> (f32, f32) {
+        let (w, h) = self.size();
+        let (pixel_w, pixel_h) = self.size_in_pixels();
+        if w == 0 || h == 0 || pixel_w == 0 || pixel_h == 0 {
+            // SDL returns 0.0 when it fails to get the density
+            let density = self.pixel_density();
+            let density = if density > 0.0 { density } else { 1.0 };
+            return (density, density);
+        }
+        (pixel_w as f32 / w as f32, pixel_h as f32 / h as f32)
+    }
+
     #[doc(alias = "SDL_SetWindowMinimumSize")]
     pub fn set_minimum_size(&mut self, width: u32, height: u32) -> Result<(), IntegerOrSdlError> {
         let w = validate_int(width, "width")?;
@@ -1956,45 +2771,165 @@ impl Window {
         (w as u32, h as u32)
     }
 
+    /// Constrains the width / height ratio of the window's client area while the user resizes
+    /// it, e.g. `(16.0 / 9.0, 16.0 / 9.0)` to lock it to 16:9. `0.0` leaves that side
+    /// unconstrained.
+    #[doc(alias = "SDL_SetWindowAspectRatio")]
+    pub fn set_aspect_ratio(&mut self, min_aspect: f32, max_aspect: f32) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowAspectRatio(self.context.raw, min_aspect, max_aspect) }
+        {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Returns the `(min_aspect, max_aspect)` constraints of the window, `0.0` if unconstrained.
+    #[doc(alias = "SDL_GetWindowAspectRatio")]
+    pub fn aspect_ratio(&self) -> Result<(f32, f32), Error> {
+        let mut min_aspect = 0.0;
+        let mut max_aspect = 0.0;
+        if unsafe {
+            sys::video::SDL_GetWindowAspectRatio(self.context.raw, &mut min_aspect, &mut max_aspect)
+        } {
+            Ok((min_aspect, max_aspect))
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Adds or removes the window decorations (title bar and borders).
     #[doc(alias = "SDL_SetWindowBordered")]
-    pub fn set_bordered(&mut self, bordered: bool) -> bool {
-        unsafe { sys::video::SDL_SetWindowBordered(self.context.raw, bordered) }
+    pub fn set_bordered(&mut self, bordered: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowBordered(self.context.raw, bordered) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     #[doc(alias = "SDL_ShowWindow")]
-    pub fn show(&mut self) -> bool {
-        unsafe { sys::video::SDL_ShowWindow(self.context.raw) }
+    pub fn show(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_ShowWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     #[doc(alias = "SDL_HideWindow")]
-    pub fn hide(&mut self) -> bool {
-        unsafe { sys::video::SDL_HideWindow(self.context.raw) }
+    pub fn hide(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_HideWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Brings the window to the front and asks for input focus. The window manager may refuse;
+    /// see the `SDL_HINT_FORCE_RAISEWINDOW` hint.
     #[doc(alias = "SDL_RaiseWindow")]
-    pub fn raise(&mut self) -> bool {
-        unsafe { sys::video::SDL_RaiseWindow(self.context.raw) }
+    pub fn raise(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_RaiseWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Makes the window as large as possible.
     #[doc(alias = "SDL_MaximizeWindow")]
-    pub fn maximize(&mut self) -> bool {
-        unsafe { sys::video::SDL_MaximizeWindow(self.context.raw) }
+    pub fn maximize(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_MaximizeWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Minimizes the window to an iconic representation.
     #[doc(alias = "SDL_MinimizeWindow")]
-    pub fn minimize(&mut self) -> bool {
-        unsafe { sys::video::SDL_MinimizeWindow(self.context.raw) }
+    pub fn minimize(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_MinimizeWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Restores a minimized or maximized window to its normal size and position.
     #[doc(alias = "SDL_RestoreWindow")]
-    pub fn restore(&mut self) -> bool {
-        unsafe { sys::video::SDL_RestoreWindow(self.context.raw) }
+    pub fn restore(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_RestoreWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Waits until pending changes to the window's position, size and state have been applied.
+    ///
+    /// Most window managers, notably on X11 and Wayland, apply changes made with
+    /// [`set_position`](Self::set_position), [`set_size`](Self::set_size),
+    /// [`maximize`](Self::maximize), [`minimize`](Self::minimize), [`restore`](Self::restore)
+    /// and [`set_fullscreen`](Self::set_fullscreen) asynchronously, so getters may still report
+    /// the old state right after these return. Returns an error if the changes weren't applied
+    /// in time. Set the `SDL_HINT_VIDEO_SYNC_WINDOW_OPERATIONS` hint
+    /// to make every setter wait on its own.
+    ///
+    /// ```no_run
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// # let mut window = video_subsystem.window("", 800, 600).build().unwrap();
+    /// window.set_fullscreen(true).unwrap();
+    /// window.sync().unwrap();
+    /// let (width, height) = window.size_in_pixels(); // the fullscreen size
+    /// ```
+    #[doc(alias = "SDL_SyncWindow")]
+    pub fn sync(&mut self) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SyncWindow(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Allows or prevents the user from resizing the window.
+    #[doc(alias = "SDL_SetWindowResizable")]
+    pub fn set_resizable(&mut self, resizable: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowResizable(self.context.raw, resizable) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Keeps the window above all other windows.
+    #[doc(alias = "SDL_SetWindowAlwaysOnTop")]
+    pub fn set_always_on_top(&mut self, on_top: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowAlwaysOnTop(self.context.raw, on_top) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets whether the window may receive input focus, e.g. `false` for overlays.
+    #[doc(alias = "SDL_SetWindowFocusable")]
+    pub fn set_focusable(&mut self, focusable: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowFocusable(self.context.raw, focusable) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     pub fn fullscreen_state(&self) -> FullscreenType {
         FullscreenType::from_window_flags(self.window_flags() as u32)
     }
 
+    /// Switches the window to or from fullscreen, using the mode set with
+    /// [`set_fullscreen_mode`](Self::set_fullscreen_mode).
     #[doc(alias = "SDL_SetWindowFullscreen")]
     pub fn set_fullscreen(&mut self, fullscreen: bool) -> Result<(), Error> {
         unsafe {
@@ -2007,6 +2942,105 @@ impl Window {
         }
     }
 
+    /// Makes the window a child of `parent`, or a toplevel window again if `parent` is `None`.
+    ///
+    /// Child windows stay on top of their parent and are hidden and destroyed along with it, so
+    /// the parent is kept alive for as long as it has this child. Popup windows can't be
+    /// reparented, and a window can't become a child of itself or of its own descendants.
+    #[doc(alias = "SDL_SetWindowParent")]
+    pub fn set_parent(&mut self, parent: Option<&Window>) -> Result<(), Error> {
+        // a cycle would keep its windows alive forever
+        let mut ancestor = parent.map(|parent| parent.context());
+        while let Some(context) = ancestor {
+            if Arc::ptr_eq(&context, &self.context) {
+                return Err(Error(
+                    "A window can't be a child of itself or of its descendants".to_owned(),
+                ));
+            }
+            ancestor = context
+                .parent
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone();
+        }
+
+        let raw_parent = parent.map_or(null_mut(), |parent| parent.raw());
+        if !unsafe { sys::video::SDL_SetWindowParent(self.context.raw, raw_parent) } {
+            return Err(get_error());
+        }
+        *self
+            .context
+            .parent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = parent.map(|parent| parent.context());
+        Ok(())
+    }
+
+    /// Makes the window modal, blocking input to its parent until it is closed or made
+    /// non-modal. The window must have a parent, see [`set_parent`](Self::set_parent).
+    ///
+    /// ```no_run
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// let main_window = video_subsystem.window("Editor", 1280, 720).build().unwrap();
+    /// let mut dialog = video_subsystem.window("Save changes?", 320, 120).build().unwrap();
+    /// dialog.set_parent(Some(&main_window)).unwrap();
+    /// dialog.set_modal(true).unwrap();
+    /// ```
+    #[doc(alias = "SDL_SetWindowModal")]
+    pub fn set_modal(&mut self, modal: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowModal(self.context.raw, modal) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Lets `callback` decide which parts of the window move or resize it when dragged, e.g. to
+    /// give a borderless window a custom title bar. `None` removes the callback.
+    ///
+    /// The callback runs on the main thread while events are pumped, and should be fast.
+    ///
+    /// ```no_run
+    /// use sdl3::video::HitTestResult;
+    /// # let sdl_context = sdl3::init().unwrap();
+    /// # let video_subsystem = sdl_context.video().unwrap();
+    /// let mut window = video_subsystem
+    ///     .window("", 800, 600)
+    ///     .borderless()
+    ///     .resizable()
+    ///     .build()
+    ///     .unwrap();
+    /// window
+    ///     .set_hit_test(Some(Box::new(|point| match (point.x(), point.y()) {
+    ///         (x, y) if x > 790 && y > 590 => HitTestResult::ResizeBottomRight,
+    ///         (_, y) if y < 32 => HitTestResult::Draggable,
+    ///         _ => HitTestResult::Normal,
+    ///     })))
+    ///     .unwrap();
+    /// ```
+    #[doc(alias = "SDL_SetWindowHitTest")]
+    pub fn set_hit_test(&mut self, callback: Option<HitTestCallback>) -> Result<(), Error> {
+        let mut callback = callback.map(Box::new);
+        let (c_callback, data): (sys::video::SDL_HitTest, *mut c_void) = match callback {
+            Some(ref mut callback) => (
+                Some(c_hit_test_callback),
+                &mut **callback as *mut HitTestCallback as *mut c_void,
+            ),
+            None => (None, null_mut()),
+        };
+        if !unsafe { sys::video::SDL_SetWindowHitTest(self.context.raw, c_callback, data) } {
+            return Err(get_error());
+        }
+        // Only replace the old callback once SDL no longer refers to it
+        *self
+            .context
+            .hit_test
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = callback;
+        Ok(())
+    }
+
     /// Returns a WindowSurfaceRef, which can be used like a regular Surface. This is an
     /// alternative way to the Renderer (Canvas) way to modify pixels directly in the Window.
     ///
@@ -2031,14 +3065,68 @@ impl Window {
         }
     }
 
+    /// Returns whether the window has a surface, i.e. `surface` was called since the window was
+    /// created or its surface destroyed.
+    #[doc(alias = "SDL_WindowHasSurface")]
+    pub fn has_surface(&self) -> bool {
+        unsafe { sys::video::SDL_WindowHasSurface(self.context.raw) }
+    }
+
+    /// Destroys the window's surface, e.g. before switching to a renderer or the GPU API.
+    ///
+    /// Takes the event pump mutably, like [`surface`](Self::surface) borrows it, so the surface
+    /// can't be destroyed through any handle to this window while a `WindowSurfaceRef` is alive.
+    #[doc(alias = "SDL_DestroyWindowSurface")]
+    pub fn destroy_surface(&mut self, _e: &mut EventPump) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_DestroyWindowSurface(self.context.raw) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Sets whether `WindowSurfaceRef::update_window` waits for the vertical retrace. Call it
+    /// after `surface`, as the setting belongs to the window's surface.
+    #[doc(alias = "SDL_SetWindowSurfaceVSync")]
+    pub fn set_surface_vsync<V: Into<RendererVSync>>(&mut self, vsync: V) -> Result<(), Error> {
+        let vsync: i32 = vsync.into().into();
+        if unsafe { sys::video::SDL_SetWindowSurfaceVSync(self.context.raw, vsync) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    #[doc(alias = "SDL_GetWindowSurfaceVSync")]
+    pub fn surface_vsync(&self) -> Result<RendererVSync, Error> {
+        let mut vsync = 0;
+        if unsafe { sys::video::SDL_GetWindowSurfaceVSync(self.context.raw, &mut vsync) } {
+            Ok(RendererVSync::from(vsync))
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Grabs the keyboard while the window has input focus, so system shortcuts such as Alt+Tab
+    /// or the Super key go to the application instead of the desktop.
     #[doc(alias = "SDL_SetWindowKeyboardGrab")]
-    pub fn set_keyboard_grab(&mut self, grabbed: bool) -> bool {
-        unsafe { sys::video::SDL_SetWindowKeyboardGrab(self.context.raw, grabbed) }
+    pub fn set_keyboard_grab(&mut self, grabbed: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowKeyboardGrab(self.context.raw, grabbed) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
+    /// Confines the mouse to the window while it has input focus. See `set_mouse_rect` to
+    /// confine it to part of the window.
     #[doc(alias = "SDL_SetWindowMouseGrab")]
-    pub fn set_mouse_grab(&mut self, grabbed: bool) -> bool {
-        unsafe { sys::video::SDL_SetWindowMouseGrab(self.context.raw, grabbed) }
+    pub fn set_mouse_grab(&mut self, grabbed: bool) -> Result<(), Error> {
+        if unsafe { sys::video::SDL_SetWindowMouseGrab(self.context.raw, grabbed) } {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
     }
 
     #[doc(alias = "SDL_GetWindowKeyboardGrab")]
@@ -2051,8 +3139,12 @@ impl Window {
         unsafe { sys::video::SDL_GetWindowMouseGrab(self.context.raw) }
     }
 
+    /// Confines the mouse to `rect`, in window coordinates, while the window has mouse focus,
+    /// e.g. to keep the cursor over the map for edge scrolling. `None` releases it.
+    ///
+    /// The rect is kept when the window loses focus and applies again once it regains it.
     #[doc(alias = "SDL_SetWindowMouseRect")]
-    pub fn set_mouse_rect<R>(&self, rect: R) -> Result<(), Error>
+    pub fn set_mouse_rect<R>(&mut self, rect: R) -> Result<(), Error>
     where
         R: Into<Option<Rect>>,
     {
@@ -2071,6 +3163,7 @@ impl Window {
         }
     }
 
+    /// Returns the rect the mouse is confined to, if any.
     #[doc(alias = "SDL_GetWindowMouseRect")]
     pub fn mouse_rect(&self) -> Option<Rect> {
         unsafe {
@@ -2117,7 +3210,19 @@ impl Window {
         }
     }
 
-    /// Requests a window to demand attention from the user.
+    /// Requests a window to demand attention from the user, e.g. by flashing its taskbar
+    /// entry.
+    ///
+    /// Use `FlashOperation::UntilFocused` for notifications that shouldn't be missed, and
+    /// `FlashOperation::Cancel` to stop flashing early. This method returns an error if
+    /// flashing isn't supported by the current platform.
+    ///
+    /// ```no_run
+    /// use sdl3::video::FlashOperation;
+    ///
+    /// # let mut window: sdl3::video::Window = unimplemented!();
+    /// window.flash(FlashOperation::UntilFocused).ok();
+    /// ```
     #[doc(alias = "SDL_FlashWindow")]
     pub fn flash(&mut self, operation: FlashOperation) -> Result<(), Error> {
         let result = unsafe { sys::video::SDL_FlashWindow(self.context.raw, operation.to_ll()) };
@@ -2127,6 +3232,33 @@ impl Window {
             Err(get_error())
         }
     }
+
+    /// Gives the window a non-rectangular shape, e.g. for custom splash screens.
+    ///
+    /// Pixels of `shape` with an alpha of 0 are cut out of the window, and clicks on them pass
+    /// through to whatever is behind it. The surface is scaled to the size of the window. The
+    /// window must have been created with `WindowBuilder::transparent`.
+    #[doc(alias = "SDL_SetWindowShape")]
+    pub fn set_shape<S: AsRef<SurfaceRef>>(&mut self, shape: S) -> Result<(), Error> {
+        let result =
+            unsafe { sys::video::SDL_SetWindowShape(self.context.raw, shape.as_ref().raw()) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
+
+    /// Restores the rectangular shape of a window shaped with `set_shape`.
+    #[doc(alias = "SDL_SetWindowShape")]
+    pub fn clear_shape(&mut self) -> Result<(), Error> {
+        let result = unsafe { sys::video::SDL_SetWindowShape(self.context.raw, null_mut()) };
+        if result {
+            Ok(())
+        } else {
+            Err(get_error())
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -2165,12 +3297,13 @@ impl Iterator for DriverIterator {
 
 impl ExactSizeIterator for DriverIterator {}
 
-/// Gets an iterator of all video drivers compiled into the SDL2 library.
+/// Gets an iterator of all video drivers compiled into the SDL library, in the order SDL tries
+/// them. Pick one with `sdl3::hint::set_video_driver`.
 #[inline]
 #[doc(alias = "SDL_GetVideoDriver")]
 pub fn drivers() -> DriverIterator {
     // This function is thread-safe and doesn't require the video subsystem to be initialized.
-    // The list of drivers are read-only and statically compiled into SDL2, varying by platform.
+    // The list of drivers are read-only and statically compiled into SDL, varying by platform.
 
     // SDL_GetNumVideoDrivers can never return a negative value.
     DriverIterator {
@@ -0,0 +1,94 @@
+//! Helpers for bringing your own Vulkan renderer
+//!
+//! Load the Vulkan loader with [`VideoSubsystem::vulkan_load_library_default`], create a
+//! window with [`WindowBuilder::vulkan`], then create the instance with the extensions from
+//! [`instance_extensions`] and a surface with [`Window::vulkan_create_surface`].
+//!
+//! With the `ash` feature, the handle types are ash's own, so they can be passed to and from
+//! ash without conversion.
+//!
+//! [`VideoSubsystem::vulkan_load_library_default`]: crate::VideoSubsystem::vulkan_load_library_default
+//! [`WindowBuilder::vulkan`]: crate::video::WindowBuilder::vulkan
+//! [`Window::vulkan_create_surface`]: crate::video::Window::vulkan_create_surface
+
+use crate::get_error;
+use crate::Error;
+use libc::{c_char, c_uint};
+use std::ffi::CStr;
+
+pub use crate::sys::vulkan::{VkInstance, VkPhysicalDevice, VkSurfaceKHR};
+
+/// The signature of `vkGetInstanceProcAddr`. With the `ash` feature this is the same type as
+/// `ash::vk::PFN_vkGetInstanceProcAddr`.
+pub type VkGetInstanceProcAddr =
+    unsafe extern "system" fn(VkInstance, *const c_char) -> Option<unsafe extern "system" fn()>;
+
+/// Get the names of the Vulkan instance extensions needed to create surfaces.
+#[doc(alias = "SDL_Vulkan_GetInstanceExtensions")]
+pub fn instance_extensions() -> Result<Vec<String>, Error> {
+    let mut count: c_uint = 0;
+    // returns a pointer to an array of extension names
+    let extension_names_raw = unsafe { sys::vulkan::SDL_Vulkan_GetInstanceExtensions(&mut count) };
+    if extension_names_raw.is_null() {
+        return Err(get_error());
+    }
+
+    let names_slice = unsafe { std::slice::from_raw_parts(extension_names_raw, count as usize) };
+
+    let mut extension_names = Vec::with_capacity(count as usize);
+    for &ext in names_slice {
+        if ext.is_null() {
+            return Err(Error(
+                "Received null pointer for extension name".to_string(),
+            ));
+        }
+        let c_str = unsafe { CStr::from_ptr(ext) };
+        extension_names.push(c_str.to_string_lossy().into_owned());
+    }
+
+    Ok(extension_names)
+}
+
+/// Get the address of `vkGetInstanceProcAddr` from the loaded Vulkan loader, to load every
+/// other Vulkan function with.
+///
+/// Returns an error if the Vulkan loader has not been loaded yet.
+#[doc(alias = "SDL_Vulkan_GetVkGetInstanceProcAddr")]
+pub fn get_instance_proc_addr() -> Result<VkGetInstanceProcAddr, Error> {
+    match unsafe { sys::vulkan::SDL_Vulkan_GetVkGetInstanceProcAddr() } {
+        // SDL hands out the function as a generic function pointer
+        Some(function) => Ok(unsafe {
+            std::mem::transmute::<unsafe extern "C" fn(), VkGetInstanceProcAddr>(function)
+        }),
+        None => Err(get_error()),
+    }
+}
+
+/// Returns whether a queue family of a physical device can present to surfaces on this
+/// platform, for picking a device before any window exists.
+///
+/// # Safety
+///
+/// `instance` must be a live Vulkan instance created with the loaded Vulkan loader, and
+/// `physical_device` must have been enumerated from it.
+#[doc(alias = "SDL_Vulkan_GetPresentationSupport")]
+pub unsafe fn presentation_support(
+    instance: VkInstance,
+    physical_device: VkPhysicalDevice,
+    queue_family_index: u32,
+) -> bool {
+    sys::vulkan::SDL_Vulkan_GetPresentationSupport(instance, physical_device, queue_family_index)
+}
+
+/// Destroy a surface created with [`Window::vulkan_create_surface`].
+///
+/// # Safety
+///
+/// `surface` must have been created from `instance` and must not be used afterwards,
+/// including by swapchains that have not been destroyed yet.
+///
+/// [`Window::vulkan_create_surface`]: crate::video::Window::vulkan_create_surface
+#[doc(alias = "SDL_Vulkan_DestroySurface")]
+pub unsafe fn destroy_surface(instance: VkInstance, surface: VkSurfaceKHR) {
+    sys::vulkan::SDL_Vulkan_DestroySurface(instance, surface, std::ptr::null());
+}
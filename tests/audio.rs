@@ -11,3 +11,15 @@ fn audio_spec_wav() {
     let buffer = wav.buffer();
     assert_eq!(buffer.len(), 4_410);
 }
+
+#[test]
+fn convert_audio() {
+    use sdl3::audio::{AudioFormat, AudioSpec};
+
+    let wav = sdl3::audio::AudioSpecWAV::load_wav("./assets/sine.wav").unwrap();
+    let dst_spec = AudioSpec::new(Some(22_050), Some(2), Some(AudioFormat::F32LE));
+    let converted = sdl3::audio::convert_audio(&wav.spec(), wav.buffer(), &dst_spec).unwrap();
+
+    // every i16 sample becomes two f32 samples
+    assert_eq!(converted.len(), 4_410 * 4);
+}
@@ -31,6 +31,8 @@ fn test_events() {
 
     test3(&ev);
     test4(&ev, &mut ep);
+    test5(&ev);
+    test6(&ev, &mut ep);
 }
 
 fn test1(ev: &sdl3::EventSubsystem) {
@@ -116,9 +118,130 @@ fn test4(ev: &sdl3::EventSubsystem, ep: &mut sdl3::EventPump) {
     if received.is_user_event() {
         let e2 = received.as_user_event_type::<SomeEventTypeTest4>().unwrap();
         assert_eq!(e2.a, 42);
+        // the payload can only be taken once, even through a clone
+        assert!(received
+            .clone()
+            .as_user_event_type::<SomeEventTypeTest4>()
+            .is_none());
     }
 }
 
+fn test5(ev: &sdl3::EventSubsystem) {
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+    let event = |code| event::Event::User {
+        timestamp: 0,
+        window_id: 0,
+        type_: user_event_id,
+        code,
+        data1: std::ptr::null_mut(),
+        data2: std::ptr::null_mut(),
+    };
+
+    assert_eq!(ev.add_events(&[event(1), event(2)]).unwrap(), 2);
+
+    let peeked: Vec<event::Event> = ev
+        .peek_events_in_range(10, user_event_id, user_event_id)
+        .unwrap();
+    assert_eq!(peeked.len(), 2);
+
+    let received: Vec<event::Event> = ev
+        .get_events_in_range(10, user_event_id, user_event_id)
+        .unwrap();
+    let codes: Vec<i32> = received
+        .iter()
+        .map(|event| match event {
+            event::Event::User { code, .. } => *code,
+            other => panic!("Received non User event: {:?}", other),
+        })
+        .collect();
+    assert_eq!(codes, vec![1, 2]);
+    assert!(ev
+        .get_events_in_range::<Vec<event::Event>>(10, user_event_id, user_event_id)
+        .unwrap()
+        .is_empty());
+}
+
+struct SomeEventTypeTest6 {
+    a: u32,
+}
+
+struct SomeOtherEventTypeTest6 {
+    b: String,
+}
+
+struct ZeroSizedEventTypeTest6;
+
+fn test6(ev: &sdl3::EventSubsystem, ep: &mut sdl3::EventPump) {
+    ev.register_custom_event::<SomeEventTypeTest6>().unwrap();
+    ev.register_custom_event::<SomeOtherEventTypeTest6>()
+        .unwrap();
+    ev.register_custom_event::<ZeroSizedEventTypeTest6>()
+        .unwrap();
+
+    ev.push_custom_event(SomeEventTypeTest6 { a: 1 }).unwrap();
+    ev.push_custom_event(SomeOtherEventTypeTest6 { b: "b".to_owned() })
+        .unwrap();
+    let first = ep.poll_event().unwrap();
+    let other = ep.poll_event().unwrap();
+
+    // an event claiming the other payload is of the first type
+    let forged = match (&first, &other) {
+        (
+            event::Event::User { type_, .. },
+            event::Event::User {
+                timestamp,
+                window_id,
+                code,
+                data1,
+                data2,
+                ..
+            },
+        ) => event::Event::User {
+            timestamp: *timestamp,
+            window_id: *window_id,
+            type_: *type_,
+            code: *code,
+            data1: *data1,
+            data2: *data2,
+        },
+        _ => panic!("expected user events"),
+    };
+    assert!(forged.as_user_event_type::<SomeEventTypeTest6>().is_none());
+    let e2 = other
+        .as_user_event_type::<SomeOtherEventTypeTest6>()
+        .unwrap();
+    assert_eq!(e2.b, "b");
+
+    // a clone of a taken event must not take a payload pushed later, even at the same address
+    let stale = first.clone();
+    assert_eq!(
+        first.as_user_event_type::<SomeEventTypeTest6>().unwrap().a,
+        1
+    );
+    ev.push_custom_event(SomeEventTypeTest6 { a: 2 }).unwrap();
+    let second = ep.poll_event().unwrap();
+    assert!(stale.as_user_event_type::<SomeEventTypeTest6>().is_none());
+    assert_eq!(
+        second.as_user_event_type::<SomeEventTypeTest6>().unwrap().a,
+        2
+    );
+
+    // zero-sized payloads all share one address
+    ev.push_custom_event(ZeroSizedEventTypeTest6).unwrap();
+    ev.push_custom_event(ZeroSizedEventTypeTest6).unwrap();
+    let zst1 = ep.poll_event().unwrap();
+    let zst2 = ep.poll_event().unwrap();
+    assert!(zst1
+        .as_user_event_type::<ZeroSizedEventTypeTest6>()
+        .is_some());
+    assert!(zst1
+        .as_user_event_type::<ZeroSizedEventTypeTest6>()
+        .is_none());
+    assert!(zst2
+        .as_user_event_type::<ZeroSizedEventTypeTest6>()
+        .is_some());
+}
+
 #[test]
 fn test_event_sender_no_subsystem() {
     let _lock = CONTEXT_MUTEX.lock();
@@ -145,3 +268,64 @@ fn test_event_sender_no_subsystem() {
         })
         .is_err());
 }
+
+#[cfg(feature = "futures")]
+#[test]
+fn test_event_stream_push_from_thread() {
+    use futures_core::Stream;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let _lock = CONTEXT_MUTEX.lock();
+    let sdl = sdl3::init().unwrap();
+    let ev = sdl.event().unwrap();
+    let user_event_id = unsafe { ev.register_event().unwrap() };
+    let mut stream = ev.event_stream().unwrap();
+
+    let sender = ev.event_sender();
+    let pusher = std::thread::spawn(move || {
+        for code in 0..100 {
+            sender
+                .push_event(event::Event::User {
+                    timestamp: 0,
+                    window_id: 0,
+                    type_: user_event_id,
+                    code,
+                    data1: std::ptr::null_mut(),
+                    data2: std::ptr::null_mut(),
+                })
+                .unwrap();
+        }
+    });
+
+    // a minimal executor: park until the stream wakes us, which hangs if a wake-up is lost
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut codes = Vec::new();
+    while codes.len() < 100 {
+        match Pin::new(&mut stream).poll_next(&mut cx) {
+            Poll::Ready(Some(event::Event::User { code, .. })) => codes.push(code),
+            Poll::Ready(Some(_)) => {}
+            Poll::Ready(None) => panic!("event stream ended"),
+            Poll::Pending => {
+                let parked_at = std::time::Instant::now();
+                std::thread::park_timeout(std::time::Duration::from_secs(5));
+                assert!(
+                    parked_at.elapsed() < std::time::Duration::from_secs(5),
+                    "event stream missed a wake-up"
+                );
+            }
+        }
+    }
+    pusher.join().unwrap();
+    assert_eq!(codes, (0..100).collect::<Vec<i32>>());
+}